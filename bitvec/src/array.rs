@@ -13,6 +13,7 @@ use crate::{
 };
 
 mod api;
+mod arith;
 mod iter;
 mod ops;
 mod tests;
@@ -0,0 +1,318 @@
+#![doc = include_str!("../../doc/array/arith.md")]
+
+use super::BitArray;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+/// Fixed-width integer arithmetic.
+impl<A, O> BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Constructs a bit-array holding `value` as a little-endian unsigned
+	/// integer, zero-extending it if the bit-array is wider than `u128`, or
+	/// discarding its high bits if the bit-array is narrower.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let reg = BitArray::<[u64; 4], Lsb0>::from_u128_le(5);
+	/// assert_eq!(reg.iter_ones().collect::<Vec<_>>(), [0, 2]);
+	/// assert_eq!(reg.to_u128_le(), Some(5));
+	/// ```
+	#[inline]
+	pub fn from_u128_le(value: u128) -> Self {
+		let mut out = Self::ZERO;
+		out.store_u128_le(value);
+		out
+	}
+
+	/// Constructs a bit-array holding `value` as a big-endian unsigned integer,
+	/// zero-extending it if the bit-array is wider than `u128`, or discarding
+	/// its high bits if the bit-array is narrower.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let reg = BitArray::<u8, Msb0>::from_u128_be(0x1A5);
+	/// assert_eq!(reg.into_inner(), 0xA5);
+	/// ```
+	#[inline]
+	pub fn from_u128_be(value: u128) -> Self {
+		let mut out = Self::ZERO;
+		out.store_u128_be(value);
+		out
+	}
+
+	/// Constructs a bit-array holding `value` as a little-endian
+	/// two’s-complement integer, sign-extending it if the bit-array is wider
+	/// than `i128`, or discarding its high bits if the bit-array is narrower.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let reg = BitArray::<[u64; 4], Lsb0>::from_i128_le(-1);
+	/// assert!(reg.all());
+	/// assert_eq!(reg.to_i128_le(), Some(-1));
+	/// ```
+	#[inline]
+	pub fn from_i128_le(value: i128) -> Self {
+		let mut out = Self::ZERO;
+		out.store_i128_le(value);
+		out
+	}
+
+	/// Constructs a bit-array holding `value` as a big-endian two’s-complement
+	/// integer, sign-extending it if the bit-array is wider than `i128`, or
+	/// discarding its high bits if the bit-array is narrower.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let reg = BitArray::<u16, Msb0>::from_i128_be(-2);
+	/// assert_eq!(reg.into_inner(), 0xFFFE);
+	/// ```
+	#[inline]
+	pub fn from_i128_be(value: i128) -> Self {
+		let mut out = Self::ZERO;
+		out.store_i128_be(value);
+		out
+	}
+
+	/// Computes `self + rhs`, treating both as little-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Original
+	///
+	/// [`u64::checked_add`](https://doc.rust-lang.org/std/primitive.u64.html#method.checked_add)
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// type U256 = BitArray<[u64; 4], Lsb0>;
+	///
+	/// let a = U256::from_u128_le(u128::MAX);
+	/// let b = a.checked_add_le(&a).unwrap();
+	/// assert_eq!(b.first_one(), Some(1));
+	/// assert_eq!(b.last_one(), Some(128));
+	///
+	/// assert!(U256::from_i128_le(-1).checked_add_le(&a).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_add_le<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_add_le(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+
+	/// Computes `self + rhs`, treating both as big-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = BitArray::<u8, Msb0>::new(200);
+	/// let b = BitArray::<u8, Msb0>::new(55);
+	/// assert_eq!(a.checked_add_be(&b).unwrap().into_inner(), 255);
+	/// assert!(a.checked_add_be(&a).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_add_be<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_add_be(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+
+	/// Computes `self - rhs`, treating both as little-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Original
+	///
+	/// [`u64::checked_sub`](https://doc.rust-lang.org/std/primitive.u64.html#method.checked_sub)
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// type U256 = BitArray<[u64; 4], Lsb0>;
+	///
+	/// let a = U256::from_u128_le(10);
+	/// let b = U256::from_u128_le(3);
+	/// assert_eq!(a.checked_sub_le(&b).unwrap().to_u128_le(), Some(7));
+	/// assert!(b.checked_sub_le(&a).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_sub_le<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_sub_le(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+
+	/// Computes `self - rhs`, treating both as big-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = BitArray::<u8, Msb0>::new(10);
+	/// let b = BitArray::<u8, Msb0>::new(3);
+	/// assert_eq!(a.checked_sub_be(&b).unwrap().into_inner(), 7);
+	/// assert!(b.checked_sub_be(&a).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_sub_be<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_sub_be(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+
+	/// Computes `self * rhs`, treating both as little-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Original
+	///
+	/// [`u64::checked_mul`](https://doc.rust-lang.org/std/primitive.u64.html#method.checked_mul)
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// type U256 = BitArray<[u64; 4], Lsb0>;
+	///
+	/// let a = U256::from_u128_le(u128::MAX);
+	/// let sq = a.checked_mul_le(&a).unwrap();
+	/// assert_eq!(sq.first_one(), Some(0));
+	/// assert_eq!(sq.last_one(), Some(255));
+	/// assert!(sq.checked_mul_le(&a).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_mul_le<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_mul_le(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+
+	/// Computes `self * rhs`, treating both as big-endian unsigned integers,
+	/// and returns `None` if the result does not fit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not the same length as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = BitArray::<u8, Msb0>::new(15);
+	/// assert_eq!(a.checked_mul_be(&a).unwrap().into_inner(), 225);
+	/// assert!(a.checked_mul_be(&BitArray::<u8, Msb0>::new(18)).is_none());
+	/// ```
+	#[inline]
+	pub fn checked_mul_be<T2, O2>(
+		mut self,
+		rhs: &BitSlice<T2, O2>,
+	) -> Option<Self>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if self.overflowing_mul_be(rhs) {
+			None
+		}
+		else {
+			Some(self)
+		}
+	}
+}
@@ -12,6 +12,10 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 use super::BitArray;
@@ -175,6 +179,60 @@ where
 	}
 }
 
+impl<A, O, Rhs> Shl<Rhs> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+impl<A, O, Rhs> ShlAssign<Rhs> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+impl<A, O, Rhs> Shr<Rhs> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+impl<A, O, Rhs> ShrAssign<Rhs> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
+
 impl<A, O> Deref for BitArray<A, O>
 where
 	A: BitViewSized,
@@ -18,6 +18,10 @@ use core::{
 		Index,
 		IndexMut,
 		Range,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -59,6 +63,10 @@ fn bonus_impl() {
 		BitOr<BitArray>,
 		BitXor<&'static BitSlice>,
 		BitXor<BitArray>,
+		Shl<usize>,
+		ShlAssign<usize>,
+		Shr<usize>,
+		ShrAssign<usize>,
 	);
 }
 
@@ -0,0 +1,5 @@
+#![doc = include_str!("../doc/encoding.md")]
+
+pub mod codes;
+mod tests;
+pub mod text;
@@ -0,0 +1,450 @@
+#![doc = include_str!("../../doc/encoding/codes.md")]
+
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	stream::{
+		BitReader,
+		StreamError,
+	},
+};
+#[cfg(feature = "alloc")]
+use crate::{
+	stream::BitWriter,
+	vec::BitVec,
+};
+
+/// Appends `value` in unary: `value` `0` bits, followed by a `1` bit.
+///
+/// ## Panics
+///
+/// This panics if `value` does not fit in a `usize`, as a bit-vector cannot
+/// hold that many bits.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_unary(&mut bv, 3);
+/// codes::encode_unary(&mut bv, 0);
+/// assert_eq!(bv, bits![0, 0, 0, 1, 1]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_unary<T, O>(bits: &mut BitVec<T, O>, value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	put_unary(bits, unary_len(value));
+}
+
+/// Reads a unary value: a run of `0` bits, terminated by a `1` bit.
+///
+/// The run is measured with [`.first_one()`], which scans whole memory
+/// elements at a time.
+///
+/// [`.first_one()`]: crate::slice::BitSlice::first_one
+#[inline]
+pub fn decode_unary<T, O>(
+	reader: &mut BitReader<T, O>,
+) -> Result<u64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	get_unary(reader).map(|zeros| zeros as u64)
+}
+
+/// Appends `value` in the Elias gamma code.
+///
+/// A value with `n + 1` significant bits is written as `n` `0` bits, followed
+/// by those significant bits, most significant first.
+///
+/// ## Panics
+///
+/// This panics if `value` is zero, which the code cannot represent.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_gamma(&mut bv, 1);
+/// codes::encode_gamma(&mut bv, 5);
+/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 1]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_gamma<T, O>(bits: &mut BitVec<T, O>, value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	assert_ne!(value, 0, "the Elias gamma code cannot represent zero");
+	put_gamma(bits, value as u128);
+}
+
+/// Reads a value in the Elias gamma code.
+#[inline]
+pub fn decode_gamma<T, O>(
+	reader: &mut BitReader<T, O>,
+) -> Result<u64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	transact(reader, |reader| get_gamma(reader).and_then(narrow))
+}
+
+/// Appends `value` in the Elias delta code.
+///
+/// A value with `n + 1` significant bits is written as `n + 1` in the Elias
+/// gamma code, followed by the `n` significant bits of `value` below its
+/// highest, most significant first.
+///
+/// ## Panics
+///
+/// This panics if `value` is zero, which the code cannot represent.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_delta(&mut bv, 1);
+/// codes::encode_delta(&mut bv, 10);
+/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 0, 0, 1, 0]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_delta<T, O>(bits: &mut BitVec<T, O>, value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	assert_ne!(value, 0, "the Elias delta code cannot represent zero");
+	let rest = significant_bits(value as u128) - 1;
+	put_gamma(bits, rest as u128 + 1);
+	put(bits, value as u128 ^ 1 << rest, rest);
+}
+
+/// Reads a value in the Elias delta code.
+#[inline]
+pub fn decode_delta<T, O>(
+	reader: &mut BitReader<T, O>,
+) -> Result<u64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	transact(reader, |reader| {
+		let width = get_gamma(reader)?;
+		if width > 64 {
+			return Err(StreamError::TooWide {
+				width: width as usize,
+				max:   64,
+			});
+		}
+		let rest = width as usize - 1;
+		let low = reader.read::<u64>(rest)?;
+		Ok(1 << rest | low)
+	})
+}
+
+/// Appends `value` in the Golomb-Rice code with parameter `k`.
+///
+/// The value is written as `value >> k` in unary, followed by the low `k` bits
+/// of `value`, most significant first. Large values with a small `k` produce
+/// long unary prefixes, so `k` should be chosen to suit the expected
+/// magnitude of the values.
+///
+/// ## Panics
+///
+/// This panics if `k` is greater than 64, or if `value >> k` does not fit in a
+/// `usize`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_rice(&mut bv, 10, 2);
+/// codes::encode_rice(&mut bv, 3, 2);
+/// assert_eq!(bv, bits![0, 0, 1, 1, 0, 1, 1, 1]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_rice<T, O>(bits: &mut BitVec<T, O>, value: u64, k: usize)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	assert!(k <= 64, "a Rice parameter of {} exceeds 64", k);
+	put_unary(bits, unary_len(value.checked_shr(k as u32).unwrap_or(0)));
+	let value = value as u128;
+	put(bits, value & !(!0 << k), k);
+}
+
+/// Reads a value in the Golomb-Rice code with parameter `k`.
+#[inline]
+pub fn decode_rice<T, O>(
+	reader: &mut BitReader<T, O>,
+	k: usize,
+) -> Result<u64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	transact(reader, |reader| {
+		let high = get_unary(reader)? as u128;
+		let low = reader.read::<u64>(k)? as u128;
+		narrow(high << k | low)
+	})
+}
+
+/// Appends `value` in the unsigned exponential-Golomb code of order zero,
+/// which H.264 and HEVC call `ue(v)`.
+///
+/// This is `value + 1` in the Elias gamma code.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_exp_golomb(&mut bv, 0);
+/// codes::encode_exp_golomb(&mut bv, 3);
+/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 0]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_exp_golomb<T, O>(bits: &mut BitVec<T, O>, value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	put_gamma(bits, value as u128 + 1);
+}
+
+/// Reads a value in the unsigned exponential-Golomb code of order zero.
+#[inline]
+pub fn decode_exp_golomb<T, O>(
+	reader: &mut BitReader<T, O>,
+) -> Result<u64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	transact(reader, |reader| {
+		get_gamma(reader).and_then(|x| narrow(x - 1))
+	})
+}
+
+/// Appends `value` in the signed exponential-Golomb code of order zero, which
+/// H.264 and HEVC call `se(v)`.
+///
+/// Positive values `k` are mapped to `2k - 1`, and all others to `-2k`, so
+/// that the sequence `0, 1, -1, 2, -2, …` is written as the unsigned codes for
+/// `0, 1, 2, 3, 4, …`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::encoding::codes;
+///
+/// let mut bv = BitVec::<u8, Msb0>::new();
+/// codes::encode_signed_exp_golomb(&mut bv, 1);
+/// codes::encode_signed_exp_golomb(&mut bv, -1);
+/// assert_eq!(bv, bits![0, 1, 0, 0, 1, 1]);
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_signed_exp_golomb<T, O>(bits: &mut BitVec<T, O>, value: i64)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let value = value as i128;
+	let mapped = if value > 0 { 2 * value - 1 } else { -2 * value };
+	put_gamma(bits, mapped as u128 + 1);
+}
+
+/// Reads a value in the signed exponential-Golomb code of order zero.
+#[inline]
+pub fn decode_signed_exp_golomb<T, O>(
+	reader: &mut BitReader<T, O>,
+) -> Result<i64, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	transact(reader, |reader| {
+		let mapped = get_gamma(reader)? - 1;
+		let half = (mapped / 2) as i128;
+		let value = if mapped % 2 == 1 { half + 1 } else { -half };
+		if value > i64::MAX as i128 || value < i64::MIN as i128 {
+			return Err(StreamError::TooWide {
+				width: significant_bits(mapped),
+				max:   64,
+			});
+		}
+		Ok(value as i64)
+	})
+}
+
+/// Runs a decoder over the unread bits of `reader`, and only advances `reader`
+/// if it succeeds.
+///
+/// Codes are always read with big-endian field loads, regardless of the
+/// reader’s endianness.
+fn transact<T, O, F, R>(
+	reader: &mut BitReader<T, O>,
+	decode: F,
+) -> Result<R, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	F: FnOnce(&mut BitReader<T, O>) -> Result<R, StreamError>,
+{
+	let mut inner = BitReader::new(reader.as_bitslice());
+	let out = decode(&mut inner)?;
+	reader.skip(inner.position())?;
+	Ok(out)
+}
+
+/// Counts the bits in `value` up to and including its highest `1` bit.
+fn significant_bits(value: u128) -> usize {
+	128 - value.leading_zeros() as usize
+}
+
+/// Converts a decoded value to `u64`, failing if it is too large.
+fn narrow(value: u128) -> Result<u64, StreamError> {
+	if value > u64::MAX as u128 {
+		return Err(StreamError::TooWide {
+			width: significant_bits(value),
+			max:   64,
+		});
+	}
+	Ok(value as u64)
+}
+
+/// Reads a run of `0` bits and its terminating `1` bit, and produces the
+/// length of the run.
+fn get_unary<T, O>(reader: &mut BitReader<T, O>) -> Result<usize, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let remaining = reader.remaining();
+	let zeros =
+		reader
+			.as_bitslice()
+			.first_one()
+			.ok_or(StreamError::Underrun {
+				requested: remaining + 1,
+				remaining,
+			})?;
+	reader.skip(zeros + 1)?;
+	Ok(zeros)
+}
+
+/// Reads a nonzero value in the Elias gamma code.
+///
+/// Values of up to 65 significant bits are accepted, so that the exponential-
+/// Golomb codes can reach `u64::MAX` and `i64::MIN`.
+fn get_gamma<T, O>(reader: &mut BitReader<T, O>) -> Result<u128, StreamError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let zeros = get_unary(reader)?;
+	if zeros > 64 {
+		return Err(StreamError::TooWide {
+			width: zeros + 1,
+			max:   64,
+		});
+	}
+	let low = reader.read::<u128>(zeros)?;
+	Ok(1 << zeros | low)
+}
+
+/// Converts the length of a unary prefix to a bit count.
+///
+/// ## Panics
+///
+/// This panics if `zeros` does not fit in a `usize`.
+#[cfg(feature = "alloc")]
+fn unary_len(zeros: u64) -> usize {
+	usize::try_from(zeros).unwrap_or_else(|_| {
+		panic!("a unary prefix of {} bits exceeds `usize::MAX`", zeros)
+	})
+}
+
+/// Appends `zeros` `0` bits, followed by a `1` bit.
+#[cfg(feature = "alloc")]
+fn put_unary<T, O>(bits: &mut BitVec<T, O>, zeros: usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = bits.len();
+	bits.resize(len + zeros, false);
+	bits.push(true);
+}
+
+/// Appends a nonzero value in the Elias gamma code.
+///
+/// The highest bit of `value` is written as the end of the unary prefix, rather
+/// than as part of the binary field, so that it immediately follows the `0`
+/// run in every ordering.
+#[cfg(feature = "alloc")]
+fn put_gamma<T, O>(bits: &mut BitVec<T, O>, value: u128)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let rest = significant_bits(value) - 1;
+	put_unary(bits, rest);
+	put(bits, value ^ 1 << rest, rest);
+}
+
+/// Appends the low `width` bits of `value`, which has no higher bits set, with
+/// [`BitField::store_be`].
+#[cfg(feature = "alloc")]
+fn put<T, O>(bits: &mut BitVec<T, O>, value: u128, width: usize)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	BitWriter::<T, O, _>::from_bitvec(bits)
+		.write(value, width)
+		.expect("codes only write fields that fit their values");
+}
@@ -0,0 +1,264 @@
+//! Unit tests for bit-level encodings.
+
+#![cfg(test)]
+
+use super::codes::*;
+use crate::{
+	prelude::*,
+	stream::{
+		BitReader,
+		StreamError,
+	},
+};
+
+#[test]
+#[cfg(feature = "alloc")]
+fn codes() {
+	//  Each value is followed by its code, hand-written most significant bit
+	//  first.
+	let unary = [(0, "1"), (1, "01"), (4, "00001")];
+	let gamma = [
+		(1, "1"),
+		(2, "010"),
+		(3, "011"),
+		(4, "00100"),
+		(9, "0001001"),
+	];
+	let delta = [(1, "1"), (2, "0100"), (4, "01100"), (17, "001010001")];
+	let rice = [(0, "100"), (5, "0101"), (7, "0111"), (12, "000100")];
+	let ue = [
+		(0, "1"),
+		(1, "010"),
+		(2, "011"),
+		(6, "00111"),
+		(7, "0001000"),
+	];
+	let se = [
+		(0, "1"),
+		(1, "010"),
+		(-1, "011"),
+		(2, "00100"),
+		(-3, "00111"),
+	];
+
+	fn check<F, G, V>(cases: &[(V, &str)], encode: F, decode: G)
+	where
+		F: Fn(&mut BitVec<u8, Msb0>, V),
+		G: Fn(&mut BitReader<u8, Msb0>) -> Result<V, StreamError>,
+		V: Copy + core::fmt::Debug + PartialEq,
+	{
+		let mut bv = BitVec::<u8, Msb0>::new();
+		let mut expected = BitVec::<u8, Msb0>::new();
+		for &(value, code) in cases {
+			encode(&mut bv, value);
+			expected.extend(code.chars().map(|c| c == '1'));
+			assert_eq!(bv, expected, "encoding {:?}", value);
+		}
+		let mut reader = BitReader::new(bv.as_bitslice());
+		for &(value, _) in cases {
+			assert_eq!(decode(&mut reader), Ok(value));
+		}
+		assert_eq!(reader.remaining(), 0);
+	}
+
+	check(&unary, encode_unary, decode_unary);
+	check(&gamma, encode_gamma, decode_gamma);
+	check(&delta, encode_delta, decode_delta);
+	check(&rice, |bv, v| encode_rice(bv, v, 2), |r| decode_rice(r, 2));
+	check(&ue, encode_exp_golomb, decode_exp_golomb);
+	check(&se, encode_signed_exp_golomb, decode_signed_exp_golomb);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn codes_extremes() {
+	let mut bv = BitVec::<usize, Lsb0>::new();
+	encode_gamma(&mut bv, u64::MAX);
+	encode_delta(&mut bv, u64::MAX);
+	encode_exp_golomb(&mut bv, u64::MAX);
+	encode_signed_exp_golomb(&mut bv, i64::MIN);
+	encode_signed_exp_golomb(&mut bv, i64::MAX);
+	encode_rice(&mut bv, u64::MAX, 60);
+	encode_rice(&mut bv, u64::MAX, 64);
+	assert_eq!(bv.len(), 127 + 76 + 129 + 129 + 127 + 76 + 65);
+
+	let mut reader = BitReader::new(bv.as_bitslice());
+	reader.set_endian(crate::stream::Endian::Little);
+	assert_eq!(decode_gamma(&mut reader), Ok(u64::MAX));
+	assert_eq!(decode_delta(&mut reader), Ok(u64::MAX));
+	assert_eq!(decode_exp_golomb(&mut reader), Ok(u64::MAX));
+	assert_eq!(decode_signed_exp_golomb(&mut reader), Ok(i64::MIN));
+	assert_eq!(decode_signed_exp_golomb(&mut reader), Ok(i64::MAX));
+	assert_eq!(decode_rice(&mut reader, 60), Ok(u64::MAX));
+	assert_eq!(decode_rice(&mut reader, 64), Ok(u64::MAX));
+	assert_eq!(reader.remaining(), 0);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", target_pointer_width = "32"))]
+#[should_panic = "a unary prefix of 4294967296 bits exceeds `usize::MAX`"]
+fn unary_too_long() {
+	encode_unary(&mut BitVec::<u8, Msb0>::new(), 1 << 32);
+}
+
+#[test]
+fn codes_errors() {
+	let bits = bits![u8, Msb0; 0, 0, 0, 1, 0];
+	let mut reader = BitReader::new(bits);
+	assert_eq!(
+		decode_gamma(&mut reader),
+		Err(StreamError::Underrun {
+			requested: 3,
+			remaining: 1,
+		})
+	);
+	assert_eq!(reader.position(), 0);
+	assert_eq!(decode_unary(&mut reader), Ok(3));
+	assert_eq!(
+		decode_unary(&mut reader),
+		Err(StreamError::Underrun {
+			requested: 2,
+			remaining: 1,
+		})
+	);
+	assert_eq!(reader.position(), 4);
+
+	//  64 zeros, then a 65-bit value whose decrement is 65 bits wide.
+	let mut words = [0u64; 3];
+	let bits = words.view_bits_mut::<Msb0>();
+	bits.set(64, true);
+	bits.set(128, true);
+	let mut reader = BitReader::new(&*bits);
+	assert_eq!(
+		decode_exp_golomb(&mut reader),
+		Err(StreamError::TooWide {
+			width: 65,
+			max:   64,
+		})
+	);
+	assert_eq!(
+		decode_gamma(&mut reader),
+		Err(StreamError::TooWide {
+			width: 65,
+			max:   64,
+		})
+	);
+	assert_eq!(decode_signed_exp_golomb(&mut reader).unwrap(), i64::MIN);
+
+	let bits = bits![u8, Msb0; 0; 66];
+	let mut reader = BitReader::new(bits);
+	reader.skip(1).unwrap();
+	assert!(decode_delta(&mut reader).is_err());
+	assert_eq!(
+		decode_rice(&mut reader, 65),
+		Err(StreamError::Underrun {
+			requested: 66,
+			remaining: 65,
+		})
+	);
+	assert_eq!(reader.position(), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn text() {
+	use crate::encoding::text::ParseBitsError;
+
+	let bv = bitvec![u8, Msb0; 0, 1, 1, 0, 1, 0, 0, 1, 1, 1];
+	for text in [
+		"0110100111",
+		" 0b01_1010_0111\n",
+		"[0, 1, 1, 0, 1, 0, 0, 1, 1, 1]",
+		"[\n    0,\n    1,\n    1,\n    01001,\n    0b11,\n]",
+	] {
+		assert_eq!(text.parse::<BitVec<u8, Msb0>>(), Ok(bv.clone()));
+		assert_eq!(text.parse::<BitBox<u16, Lsb0>>().unwrap(), bv);
+	}
+	assert_eq!(bv.to_string().parse::<BitVec>().unwrap(), bv);
+	assert_eq!(format!("{:b}", bv).parse::<BitVec>().unwrap(), bv);
+	assert_eq!(format!("{:#b}", bv).parse::<BitVec>().unwrap(), bv);
+	assert_eq!("0x6_9".parse::<BitVec>().unwrap(), bv[.. 8]);
+	assert_eq!("0O75".parse::<BitVec>().unwrap(), bits![1, 1, 1, 1, 0, 1]);
+	assert!("".parse::<BitVec>().unwrap().is_empty());
+	assert!(" [ ] ".parse::<BitVec>().unwrap().is_empty());
+
+	let unexpected =
+		|position, found| ParseBitsError::Unexpected { position, found };
+	for (text, err) in [
+		("0x", unexpected(2, None)),
+		("0x0g", unexpected(3, Some('g'))),
+		("  012", unexpected(4, Some('2'))),
+		("[0, 1", unexpected(5, None)),
+		("[0,, 1]", unexpected(3, Some(','))),
+		("[0 1]", unexpected(3, Some('1'))),
+		("[0b]", unexpected(3, Some(']'))),
+		("[0x1]", unexpected(2, Some('x'))),
+		("[0] 1", unexpected(4, Some('1'))),
+		("[0, é]", unexpected(4, Some('é'))),
+	] {
+		assert_eq!(text.parse::<BitVec>(), Err(err), "{}", text);
+	}
+
+	let arr = "0xA5".parse::<BitArray<u8, Lsb0>>().unwrap();
+	assert_eq!(arr.data, 0xA5u8.reverse_bits());
+	assert_eq!(
+		"0xA5_0".parse::<BitArray<u8, Lsb0>>(),
+		Err(ParseBitsError::Length {
+			expected: 8,
+			found:    12,
+		}),
+	);
+
+	for len in 0 .. 40 {
+		let bits = (0 .. len).map(|n| n % 3 == 0).collect::<BitVec<u16>>();
+		let hex = bits.to_hex();
+		assert_eq!(hex.len(), (len + 3) / 4);
+		assert_eq!(BitVec::<u16>::from_hex(&hex, len).unwrap(), bits);
+		let b64 = bits.to_base64();
+		assert_eq!(b64.len() % 4, 0);
+		assert_eq!(BitVec::<u16>::from_base64(&b64, len).unwrap(), bits);
+		assert_eq!(
+			BitVec::<u16>::from_base64(b64.trim_end_matches('='), len).unwrap(),
+			bits,
+		);
+	}
+	assert_eq!(bitvec![u8, Msb0; 1; 24].to_base64(), "////");
+	assert_eq!(bits![0, 1, 1, 0, 0, 1, 1, 0].to_base64(), "Zg==");
+
+	assert_eq!(
+		BitVec::<u8>::from_hex("ff", 4),
+		Err(ParseBitsError::Length {
+			expected: 4,
+			found:    8,
+		}),
+	);
+	assert_eq!(
+		BitVec::<u8>::from_hex("f1", 5),
+		Err(ParseBitsError::Padding { position: 1 }),
+	);
+	assert_eq!(
+		BitVec::<u8>::from_base64("Zg=", 8),
+		Err(ParseBitsError::Unexpected {
+			position: 2,
+			found:    Some('='),
+		}),
+	);
+	assert_eq!(
+		BitVec::<u8>::from_base64("Zh==", 8),
+		Err(ParseBitsError::Padding { position: 1 }),
+	);
+	assert_eq!(
+		BitVec::<u8>::from_base64("Z", 8),
+		Err(ParseBitsError::Unexpected {
+			position: 1,
+			found:    None,
+		}),
+	);
+	assert_eq!(
+		BitVec::<u8>::from_base64("Z-==", 8),
+		Err(ParseBitsError::Unexpected {
+			position: 1,
+			found:    Some('-'),
+		}),
+	);
+}
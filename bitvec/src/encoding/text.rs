@@ -0,0 +1,579 @@
+#![doc = include_str!("../../doc/encoding/text.md")]
+
+#[cfg(feature = "alloc")]
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	str::FromStr,
+};
+
+use crate::{
+	array::BitArray,
+	order::BitOrder,
+	view::BitViewSized,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+	boxed::BitBox,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The base64 alphabet of RFC 4648, indexed by sextet value.
+#[cfg(feature = "alloc")]
+const BASE64: &[u8; 64] =
+	b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[doc = include_str!("../../doc/encoding/ParseBitsError.md")]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParseBitsError {
+	/// The text has a character that is not allowed where it appears, or ends
+	/// early.
+	Unexpected {
+		/// The byte offset of the character in the text.
+		position: usize,
+		/// The character, or `None` if the text ended.
+		found:    Option<char>,
+	},
+	/// The text holds a different number of bits than the destination.
+	Length {
+		/// The number of bits in the destination.
+		expected: usize,
+		/// The number of bits in the text.
+		found:    usize,
+	},
+	/// A digit has `1` bits after the end of the encoded bit-slice, where only
+	/// `0` padding is allowed.
+	Padding {
+		/// The byte offset of the digit in the text.
+		position: usize,
+	},
+}
+
+impl ParseBitsError {
+	/// Gets the byte offset in the text of the character that caused the
+	/// error, if one did.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use bitvec::prelude::*;
+	/// use bitvec::encoding::text::ParseBitsError;
+	///
+	/// let err = "0b10_2".parse::<BitVec>().unwrap_err();
+	/// assert_eq!(err.position(), Some(5));
+	/// # }
+	/// ```
+	#[inline]
+	pub fn position(&self) -> Option<usize> {
+		match *self {
+			Self::Unexpected { position, .. } | Self::Padding { position } => {
+				Some(position)
+			},
+			Self::Length { .. } => None,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("ParseBitsError::")?;
+		match *self {
+			Self::Unexpected { position, found } => fmt
+				.debug_struct("Unexpected")
+				.field("position", &position)
+				.field("found", &found)
+				.finish(),
+			Self::Length { expected, found } => fmt
+				.debug_struct("Length")
+				.field("expected", &expected)
+				.field("found", &found)
+				.finish(),
+			Self::Padding { position } => fmt
+				.debug_struct("Padding")
+				.field("position", &position)
+				.finish(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Unexpected {
+				position,
+				found: Some(found),
+			} => write!(fmt, "unexpected {:?} at position {}", found, position),
+			Self::Unexpected {
+				position,
+				found: None,
+			} => write!(fmt, "unexpected end of text at position {}", position),
+			Self::Length { expected, found } => {
+				write!(fmt, "expected {} bits, found {}", expected, found)
+			},
+			Self::Padding { position } => write!(
+				fmt,
+				"the digit at position {} has nonzero padding bits",
+				position,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitsError {}
+
+#[doc = include_str!("../../doc/encoding/text/FromStr.md")]
+#[cfg(feature = "alloc")]
+impl<T, O> FromStr for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::new();
+		parse(text, |bit| out.push(bit))?;
+		Ok(out)
+	}
+}
+
+#[doc = include_str!("../../doc/encoding/text/FromStr.md")]
+#[cfg(feature = "alloc")]
+#[cfg(not(tarpaulin_include))]
+impl<T, O> FromStr for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		text.parse::<BitVec<T, O>>()
+			.map(BitVec::into_boxed_bitslice)
+	}
+}
+
+#[doc = include_str!("../../doc/encoding/text/FromStr.md")]
+///
+/// The text must hold exactly as many bits as the array; otherwise, this fails
+/// with [`ParseBitsError::Length`].
+impl<A, O> FromStr for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::ZERO;
+		let len = out.len();
+		let mut count = 0;
+		parse(text, |bit| {
+			if count < len {
+				out.set(count, bit);
+			}
+			count += 1;
+		})?;
+		if count != len {
+			return Err(ParseBitsError::Length {
+				expected: len,
+				found:    count,
+			});
+		}
+		Ok(out)
+	}
+}
+
+/// Hexadecimal and base64 text.
+#[cfg(feature = "alloc")]
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Encodes the bit-slice as lowercase hexadecimal digits.
+	///
+	/// Each digit holds four bits, with the first in its most significant
+	/// place. When the length is not a multiple of four, the last digit is
+	/// filled out with `0` bits. Use [`BitVec::from_hex`] with the length of
+	/// the bit-slice to decode it.
+	///
+	/// Unlike the [`LowerHex`] rendering, this does not divide the bit-slice
+	/// at memory element boundaries.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 0, 1, 1, 1, 1, 1, 1];
+	/// assert_eq!(bits.to_hex(), "afc");
+	/// ```
+	///
+	/// [`BitVec::from_hex`]: crate::vec::BitVec::from_hex
+	/// [`LowerHex`]: core::fmt::LowerHex
+	#[inline]
+	pub fn to_hex(&self) -> String {
+		self.chunks(4)
+			.map(|digit| {
+				let value = pack(digit) << (4 - digit.len());
+				char::from_digit(value as u32, 16)
+					.expect("a four-bit value is a hexadecimal digit")
+			})
+			.collect()
+	}
+
+	/// Encodes the bit-slice in padded base64, using the standard alphabet of
+	/// RFC 4648.
+	///
+	/// The bit-slice is first divided into bytes, with the first bit in the
+	/// most significant place of the first byte. When the length is not a
+	/// multiple of eight, the last byte is filled out with `0` bits. Use
+	/// [`BitVec::from_base64`] with the length of the bit-slice to decode it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 1, 0, 1, 0, 1];
+	/// assert_eq!(bits.to_base64(), "TUA=");
+	/// ```
+	///
+	/// [`BitVec::from_base64`]: crate::vec::BitVec::from_base64
+	#[inline]
+	pub fn to_base64(&self) -> String {
+		let bytes = self
+			.chunks(8)
+			.map(|byte| pack(byte) << (8 - byte.len()))
+			.collect::<Vec<u8>>();
+		let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+		for group in bytes.chunks(3) {
+			let word =
+				group.iter().enumerate().fold(0u32, |word, (idx, &byte)| {
+					word | (byte as u32) << (16 - 8 * idx)
+				});
+			for idx in 0 .. 4 {
+				if idx <= group.len() {
+					let sextet = word >> (18 - 6 * idx) & 0x3F;
+					out.push(BASE64[sextet as usize] as char);
+				}
+				else {
+					out.push('=');
+				}
+			}
+		}
+		out
+	}
+}
+
+/// Hexadecimal and base64 text.
+#[cfg(feature = "alloc")]
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Decodes the hexadecimal text produced by [`BitSlice::to_hex`] into a
+	/// bit-vector of `len` bits.
+	///
+	/// Digits may be in either case, and may be separated by underscores.
+	///
+	/// ## Errors
+	///
+	/// This fails if the text has a character that is not a hexadecimal
+	/// digit, if it does not have exactly enough digits for `len` bits, or if
+	/// the last digit has `1` bits beyond `len`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::encoding::text::ParseBitsError;
+	///
+	/// let bv = BitVec::<u8, Msb0>::from_hex("AF_C", 10).unwrap();
+	/// assert_eq!(bv, bits![1, 0, 1, 0, 1, 1, 1, 1, 1, 1]);
+	///
+	/// assert_eq!(
+	///   BitVec::<u8, Msb0>::from_hex("afd", 10),
+	///   Err(ParseBitsError::Padding { position: 2 }),
+	/// );
+	/// ```
+	///
+	/// [`BitSlice::to_hex`]: crate::slice::BitSlice::to_hex
+	#[inline]
+	pub fn from_hex(text: &str, len: usize) -> Result<Self, ParseBitsError> {
+		let mut out = Self::new();
+		//  Each digit is pushed whole, so the digit holding bit `n` is the
+		//  `n / 4`th digit of the text.
+		let mut digits = Vec::new();
+		parse_digits(text, 0, 4, |bit, position| {
+			if out.len() % 4 == 0 {
+				digits.push(position);
+			}
+			out.push(bit);
+		})?;
+		finish_padded(out, len, 4, |idx| digits[idx / 4])
+	}
+
+	/// Decodes the base64 text produced by [`BitSlice::to_base64`] into a
+	/// bit-vector of `len` bits.
+	///
+	/// The text must use the standard alphabet of RFC 4648. Trailing `=`
+	/// padding may be omitted.
+	///
+	/// ## Errors
+	///
+	/// This fails if the text has a character outside the alphabet, if its
+	/// padding is malformed, if it does not encode exactly enough bytes for
+	/// `len` bits, or if the last byte has `1` bits beyond `len`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::encoding::text::ParseBitsError;
+	///
+	/// let bv = BitVec::<u8, Msb0>::from_base64("TUA=", 10).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 0, 0, 1, 1, 0, 1, 0, 1]);
+	///
+	/// assert_eq!(
+	///   BitVec::<u8, Msb0>::from_base64("TUA", 9),
+	///   Err(ParseBitsError::Padding { position: 1 }),
+	/// );
+	/// ```
+	///
+	/// [`BitSlice::to_base64`]: crate::slice::BitSlice::to_base64
+	#[inline]
+	pub fn from_base64(text: &str, len: usize) -> Result<Self, ParseBitsError> {
+		let body = text.trim_end_matches('=');
+		let pad = text.len() - body.len();
+		if pad > 2 || (pad > 0 && text.len() % 4 != 0) {
+			return Err(ParseBitsError::Unexpected {
+				position: body.len(),
+				found:    Some('='),
+			});
+		}
+
+		let mut out = Self::with_capacity(body.len() * 6);
+		for (position, ch) in body.char_indices() {
+			let sextet = BASE64.iter().position(|&b| b as char == ch).ok_or(
+				ParseBitsError::Unexpected {
+					position,
+					found: Some(ch),
+				},
+			)?;
+			out.extend((0 .. 6).rev().map(|shift| sextet >> shift & 1 == 1));
+		}
+		//  A lone final character cannot complete a byte.
+		if body.len() % 4 == 1 {
+			return Err(ParseBitsError::Unexpected {
+				position: body.len(),
+				found:    None,
+			});
+		}
+		//  Only whole bytes are encoded, so the bits of the final character
+		//  beyond them are padding as well.
+		let bytes = out.len() / 8;
+		if out[bytes * 8 ..].any() {
+			return Err(ParseBitsError::Padding {
+				position: body.len() - 1,
+			});
+		}
+		out.truncate(bytes * 8);
+		//  Every character is ASCII, so the character holding bit `n` is at
+		//  byte offset `n / 6`.
+		finish_padded(out, len, 8, |idx| idx / 6)
+	}
+}
+
+/// Checks that `bits` has exactly enough `unit`-bit groups for `len` bits, and
+/// that the bits after `len` are all `0`, then truncates it to `len`.
+///
+/// `locate` maps a bit index to the byte offset of the text that encoded it.
+#[cfg(feature = "alloc")]
+fn finish_padded<T, O, F>(
+	mut bits: BitVec<T, O>,
+	len: usize,
+	unit: usize,
+	locate: F,
+) -> Result<BitVec<T, O>, ParseBitsError>
+where
+	T: BitStore,
+	O: BitOrder,
+	F: FnOnce(usize) -> usize,
+{
+	if bits.len() != (len + unit - 1) / unit * unit {
+		return Err(ParseBitsError::Length {
+			expected: len,
+			found:    bits.len(),
+		});
+	}
+	if let Some(idx) = bits[len ..].first_one() {
+		return Err(ParseBitsError::Padding {
+			position: locate(len + idx),
+		});
+	}
+	bits.truncate(len);
+	Ok(bits)
+}
+
+/// Packs a bit-slice of at most eight bits into a byte, with its first bit in
+/// the most significant place that it occupies.
+#[cfg(feature = "alloc")]
+fn pack<T, O>(bits: &BitSlice<T, O>) -> u8
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	bits.iter()
+		.by_vals()
+		.fold(0, |byte, bit| byte << 1 | bit as u8)
+}
+
+/// Parses `text` as a bit string, passing each of its bits to `push` in order.
+///
+/// The text is either a list of binary words in the form produced by the
+/// `Display` and `Binary` renderings, or a single word with an optional `0b`,
+/// `0o`, or `0x` radix prefix. Surrounding whitespace is ignored.
+fn parse<F>(text: &str, mut push: F) -> Result<(), ParseBitsError>
+where F: FnMut(bool) {
+	let start = text.len() - text.trim_start().len();
+	let body = text.trim();
+	if body.starts_with('[') {
+		return parse_list(body, start, push);
+	}
+	let (radix, digits) = match body.as_bytes() {
+		[b'0', b'b' | b'B', ..] => (1, 2),
+		[b'0', b'o' | b'O', ..] => (3, 2),
+		[b'0', b'x' | b'X', ..] => (4, 2),
+		_ => (1, 0),
+	};
+	let count =
+		parse_digits(&body[digits ..], start + digits, radix, |bit, _| {
+			push(bit)
+		})?;
+	//  A prefix must be followed by at least one digit.
+	if digits != 0 && count == 0 {
+		return Err(ParseBitsError::Unexpected {
+			position: start + body.len(),
+			found:    None,
+		});
+	}
+	Ok(())
+}
+
+/// Parses a bracketed, comma-separated, list of binary words. A trailing comma
+/// is allowed, as the pretty-printed `Debug` rendering produces one.
+fn parse_list<F>(
+	body: &str,
+	offset: usize,
+	mut push: F,
+) -> Result<(), ParseBitsError>
+where
+	F: FnMut(bool),
+{
+	let unexpected = |idx: usize| ParseBitsError::Unexpected {
+		position: offset + idx,
+		found:    body[idx ..].chars().next(),
+	};
+	let is_space = |idx: usize| {
+		body[idx ..]
+			.chars()
+			.next()
+			.map_or(false, char::is_whitespace)
+	};
+	let skip_space = |mut idx: usize| {
+		while is_space(idx) {
+			idx += body[idx ..].chars().next().map_or(0, char::len_utf8);
+		}
+		idx
+	};
+
+	let mut idx = skip_space(1);
+	loop {
+		if body[idx ..].starts_with(']') {
+			break;
+		}
+		let len = body[idx ..]
+			.find(|c: char| c.is_whitespace() || c == ',' || c == ']')
+			.unwrap_or(body.len() - idx);
+		if len == 0 {
+			return Err(unexpected(idx));
+		}
+		let word = &body[idx .. idx + len];
+		let skip = if word.starts_with("0b") { 2 } else { 0 };
+		let count =
+			parse_digits(&word[skip ..], offset + idx + skip, 1, |bit, _| {
+				push(bit)
+			})?;
+		if count == 0 {
+			return Err(unexpected(idx + len));
+		}
+		idx = skip_space(idx + len);
+		match body[idx ..].chars().next() {
+			Some(',') => idx = skip_space(idx + 1),
+			Some(']') => break,
+			_ => return Err(unexpected(idx)),
+		}
+	}
+	//  `body` has no trailing whitespace, so the bracket must end it.
+	let end = skip_space(idx + 1);
+	if end != body.len() {
+		return Err(unexpected(end));
+	}
+	Ok(())
+}
+
+/// Parses digits of `radix` bits each, separated by any number of
+/// underscores, and passes each bit to `push`, most significant first, along
+/// with the byte offset of its digit. `offset` is the byte offset of `digits`
+/// in the full text.
+///
+/// Returns the number of digits.
+fn parse_digits<F>(
+	digits: &str,
+	offset: usize,
+	radix: u32,
+	mut push: F,
+) -> Result<usize, ParseBitsError>
+where
+	F: FnMut(bool, usize),
+{
+	let mut count = 0;
+	for (idx, ch) in digits.char_indices() {
+		if ch == '_' {
+			continue;
+		}
+		let position = offset + idx;
+		let value =
+			ch.to_digit(1 << radix).ok_or(ParseBitsError::Unexpected {
+				position,
+				found: Some(ch),
+			})?;
+		for shift in (0 .. radix).rev() {
+			push(value >> shift & 1 == 1, position);
+		}
+		count += 1;
+	}
+	Ok(count)
+}
@@ -0,0 +1,304 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/ewah.md")]
+
+use alloc::vec::Vec;
+use core::cmp;
+
+use self::words::{
+	fill,
+	lit_len,
+	run_bit,
+	run_len,
+	Builder,
+	Cursor,
+};
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod ops;
+mod tests;
+mod traits;
+mod words;
+
+#[doc = include_str!("../doc/ewah/EwahBitmap.md")]
+pub struct EwahBitmap {
+	/// The marker-word stream.
+	words: Vec<u64>,
+	/// The number of bits in the bitmap. Bits in the final word past this
+	/// length are always `0`.
+	len:   usize,
+}
+
+/// Constructors and conversions.
+impl EwahBitmap {
+	/// Constructs an empty bitmap.
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			words: Builder::new().finish(),
+			len:   0,
+		}
+	}
+
+	/// Compresses the contents of a bit-slice.
+	///
+	/// This walks the bit-slice with [`.iter_runs()`], so long runs of equal
+	/// bits are consumed in a few steps regardless of their length.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::ewah::EwahBitmap;
+	///
+	/// let mut bv = bitvec![0; 1_000_000];
+	/// bv.set(500_000, true);
+	/// let ewah = EwahBitmap::from_bitslice(&bv);
+	///
+	/// assert_eq!(ewah.len(), 1_000_000);
+	/// assert!(ewah.as_raw_slice().len() < 8);
+	/// assert_eq!(ewah.to_bitvec(), bv);
+	/// ```
+	///
+	/// [`.iter_runs()`]: crate::slice::BitSlice::iter_runs
+	#[inline]
+	pub fn from_bitslice<T, O>(bits: &BitSlice<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut builder = Builder::new();
+		for (bit, range) in bits.iter_runs() {
+			builder.push_bits(bit, range.len());
+		}
+		Self {
+			words: builder.finish(),
+			len:   bits.len(),
+		}
+	}
+
+	/// Decompresses the bitmap into a bit-vector.
+	#[inline]
+	pub fn to_bitvec(&self) -> BitVec<u64, Lsb0> {
+		let total = word_count(self.len);
+		let mut out = Vec::with_capacity(total);
+		let mut cursor = Cursor::new(&self.words);
+		while out.len() < total {
+			let left = (total - out.len()) as u64;
+			let count = match cursor.run() {
+				Some((bit, run)) => {
+					let count = cmp::min(run, left);
+					out.resize(out.len() + count as usize, fill(bit));
+					count
+				},
+				None => {
+					let lits = cursor.lits();
+					let count = cmp::min(lits.len() as u64, left);
+					out.extend_from_slice(&lits[.. count as usize]);
+					count
+				},
+			};
+			cursor.advance(count);
+		}
+		let mut bv = BitVec::from_vec(out);
+		bv.truncate(self.len);
+		bv
+	}
+
+	/// Views the compressed marker-word stream.
+	///
+	/// The stream is a sequence of groups, each of which is a marker word
+	/// followed by literal words. Bit 0 of a marker is the value of a run of
+	/// clean words, bits 1 through 32 count the clean words, and bits 33
+	/// through 63 count the literal words that follow the marker. This is the
+	/// layout used by 64-bit EWAH implementations in other languages.
+	#[inline]
+	pub fn as_raw_slice(&self) -> &[u64] {
+		&self.words
+	}
+}
+
+/// Queries.
+impl EwahBitmap {
+	/// Gets the number of bits in the bitmap.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the bitmap has no bits.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Reads a bit from the bitmap.
+	///
+	/// This walks the marker words up to the word holding `index`, but does
+	/// not decompress anything.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::ewah::EwahBitmap;
+	///
+	/// let ewah = EwahBitmap::from_bitslice(bits![0, 0, 1]);
+	/// assert_eq!(ewah.get(2), Some(true));
+	/// assert_eq!(ewah.get(3), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index >= self.len {
+			return None;
+		}
+		let target = (index / 64) as u64;
+		let mut pos = 0;
+		let mut words = self.words.as_slice();
+		while let Some((&marker, rest)) = words.split_first() {
+			pos += run_len(marker);
+			if target < pos {
+				return Some(run_bit(marker));
+			}
+			let (lits, rest) = rest.split_at(lit_len(marker) as usize);
+			if let Some(lit) = lits.get((target - pos) as usize) {
+				return Some(lit >> (index % 64) & 1 != 0);
+			}
+			pos += lits.len() as u64;
+			words = rest;
+		}
+		unreachable!("the stream covers every index below its length");
+	}
+
+	/// Counts the bits in the bitmap that are set to `1`.
+	///
+	/// Clean runs are counted by their length, and only literal words are
+	/// inspected.
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		let mut count = 0;
+		let mut words = self.words.as_slice();
+		while let Some((&marker, rest)) = words.split_first() {
+			if run_bit(marker) {
+				count += run_len(marker) as usize * 64;
+			}
+			let (lits, rest) = rest.split_at(lit_len(marker) as usize);
+			count += lits
+				.iter()
+				.map(|lit| lit.count_ones() as usize)
+				.sum::<usize>();
+			words = rest;
+		}
+		count
+	}
+}
+
+/// Boolean arithmetic.
+///
+/// These operations combine two bitmaps without decompressing them. Where both
+/// are in clean runs, the overlap is produced as a single clean run; where one
+/// is in a clean run that decides the result on its own, the other’s literal
+/// words are skipped. The shorter bitmap is treated as extended with `0` bits.
+impl EwahBitmap {
+	/// Computes `self & !other`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::ewah::EwahBitmap;
+	///
+	/// let a = EwahBitmap::from_bitslice(bits![1, 1, 0, 0]);
+	/// let b = EwahBitmap::from_bitslice(bits![0, 1, 0, 1]);
+	/// assert_eq!(a.and_not(&b).to_bitvec(), bits![1, 0, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn and_not(&self, other: &Self) -> Self {
+		self.merge(other, |a, b| a & !b)
+	}
+
+	/// Combines two bitmaps with a bitwise operator.
+	///
+	/// `op` must map clean words to clean words, and a pair of `0` words to a
+	/// `0` word.
+	pub(crate) fn merge(&self, other: &Self, op: fn(u64, u64) -> u64) -> Self {
+		let len = cmp::max(self.len, other.len);
+		let mut left = word_count(len) as u64;
+		let (mut a, mut b) =
+			(Cursor::new(&self.words), Cursor::new(&other.words));
+		let mut out = Builder::new();
+
+		while left > 0 {
+			let count = match (a.run(), b.run()) {
+				(Some((x, m)), Some((y, n))) => {
+					let count = m.min(n).min(left);
+					out.push_run(op(fill(x), fill(y)) != 0, count);
+					count
+				},
+				(Some((x, m)), None) => {
+					let lits = b.lits();
+					let count = m.min(lits.len() as u64).min(left);
+					push_mixed(&mut out, x, &lits[.. count as usize], |r, l| {
+						op(r, l)
+					});
+					count
+				},
+				(None, Some((y, n))) => {
+					let lits = a.lits();
+					let count = n.min(lits.len() as u64).min(left);
+					push_mixed(&mut out, y, &lits[.. count as usize], |r, l| {
+						op(l, r)
+					});
+					count
+				},
+				(None, None) => {
+					let (x, y) = (a.lits(), b.lits());
+					let count = (x.len().min(y.len()) as u64).min(left);
+					for (&x, &y) in x.iter().zip(y).take(count as usize) {
+						out.push_word(op(x, y));
+					}
+					count
+				},
+			};
+			a.advance(count);
+			b.advance(count);
+			left -= count;
+		}
+
+		Self {
+			words: out.finish(),
+			len,
+		}
+	}
+}
+
+/// Combines a clean run with literal words, and appends the result.
+///
+/// The literals are skipped if the run alone decides the result, as when a
+/// `0` run is combined by `&`.
+fn push_mixed<F>(out: &mut Builder, bit: bool, lits: &[u64], op: F)
+where F: Fn(u64, u64) -> u64 {
+	let word = fill(bit);
+	let (zero, ones) = (op(word, 0), op(word, !0));
+	if zero == ones {
+		out.push_run(zero != 0, lits.len() as u64);
+	}
+	else {
+		for &lit in lits {
+			out.push_word(op(word, lit));
+		}
+	}
+}
+
+/// Counts the `u64` words needed to hold `len` bits.
+#[inline]
+fn word_count(len: usize) -> usize {
+	len / 64 + (len % 64 != 0) as usize
+}
@@ -0,0 +1,53 @@
+//! Operator trait implementations for compressed bitmaps.
+
+use core::ops::{
+	BitAnd,
+	BitOr,
+	BitXor,
+};
+
+use super::EwahBitmap;
+
+/// Computes the intersection of two bitmaps, without decompressing them.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::ewah::EwahBitmap;
+///
+/// let a = EwahBitmap::from_bitslice(bits![1, 1, 0, 0]);
+/// let b = EwahBitmap::from_bitslice(bits![0, 1, 0, 1]);
+/// assert_eq!((&a & &b).to_bitvec(), bits![0, 1, 0, 0]);
+/// assert_eq!((&a | &b).to_bitvec(), bits![1, 1, 0, 1]);
+/// assert_eq!((&a ^ &b).to_bitvec(), bits![1, 0, 0, 1]);
+/// ```
+impl BitAnd for &EwahBitmap {
+	type Output = EwahBitmap;
+
+	#[inline]
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.merge(rhs, |a, b| a & b)
+	}
+}
+
+/// Computes the union of two bitmaps, without decompressing them.
+impl BitOr for &EwahBitmap {
+	type Output = EwahBitmap;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.merge(rhs, |a, b| a | b)
+	}
+}
+
+/// Computes the symmetric difference of two bitmaps, without decompressing
+/// them.
+impl BitXor for &EwahBitmap {
+	type Output = EwahBitmap;
+
+	#[inline]
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self.merge(rhs, |a, b| a ^ b)
+	}
+}
@@ -0,0 +1,82 @@
+//! Unit tests for compressed bitmaps.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::*;
+use crate::prelude::*;
+
+/// Builds a random bit-vector of long runs, interspersed with noise.
+fn random_bits(len: usize) -> BitVec<u64, Lsb0> {
+	let mut bv = BitVec::with_capacity(len);
+	while bv.len() < len {
+		let run = (random::<usize>() % 300).min(len - bv.len());
+		match random::<u8>() % 3 {
+			0 => bv.resize(bv.len() + run, false),
+			1 => bv.resize(bv.len() + run, true),
+			_ => bv.extend((0 .. run).map(|_| random::<bool>())),
+		}
+	}
+	bv
+}
+
+#[test]
+fn compression() {
+	let empty = EwahBitmap::new();
+	assert!(empty.is_empty());
+	assert_eq!(empty, EwahBitmap::from_bitslice(bits![]));
+	assert_eq!(empty.to_bitvec(), bits![]);
+
+	//  All clean words collapse into one marker.
+	let zeros = EwahBitmap::from_bitslice(&bitvec![0; 640]);
+	assert_eq!(zeros.as_raw_slice(), [10 << 1]);
+	let ones = EwahBitmap::from_bitslice(&bitvec![1; 640]);
+	assert_eq!(ones.as_raw_slice(), [10 << 1 | 1]);
+	assert_eq!(ones.count_ones(), 640);
+
+	//  A partial final word is always a literal.
+	let bits = EwahBitmap::from_bitslice(&bitvec![1; 70]);
+	assert_eq!(bits.as_raw_slice(), [1 << 33 | 1 << 1 | 1, 0x3F]);
+	assert_eq!(bits.get(69), Some(true));
+	assert_eq!(bits.get(70), None);
+
+	for len in [0, 1, 63, 64, 65, 1000, 10_000] {
+		let bv = random_bits(len);
+		let ewah = EwahBitmap::from_bitslice(&bv);
+		assert_eq!(ewah.len(), len);
+		assert_eq!(ewah.to_bitvec(), bv);
+		assert_eq!(ewah.count_ones(), bv.count_ones());
+		for idx in (0 .. len).step_by(7) {
+			assert_eq!(ewah.get(idx), Some(bv[idx]));
+		}
+		//  Orderings other than `Lsb0` compress to the same stream.
+		let msb = bv.iter().by_vals().collect::<BitVec<u8, Msb0>>();
+		assert_eq!(EwahBitmap::from_bitslice(&msb), ewah);
+	}
+}
+
+#[test]
+fn boolean_ops() {
+	for (m, n) in [(1000, 1000), (10_000, 3000), (64, 6400)] {
+		let (a, b) = (random_bits(m), random_bits(n));
+		let (x, y) =
+			(EwahBitmap::from_bitslice(&a), EwahBitmap::from_bitslice(&b));
+
+		let mut wide = a.clone();
+		wide.resize(m.max(n), false);
+		let mut other = b.clone();
+		other.resize(m.max(n), false);
+
+		let and = wide.clone() & other.as_bitslice();
+		let or = wide.clone() | other.as_bitslice();
+		let xor = wide.clone() ^ other.as_bitslice();
+		let and_not = wide.clone() & (!other.clone()).as_bitslice();
+
+		assert_eq!(&x & &y, EwahBitmap::from_bitslice(&and));
+		assert_eq!(&x | &y, EwahBitmap::from_bitslice(&or));
+		assert_eq!(&x ^ &y, EwahBitmap::from_bitslice(&xor));
+		assert_eq!(x.and_not(&y), EwahBitmap::from_bitslice(&and_not));
+		assert_eq!((&y & &x).to_bitvec(), and);
+	}
+}
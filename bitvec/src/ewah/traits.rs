@@ -0,0 +1,83 @@
+//! General trait implementations for compressed bitmaps.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use super::EwahBitmap;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl Clone for EwahBitmap {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			words: self.words.clone(),
+			len:   self.len,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Default for EwahBitmap {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Eq for EwahBitmap {
+}
+
+/// The compressed form is canonical, so bitmaps with equal contents have equal
+/// marker-word streams.
+impl PartialEq for EwahBitmap {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.len == other.len && self.words == other.words
+	}
+}
+
+impl Hash for EwahBitmap {
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.len.hash(hasher);
+		self.words.hash(hasher);
+	}
+}
+
+impl Debug for EwahBitmap {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("EwahBitmap")
+			.field("len", &self.len)
+			.field("words", &self.words.len())
+			.field("ones", &self.count_ones())
+			.finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<&BitSlice<T, O>> for EwahBitmap
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice(bits)
+	}
+}
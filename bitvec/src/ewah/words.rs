@@ -0,0 +1,233 @@
+//! Reading and writing the marker-word stream.
+//!
+//! A compressed bitmap is a sequence of groups. Each group begins with a marker
+//! word that describes a run of clean words, all `0` or all `1`, and counts the
+//! dirty literal words that follow the marker. The marker word is laid out as:
+//!
+//! - bit 0: the value of the clean run;
+//! - bits 1 through 32: the number of clean words in the run;
+//! - bits 33 through 63: the number of literal words after the marker.
+
+use alloc::vec::Vec;
+
+/// The largest number of clean words that one marker can describe.
+const RUN_MAX: u64 = (1 << 32) - 1;
+
+/// The largest number of literal words that can follow one marker.
+const LIT_MAX: u64 = (1 << 31) - 1;
+
+/// The bit-index of the literal count in a marker word.
+const LIT_SHIFT: u32 = 33;
+
+/// Gets the value of a marker’s clean run.
+#[inline]
+pub(super) fn run_bit(marker: u64) -> bool {
+	marker & 1 != 0
+}
+
+/// Gets the number of clean words described by a marker.
+#[inline]
+pub(super) fn run_len(marker: u64) -> u64 {
+	(marker >> 1) & RUN_MAX
+}
+
+/// Gets the number of literal words that follow a marker.
+#[inline]
+pub(super) fn lit_len(marker: u64) -> u64 {
+	marker >> LIT_SHIFT
+}
+
+/// Fills a word with a clean run’s value.
+#[inline]
+pub(super) fn fill(bit: bool) -> u64 {
+	if bit {
+		!0
+	}
+	else {
+		0
+	}
+}
+
+/// Produces a canonical marker-word stream.
+///
+/// Clean literals are folded into runs, and a run is only started in a new
+/// marker when the current one cannot describe it.
+#[derive(Clone, Debug)]
+pub(super) struct Builder {
+	/// The stream produced so far.
+	words:   Vec<u64>,
+	/// The index in `words` of the last marker.
+	marker:  usize,
+	/// Bits that have been pushed but do not yet fill a word.
+	partial: u64,
+	/// The number of bits in `partial`.
+	filled:  u32,
+}
+
+impl Builder {
+	/// Begins a new stream.
+	pub(super) fn new() -> Self {
+		Self {
+			words:   alloc::vec![0],
+			marker:  0,
+			partial: 0,
+			filled:  0,
+		}
+	}
+
+	/// Appends `count` clean words of `bit`.
+	pub(super) fn push_run(&mut self, bit: bool, mut count: u64) {
+		while count > 0 {
+			let marker = self.words[self.marker];
+			let len = run_len(marker);
+			//  A run can only extend a marker that has no literals yet, and
+			//  whose own run is empty or has the same value.
+			if lit_len(marker) != 0
+				|| (len != 0 && run_bit(marker) != bit)
+				|| len == RUN_MAX
+			{
+				self.marker = self.words.len();
+				self.words.push(0);
+				continue;
+			}
+			let take = count.min(RUN_MAX - len);
+			self.words[self.marker] = (len + take) << 1 | bit as u64;
+			count -= take;
+		}
+	}
+
+	/// Appends a single word.
+	pub(super) fn push_word(&mut self, word: u64) {
+		if word == 0 || word == !0 {
+			return self.push_run(word != 0, 1);
+		}
+		if lit_len(self.words[self.marker]) == LIT_MAX {
+			self.marker = self.words.len();
+			self.words.push(0);
+		}
+		self.words[self.marker] += 1 << LIT_SHIFT;
+		self.words.push(word);
+	}
+
+	/// Appends `count` bits of `bit`. Words are filled from their least
+	/// significant bit.
+	pub(super) fn push_bits(&mut self, bit: bool, mut count: usize) {
+		if self.filled != 0 {
+			let take = count.min(64 - self.filled as usize) as u32;
+			if bit {
+				self.partial |= (fill(true) >> (64 - take)) << self.filled;
+			}
+			self.filled += take;
+			count -= take as usize;
+			if self.filled == 64 {
+				self.push_word(self.partial);
+				self.partial = 0;
+				self.filled = 0;
+			}
+		}
+		if count == 0 {
+			return;
+		}
+		self.push_run(bit, (count / 64) as u64);
+		let rest = (count % 64) as u32;
+		if rest != 0 {
+			self.partial = if bit { fill(true) >> (64 - rest) } else { 0 };
+			self.filled = rest;
+		}
+	}
+
+	/// Ends the stream, flushing any partially-filled word.
+	pub(super) fn finish(mut self) -> Vec<u64> {
+		if self.filled != 0 {
+			self.push_word(self.partial);
+		}
+		self.words
+	}
+}
+
+/// Walks a marker-word stream, one run or literal at a time.
+///
+/// Once the stream is exhausted, it behaves as an endless run of `0` words, so
+/// that streams of different lengths can be combined.
+#[derive(Clone, Debug)]
+pub(super) struct Cursor<'a> {
+	/// The groups that have not yet been entered.
+	words: &'a [u64],
+	/// The value of the current clean run.
+	bit:   bool,
+	/// The number of words remaining in the current clean run.
+	run:   u64,
+	/// The literal words remaining in the current group, after the run.
+	lits:  &'a [u64],
+}
+
+impl<'a> Cursor<'a> {
+	/// Begins walking a stream.
+	pub(super) fn new(words: &'a [u64]) -> Self {
+		let mut out = Self {
+			words,
+			bit: false,
+			run: 0,
+			lits: &[],
+		};
+		out.load();
+		out
+	}
+
+	/// Enters groups until one with remaining words is found.
+	fn load(&mut self) {
+		while self.run == 0 && self.lits.is_empty() {
+			let (&marker, rest) = match self.words.split_first() {
+				Some(pair) => pair,
+				None => {
+					self.bit = false;
+					self.run = u64::MAX;
+					return;
+				},
+			};
+			let (lits, rest) = rest.split_at(lit_len(marker) as usize);
+			self.bit = run_bit(marker);
+			self.run = run_len(marker);
+			self.lits = lits;
+			self.words = rest;
+		}
+	}
+
+	/// Gets the current clean run, if the cursor is in one, as its value and
+	/// remaining length.
+	pub(super) fn run(&self) -> Option<(bool, u64)> {
+		if self.run != 0 {
+			Some((self.bit, self.run))
+		}
+		else {
+			None
+		}
+	}
+
+	/// Gets the literal words at the cursor. This is empty while the cursor is
+	/// in a clean run.
+	pub(super) fn lits(&self) -> &'a [u64] {
+		if self.run != 0 {
+			&[]
+		}
+		else {
+			self.lits
+		}
+	}
+
+	/// Moves the cursor forward by `count` words. This must not move past the
+	/// end of the current run, or of the current literals.
+	pub(super) fn advance(&mut self, count: u64) {
+		if self.run != 0 {
+			debug_assert!(count <= self.run, "advanced past a run");
+			//  The endless run after the stream never runs out.
+			if self.run != u64::MAX {
+				self.run -= count;
+			}
+		}
+		else {
+			self.lits = &self.lits[count as usize ..];
+		}
+		self.load();
+	}
+}
@@ -1,6 +1,12 @@
 #![doc = include_str!("../doc/field.md")]
 
 use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
 	mem,
 	ptr,
 };
@@ -19,11 +25,13 @@ use crate::{
 		Domain,
 		PartialElement,
 	},
-	mem::bits_of,
+	mem::{
+		bits_of,
+		BitRegister,
+	},
 	order::{
 		BitOrder,
-		Lsb0,
-		Msb0,
+		Contiguity,
 	},
 	slice::BitSlice,
 	store::BitStore,
@@ -35,6 +43,7 @@ use crate::{
 	vec::BitVec,
 };
 
+mod codec;
 mod io;
 mod tests;
 
@@ -109,285 +118,220 @@ pub trait BitField {
 	where I: Integral;
 }
 
-#[doc = include_str!("../doc/field/BitField_Lsb0.md")]
-impl<T> BitField for BitSlice<T, Lsb0>
-where T: BitStore
-{
+#[doc = include_str!("../doc/field/TryBitField.md")]
+pub trait TryBitField: BitField {
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_load_le.md")]
-	fn load_le<I>(&self) -> I
+	#[cfg(not(tarpaulin_include))]
+	#[doc = include_str!("../doc/field/TryBitField_load.md")]
+	fn try_load<I>(&self) -> Result<I, BitFieldError>
 	where I: Integral {
-		let len = self.len();
-		check::<I>("load", len);
-
-		match self.domain() {
-			//  In Lsb0, the head counts distance from LSedge to first live bit.
-			Domain::Enclave(elem) => get(elem, elem.head().into_inner()),
-			Domain::Region { head, body, tail } => {
-				let mut accum = I::ZERO;
-
-				if let Some(elem) = tail {
-					accum = get(elem, 0);
-				}
-
-				for elem in body.iter().rev().map(BitStore::load_value) {
-					maybe_shift_left(&mut accum, bits_of::<T>());
-					accum |= resize::<T::Mem, I>(elem);
-				}
-
-				if let Some(elem) = head {
-					let shamt = elem.head().into_inner();
-					maybe_shift_left(
-						&mut accum,
-						bits_of::<T>() - shamt as usize,
-					);
-					accum |= get::<_, _, I>(elem, shamt);
-				}
-
-				accum
-			},
+		if cfg!(target_endian = "big") {
+			self.try_load_be::<I>()
+		}
+		else {
+			self.try_load_le::<I>()
 		}
-		.pipe(|elem| sign(elem, len))
 	}
 
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_load_be.md")]
-	fn load_be<I>(&self) -> I
+	#[cfg(not(tarpaulin_include))]
+	#[doc = include_str!("../doc/field/TryBitField_store.md")]
+	fn try_store<I>(&mut self, value: I) -> Result<(), BitFieldError>
 	where I: Integral {
-		let len = self.len();
-		check::<I>("load", len);
+		if cfg!(target_endian = "big") {
+			self.try_store_be::<I>(value)
+		}
+		else {
+			self.try_store_le::<I>(value)
+		}
+	}
 
-		match self.domain() {
-			Domain::Enclave(elem) => get(elem, elem.head().into_inner()),
-			Domain::Region { head, body, tail } => {
-				let mut accum = I::ZERO;
+	#[doc = include_str!("../doc/field/TryBitField_load_le.md")]
+	fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral;
 
-				if let Some(elem) = head {
-					accum = get(elem, elem.head().into_inner());
-				}
+	#[doc = include_str!("../doc/field/TryBitField_load_be.md")]
+	fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral;
 
-				for elem in body.iter().map(BitStore::load_value) {
-					maybe_shift_left(&mut accum, bits_of::<T>());
-					accum |= resize::<T::Mem, I>(elem);
-				}
+	#[doc = include_str!("../doc/field/TryBitField_store_le.md")]
+	fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral;
 
-				if let Some(elem) = tail {
-					let shamt = elem.tail().into_inner() as usize;
-					maybe_shift_left(&mut accum, shamt);
-					accum |= get::<_, _, I>(elem, 0);
-				}
+	#[doc = include_str!("../doc/field/TryBitField_store_be.md")]
+	fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral;
+}
 
-				accum
-			},
-		}
-		.pipe(|elem| sign(elem, len))
-	}
+#[doc = include_str!("../doc/field/BitFieldError.md")]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BitFieldError {
+	/// The bit-slice is empty, or is wider than the integer it transfers.
+	Width {
+		/// The number of bits in the bit-slice.
+		requested: usize,
+		/// The number of bits in the integer.
+		available: usize,
+	},
+	/// A value has significant bits that the bit-slice cannot store.
+	Overflow {
+		/// The number of bits in the bit-slice.
+		width: usize,
+	},
+}
 
+#[cfg(not(tarpaulin_include))]
+impl Debug for BitFieldError {
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_store_le.md")]
-	fn store_le<I>(&mut self, mut value: I)
-	where I: Integral {
-		check::<I>("store", self.len());
-
-		match self.domain_mut() {
-			Domain::Enclave(elem) => {
-				let shamt = elem.head().into_inner();
-				set(elem, value, shamt);
-			},
-			Domain::Region { head, body, tail } => {
-				if let Some(elem) = head {
-					let shamt = elem.head().into_inner();
-					set(elem, value, shamt);
-					let rshamt = bits_of::<T>() - shamt as usize;
-					maybe_shift_right(&mut value, rshamt);
-				}
-
-				for elem in body.iter_mut() {
-					elem.store_value(resize(value));
-					maybe_shift_right(&mut value, bits_of::<T>());
-				}
-
-				if let Some(elem) = tail {
-					set(elem, value, 0);
-				}
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("BitFieldError::")?;
+		match *self {
+			Self::Width {
+				requested,
+				available,
+			} => fmt
+				.debug_struct("Width")
+				.field("requested", &requested)
+				.field("available", &available)
+				.finish(),
+			Self::Overflow { width } => {
+				fmt.debug_struct("Overflow").field("width", &width).finish()
 			},
 		}
 	}
+}
 
+#[cfg(not(tarpaulin_include))]
+impl Display for BitFieldError {
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_store_be.md")]
-	fn store_be<I>(&mut self, mut value: I)
-	where I: Integral {
-		check::<I>("store", self.len());
-
-		match self.domain_mut() {
-			Domain::Enclave(elem) => {
-				let shamt = elem.head().into_inner();
-				set(elem, value, shamt);
-			},
-			Domain::Region { head, body, tail } => {
-				if let Some(elem) = tail {
-					let shamt = elem.tail().into_inner() as usize;
-					set(elem, value, 0);
-					maybe_shift_right(&mut value, shamt);
-				}
-
-				for elem in body.iter_mut().rev() {
-					elem.store_value(resize(value));
-					maybe_shift_right(&mut value, bits_of::<T>());
-				}
-
-				if let Some(elem) = head {
-					let shamt = elem.head().into_inner();
-					set(elem, value, shamt);
-				}
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Width {
+				requested,
+				available,
+			} => write!(
+				fmt,
+				"cannot transfer {} bits through a {}-bit integer",
+				requested, available,
+			),
+			Self::Overflow { width } => {
+				write!(fmt, "the value does not fit in a {}-bit region", width)
 			},
 		}
 	}
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for BitFieldError {}
+
+#[doc = include_str!("../doc/field/BitField_BitSlice.md")]
+#[doc = include_str!("../doc/field/BitField_Lsb0.md")]
 #[doc = include_str!("../doc/field/BitField_Msb0.md")]
-impl<T> BitField for BitSlice<T, Msb0>
-where T: BitStore
+impl<T, O> BitField for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
 {
 	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_load_le.md")]
 	#[doc = include_str!("../doc/field/BitField_Msb0_load_le.md")]
 	fn load_le<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
 
-		match self.domain() {
-			Domain::Enclave(elem) => {
-				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-				get(elem, shamt)
-			},
-			Domain::Region { head, body, tail } => {
-				let mut accum = I::ZERO;
-
-				if let Some(elem) = tail {
-					let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-					accum = get(elem, shamt);
-				}
-
-				for elem in body.iter().rev().map(BitStore::load_value) {
-					maybe_shift_left(&mut accum, bits_of::<T>());
-					accum |= resize::<T::Mem, I>(elem);
-				}
-
-				if let Some(elem) = head {
-					let shamt =
-						bits_of::<T>() - elem.head().into_inner() as usize;
-					maybe_shift_left(&mut accum, shamt);
-					accum |= get::<_, _, I>(elem, 0);
-				}
-
-				accum
-			},
+		match O::CONTIGUITY {
+			Contiguity::Ascending => load_le_ascending::<_, _, I>(self),
+			Contiguity::Descending => load_le_descending::<_, _, I>(self),
+			Contiguity::Scattered => load_gathered::<_, I>(segments(self).rev()),
 		}
 		.pipe(|elem| sign(elem, len))
 	}
 
 	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_load_be.md")]
 	#[doc = include_str!("../doc/field/BitField_Msb0_load_be.md")]
 	fn load_be<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
 
-		match self.domain() {
-			Domain::Enclave(elem) => {
-				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-				get(elem, shamt)
-			},
-			Domain::Region { head, body, tail } => {
-				let mut accum = I::ZERO;
-
-				if let Some(elem) = head {
-					accum = get(elem, 0);
-				}
-
-				for elem in body.iter().map(BitStore::load_value) {
-					maybe_shift_left(&mut accum, bits_of::<T>());
-					accum |= resize::<T::Mem, I>(elem);
-				}
-
-				if let Some(elem) = tail {
-					let shamt = elem.tail().into_inner();
-					maybe_shift_left(&mut accum, shamt as usize);
-					accum |= get::<_, _, I>(elem, bits_of::<T>() as u8 - shamt);
-				}
-
-				accum
-			},
+		match O::CONTIGUITY {
+			Contiguity::Ascending => load_be_ascending::<_, _, I>(self),
+			Contiguity::Descending => load_be_descending::<_, _, I>(self),
+			Contiguity::Scattered => load_gathered::<_, I>(segments(self)),
 		}
 		.pipe(|elem| sign(elem, len))
 	}
 
 	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_store_le.md")]
 	#[doc = include_str!("../doc/field/BitField_Msb0_store_le.md")]
-	fn store_le<I>(&mut self, mut value: I)
+	fn store_le<I>(&mut self, value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
-		match self.domain_mut() {
-			Domain::Enclave(elem) => {
-				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-				set(elem, value, shamt);
-			},
-			Domain::Region { head, body, tail } => {
-				if let Some(elem) = head {
-					let shamt =
-						bits_of::<T>() - elem.head().into_inner() as usize;
-					set(elem, value, 0);
-					maybe_shift_right(&mut value, shamt);
-				}
-
-				for elem in body.iter_mut() {
-					elem.store_value(resize(value));
-					maybe_shift_right(&mut value, bits_of::<T>());
-				}
-
-				if let Some(elem) = tail {
-					let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-					set(elem, value, shamt);
-				}
-			},
+		match O::CONTIGUITY {
+			Contiguity::Ascending => store_le_ascending(self, value),
+			Contiguity::Descending => store_le_descending(self, value),
+			Contiguity::Scattered => store_scattered(self, value, true),
 		}
 	}
 
 	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_store_be.md")]
 	#[doc = include_str!("../doc/field/BitField_Msb0_store_be.md")]
-	fn store_be<I>(&mut self, mut value: I)
+	fn store_be<I>(&mut self, value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
-		match self.domain_mut() {
-			Domain::Enclave(elem) => {
-				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
-				set(elem, value, shamt);
-			},
-			Domain::Region { head, body, tail } => {
-				if let Some(elem) = tail {
-					let tail = elem.tail().into_inner() as usize;
-					let shamt = bits_of::<T>() - tail;
-					set(elem, value, shamt as u8);
-					maybe_shift_right(&mut value, tail);
-				}
-
-				for elem in body.iter_mut().rev() {
-					elem.store_value(resize(value));
-					maybe_shift_right(&mut value, bits_of::<T>());
-				}
-
-				if let Some(elem) = head {
-					set(elem, value, 0);
-				}
-			},
+		match O::CONTIGUITY {
+			Contiguity::Ascending => store_be_ascending(self, value),
+			Contiguity::Descending => store_be_descending(self, value),
+			Contiguity::Scattered => store_scattered(self, value, false),
 		}
 	}
 }
 
+impl<T, O> TryBitField for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		try_check::<I>(self.len())?;
+		Ok(self.load_le::<I>())
+	}
+
+	#[inline]
+	fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		try_check::<I>(self.len())?;
+		Ok(self.load_be::<I>())
+	}
+
+	#[inline]
+	fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		try_check::<I>(self.len())?;
+		try_fit(value, self.len())?;
+		self.store_le::<I>(value);
+		Ok(())
+	}
+
+	#[inline]
+	fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		try_check::<I>(self.len())?;
+		try_fit(value, self.len())?;
+		self.store_be::<I>(value);
+		Ok(())
+	}
+}
+
 #[doc = include_str!("../doc/field/impl_BitArray.md")]
 impl<A, O> BitField for BitArray<A, O>
 where
@@ -440,6 +384,38 @@ where
 	}
 }
 
+#[doc = include_str!("../doc/field/impl_BitArray_TryBitField.md")]
+impl<A, O> TryBitField for BitArray<A, O>
+where
+	O: BitOrder,
+	A: BitViewSized,
+	BitSlice<A::Store, O>: TryBitField,
+{
+	#[inline(always)]
+	fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_le()
+	}
+
+	#[inline(always)]
+	fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_be()
+	}
+
+	#[inline(always)]
+	fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_le(value)
+	}
+
+	#[inline(always)]
+	fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_be(value)
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg(not(tarpaulin_include))]
 impl<T, O> BitField for BitBox<T, O>
@@ -473,6 +449,39 @@ where
 	}
 }
 
+#[cfg(feature = "alloc")]
+#[cfg(not(tarpaulin_include))]
+impl<T, O> TryBitField for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: TryBitField,
+{
+	#[inline(always)]
+	fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_le()
+	}
+
+	#[inline(always)]
+	fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_be()
+	}
+
+	#[inline(always)]
+	fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_le(value)
+	}
+
+	#[inline(always)]
+	fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_be(value)
+	}
+}
+
 #[cfg(feature = "alloc")]
 #[cfg(not(tarpaulin_include))]
 impl<T, O> BitField for BitVec<T, O>
@@ -506,6 +515,39 @@ where
 	}
 }
 
+#[cfg(feature = "alloc")]
+#[cfg(not(tarpaulin_include))]
+impl<T, O> TryBitField for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: TryBitField,
+{
+	#[inline(always)]
+	fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_le()
+	}
+
+	#[inline(always)]
+	fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		self.as_bitslice().try_load_be()
+	}
+
+	#[inline(always)]
+	fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_le(value)
+	}
+
+	#[inline(always)]
+	fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		self.as_mut_bitslice().try_store_be(value)
+	}
+}
+
 /** Asserts that a bit-slice is not longer than a memory element.
 
 ## Type Parameters
@@ -532,6 +574,420 @@ where I: Integral {
 	);
 }
 
+/// Checks that a bit-slice of length `len` can transfer an `I`.
+fn try_check<I>(len: usize) -> Result<(), BitFieldError>
+where I: Integral {
+	let available = bits_of::<I>();
+	if !(1 ..= available).contains(&len) {
+		return Err(BitFieldError::Width {
+			requested: len,
+			available,
+		});
+	}
+	Ok(())
+}
+
+/// Checks that `value` survives being stored into `width` bits and loaded back
+/// out, which zero-extends unsigned integers and sign-extends signed integers.
+///
+/// `width` must be in `1 ..= I::BITS`.
+fn try_fit<I>(value: I, width: usize) -> Result<(), BitFieldError>
+where I: Integral {
+	let shamt = bits_of::<I>() - width;
+	if value << shamt >> shamt != value {
+		return Err(BitFieldError::Overflow { width });
+	}
+	Ok(())
+}
+
+/// Loads a little-endian integer from a bit-slice laid out as `Lsb0` is.
+fn load_le_ascending<T, O, I>(bits: &BitSlice<T, O>) -> I
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain() {
+		//  In Lsb0, the head counts distance from LSedge to first live bit.
+		Domain::Enclave(elem) => get(elem, elem.head().into_inner()),
+		Domain::Region { head, body, tail } => {
+			let mut accum = I::ZERO;
+
+			if let Some(elem) = tail {
+				accum = get(elem, 0);
+			}
+
+			for elem in body.iter().rev().map(BitStore::load_value) {
+				maybe_shift_left(&mut accum, bits_of::<T>());
+				accum |= resize::<T::Mem, I>(elem);
+			}
+
+			if let Some(elem) = head {
+				let shamt = elem.head().into_inner();
+				maybe_shift_left(&mut accum, bits_of::<T>() - shamt as usize);
+				accum |= get::<_, _, I>(elem, shamt);
+			}
+
+			accum
+		},
+	}
+}
+
+/// Loads a big-endian integer from a bit-slice laid out as `Lsb0` is.
+fn load_be_ascending<T, O, I>(bits: &BitSlice<T, O>) -> I
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain() {
+		Domain::Enclave(elem) => get(elem, elem.head().into_inner()),
+		Domain::Region { head, body, tail } => {
+			let mut accum = I::ZERO;
+
+			if let Some(elem) = head {
+				accum = get(elem, elem.head().into_inner());
+			}
+
+			for elem in body.iter().map(BitStore::load_value) {
+				maybe_shift_left(&mut accum, bits_of::<T>());
+				accum |= resize::<T::Mem, I>(elem);
+			}
+
+			if let Some(elem) = tail {
+				let shamt = elem.tail().into_inner() as usize;
+				maybe_shift_left(&mut accum, shamt);
+				accum |= get::<_, _, I>(elem, 0);
+			}
+
+			accum
+		},
+	}
+}
+
+/// Stores a little-endian integer into a bit-slice laid out as `Lsb0` is.
+fn store_le_ascending<T, O, I>(bits: &mut BitSlice<T, O>, mut value: I)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain_mut() {
+		Domain::Enclave(elem) => {
+			let shamt = elem.head().into_inner();
+			set(elem, value, shamt);
+		},
+		Domain::Region { head, body, tail } => {
+			if let Some(elem) = head {
+				let shamt = elem.head().into_inner();
+				set(elem, value, shamt);
+				let rshamt = bits_of::<T>() - shamt as usize;
+				maybe_shift_right(&mut value, rshamt);
+			}
+
+			for elem in body.iter_mut() {
+				elem.store_value(resize(value));
+				maybe_shift_right(&mut value, bits_of::<T>());
+			}
+
+			if let Some(elem) = tail {
+				set(elem, value, 0);
+			}
+		},
+	}
+}
+
+/// Stores a big-endian integer into a bit-slice laid out as `Lsb0` is.
+fn store_be_ascending<T, O, I>(bits: &mut BitSlice<T, O>, mut value: I)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain_mut() {
+		Domain::Enclave(elem) => {
+			let shamt = elem.head().into_inner();
+			set(elem, value, shamt);
+		},
+		Domain::Region { head, body, tail } => {
+			if let Some(elem) = tail {
+				let shamt = elem.tail().into_inner() as usize;
+				set(elem, value, 0);
+				maybe_shift_right(&mut value, shamt);
+			}
+
+			for elem in body.iter_mut().rev() {
+				elem.store_value(resize(value));
+				maybe_shift_right(&mut value, bits_of::<T>());
+			}
+
+			if let Some(elem) = head {
+				let shamt = elem.head().into_inner();
+				set(elem, value, shamt);
+			}
+		},
+	}
+}
+
+/// Loads a little-endian integer from a bit-slice laid out as `Msb0` is.
+fn load_le_descending<T, O, I>(bits: &BitSlice<T, O>) -> I
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain() {
+		Domain::Enclave(elem) => {
+			let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+			get(elem, shamt)
+		},
+		Domain::Region { head, body, tail } => {
+			let mut accum = I::ZERO;
+
+			if let Some(elem) = tail {
+				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+				accum = get(elem, shamt);
+			}
+
+			for elem in body.iter().rev().map(BitStore::load_value) {
+				maybe_shift_left(&mut accum, bits_of::<T>());
+				accum |= resize::<T::Mem, I>(elem);
+			}
+
+			if let Some(elem) = head {
+				let shamt = bits_of::<T>() - elem.head().into_inner() as usize;
+				maybe_shift_left(&mut accum, shamt);
+				accum |= get::<_, _, I>(elem, 0);
+			}
+
+			accum
+		},
+	}
+}
+
+/// Loads a big-endian integer from a bit-slice laid out as `Msb0` is.
+fn load_be_descending<T, O, I>(bits: &BitSlice<T, O>) -> I
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain() {
+		Domain::Enclave(elem) => {
+			let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+			get(elem, shamt)
+		},
+		Domain::Region { head, body, tail } => {
+			let mut accum = I::ZERO;
+
+			if let Some(elem) = head {
+				accum = get(elem, 0);
+			}
+
+			for elem in body.iter().map(BitStore::load_value) {
+				maybe_shift_left(&mut accum, bits_of::<T>());
+				accum |= resize::<T::Mem, I>(elem);
+			}
+
+			if let Some(elem) = tail {
+				let shamt = elem.tail().into_inner();
+				maybe_shift_left(&mut accum, shamt as usize);
+				accum |= get::<_, _, I>(elem, bits_of::<T>() as u8 - shamt);
+			}
+
+			accum
+		},
+	}
+}
+
+/// Stores a little-endian integer into a bit-slice laid out as `Msb0` is.
+fn store_le_descending<T, O, I>(bits: &mut BitSlice<T, O>, mut value: I)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain_mut() {
+		Domain::Enclave(elem) => {
+			let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+			set(elem, value, shamt);
+		},
+		Domain::Region { head, body, tail } => {
+			if let Some(elem) = head {
+				let shamt = bits_of::<T>() - elem.head().into_inner() as usize;
+				set(elem, value, 0);
+				maybe_shift_right(&mut value, shamt);
+			}
+
+			for elem in body.iter_mut() {
+				elem.store_value(resize(value));
+				maybe_shift_right(&mut value, bits_of::<T>());
+			}
+
+			if let Some(elem) = tail {
+				let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+				set(elem, value, shamt);
+			}
+		},
+	}
+}
+
+/// Stores a big-endian integer into a bit-slice laid out as `Msb0` is.
+fn store_be_descending<T, O, I>(bits: &mut BitSlice<T, O>, mut value: I)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	match bits.domain_mut() {
+		Domain::Enclave(elem) => {
+			let shamt = bits_of::<T>() as u8 - elem.tail().into_inner();
+			set(elem, value, shamt);
+		},
+		Domain::Region { head, body, tail } => {
+			if let Some(elem) = tail {
+				let tail = elem.tail().into_inner() as usize;
+				let shamt = bits_of::<T>() - tail;
+				set(elem, value, shamt as u8);
+				maybe_shift_right(&mut value, tail);
+			}
+
+			for elem in body.iter_mut().rev() {
+				elem.store_value(resize(value));
+				maybe_shift_right(&mut value, bits_of::<T>());
+			}
+
+			if let Some(elem) = head {
+				set(elem, value, 0);
+			}
+		},
+	}
+}
+
+/// Splits a bit-slice into the memory elements it touches, from the lowest
+/// address to the highest, as pairs of each element’s value and the mask of its
+/// live bits.
+fn segments<T, O>(
+	bits: &BitSlice<T, O>,
+) -> impl '_ + DoubleEndedIterator<Item = (T::Mem, T::Mem)>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let (head, body, tail) = match bits.domain() {
+		Domain::Enclave(elem) => (Some(elem), &[][..], None),
+		Domain::Region { head, body, tail } => (head, body, tail),
+	};
+	let partial = |elem: PartialElement<Const, T, O>| {
+		(elem.load_value(), elem.mask().into_inner())
+	};
+	head.map(partial)
+		.into_iter()
+		.chain(
+			body.iter()
+				.map(|elem| (elem.load_value(), <T::Mem as BitRegister>::ALL)),
+		)
+		.chain(tail.map(partial))
+}
+
+/// Loads an integer from memory elements whose live bits may be scattered.
+///
+/// Each element contributes its live bits, gathered into a contiguous segment
+/// in their order of significance. `segments` yields the elements from the
+/// most significant to the least.
+fn load_gathered<M, I>(segments: impl Iterator<Item = (M, M)>) -> I
+where
+	M: Integral,
+	I: Integral,
+{
+	let mut accum = I::ZERO;
+	for (value, mask) in segments {
+		maybe_shift_left(&mut accum, mask.count_ones() as usize);
+		accum |= resize::<M, I>(gather(value, mask));
+	}
+	accum
+}
+
+/// Stores an integer into memory elements whose live bits may be scattered.
+///
+/// This is the inverse of [`load_gathered`]: each element, from the least
+/// significant to the most, receives the next segment of `value` spread across
+/// its live bits.
+fn store_scattered<T, O, I>(
+	bits: &mut BitSlice<T, O>,
+	mut value: I,
+	little_endian: bool,
+) where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	let (head, body, tail) = match bits.domain_mut() {
+		Domain::Enclave(elem) => (Some(elem), &mut [][..], None),
+		Domain::Region { head, body, tail } => (head, body, tail),
+	};
+	let mut next = |mask: T::Mem| {
+		let out = scatter(resize::<I, T::Mem>(value), mask);
+		maybe_shift_right(&mut value, mask.count_ones() as usize);
+		out
+	};
+	let (low, high) = if little_endian {
+		(head, tail)
+	}
+	else {
+		(tail, head)
+	};
+
+	if let Some(mut elem) = low {
+		elem.store_value(next(elem.mask().into_inner()));
+	}
+	if little_endian {
+		for elem in body.iter_mut() {
+			elem.store_value(next(<T::Mem as BitRegister>::ALL));
+		}
+	}
+	else {
+		for elem in body.iter_mut().rev() {
+			elem.store_value(next(<T::Mem as BitRegister>::ALL));
+		}
+	}
+	if let Some(mut elem) = high {
+		elem.store_value(next(elem.mask().into_inner()));
+	}
+}
+
+/// Gathers the bits of `value` selected by `mask` into the low bits of the
+/// result, preserving their order of significance.
+fn gather<M>(value: M, mut mask: M) -> M
+where M: Integral {
+	let (mut out, mut dest) = (M::ZERO, M::ONE);
+	while mask != M::ZERO {
+		let low = mask & !(mask - M::ONE);
+		if value & low != M::ZERO {
+			out |= dest;
+		}
+		mask ^= low;
+		dest <<= 1;
+	}
+	out
+}
+
+/// Scatters the low bits of `value` into the positions selected by `mask`,
+/// preserving their order of significance. This is the inverse of [`gather`].
+fn scatter<M>(value: M, mut mask: M) -> M
+where M: Integral {
+	let (mut out, mut src) = (M::ZERO, M::ONE);
+	while mask != M::ZERO {
+		let low = mask & !(mask - M::ONE);
+		if value & src != M::ZERO {
+			out |= low;
+		}
+		mask ^= low;
+		src <<= 1;
+	}
+	out
+}
+
 /// Shifts a value to the left, if it can support the shift amount.
 fn maybe_shift_left<T: Integral>(elem: &mut T, shamt: usize) {
 	if bits_of::<T>() > shamt {
@@ -567,7 +1023,7 @@ where
 }
 
 #[doc = include_str!("../doc/field/sign.md")]
-fn sign<I>(elem: I, width: usize) -> I
+pub(crate) fn sign<I>(elem: I, width: usize) -> I
 where I: Integral {
 	if dvl::is_unsigned::<I>() {
 		return elem;
@@ -581,7 +1037,7 @@ where I: Integral {
 }
 
 #[doc = include_str!("../doc/field/resize.md")]
-fn resize<T, U>(value: T) -> U
+pub(crate) fn resize<T, U>(value: T) -> U
 where
 	T: Integral,
 	U: Integral,
@@ -0,0 +1,1228 @@
+#![doc = include_str!("../../doc/field/codec.md")]
+
+use core::array;
+
+use funty::{
+	Integral,
+	Signed,
+	Unsigned,
+};
+
+use super::{
+	check,
+	resize,
+	BitField,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	stream::Endian,
+};
+
+/// Floating-point numbers.
+///
+/// A float is loaded by loading its IEEE 754 bit-pattern as an unsigned
+/// integer, so the bit-slice must be exactly as wide as the float.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads an IEEE 754 half-precision float, using [`.load()`] significance
+	/// ordering, and widens it to `f32`.
+	///
+	/// Every half-precision value, including subnormals, infinities, and NaNs,
+	/// is exactly representable in `f32`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_f16(&self) -> f32 {
+		self.load_f16_with(native())
+	}
+
+	/// Loads an IEEE 754 half-precision float, using [`.load_le()`]
+	/// significance ordering, and widens it to `f32`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_f16_le(&self) -> f32 {
+		self.load_f16_with(Endian::Little)
+	}
+
+	/// Loads an IEEE 754 half-precision float, using [`.load_be()`]
+	/// significance ordering, and widens it to `f32`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u8, Msb0;
+	///   0, 0, 0, 0,
+	///   1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+	/// ];
+	/// assert_eq!(bits[4 ..].load_f16_be(), -5.0);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_f16_be(&self) -> f32 {
+		self.load_f16_with(Endian::Big)
+	}
+
+	/// Stores an `f32` as an IEEE 754 half-precision float, using
+	/// [`.store()`] significance ordering.
+	///
+	/// The value is rounded to the nearest half-precision value, with ties
+	/// going to the value with an even significand. Values too large to
+	/// represent become infinite, and values too small become zero with the
+	/// original sign.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_f16(&mut self, value: f32) {
+		self.store_f16_with(native(), value);
+	}
+
+	/// Stores an `f32` as an IEEE 754 half-precision float, using
+	/// [`.store_le()`] significance ordering.
+	///
+	/// This rounds as [`.store_f16()`] does.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut u16, Lsb0; 0; 16];
+	/// bits.store_f16_le(65504.0);
+	/// assert_eq!(bits.load_le::<u16>(), 0x7BFF);
+	/// bits.store_f16_le(65520.0);
+	/// assert_eq!(bits.load_f16_le(), f32::INFINITY);
+	/// bits.store_f16_le(1.0 / 3.0);
+	/// assert_eq!(bits.load_f16_le(), 0.33325195);
+	/// ```
+	///
+	/// [`.store_f16()`]: Self::store_f16
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_f16_le(&mut self, value: f32) {
+		self.store_f16_with(Endian::Little, value);
+	}
+
+	/// Stores an `f32` as an IEEE 754 half-precision float, using
+	/// [`.store_be()`] significance ordering.
+	///
+	/// This rounds as [`.store_f16()`] does.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 16.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	/// [`.store_f16()`]: Self::store_f16
+	#[inline]
+	pub fn store_f16_be(&mut self, value: f32) {
+		self.store_f16_with(Endian::Big, value);
+	}
+
+	/// Loads an IEEE 754 single-precision float, using [`.load()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_f32(&self) -> f32 {
+		f32::from_bits(self.load_float(native()))
+	}
+
+	/// Loads an IEEE 754 single-precision float, using [`.load_le()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_f32_le(&self) -> f32 {
+		f32::from_bits(self.load_float(Endian::Little))
+	}
+
+	/// Loads an IEEE 754 single-precision float, using [`.load_be()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let raw = [0u8, 0x40, 0x49, 0x0F, 0xDB];
+	/// let bits = raw.view_bits::<Msb0>();
+	/// assert_eq!(bits[8 ..].load_f32_be(), core::f32::consts::PI);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_f32_be(&self) -> f32 {
+		f32::from_bits(self.load_float(Endian::Big))
+	}
+
+	/// Stores an IEEE 754 single-precision float, using [`.store()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_f32(&mut self, value: f32) {
+		self.store_float(native(), value.to_bits());
+	}
+
+	/// Stores an IEEE 754 single-precision float, using [`.store_le()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_f32_le(&mut self, value: f32) {
+		self.store_float(Endian::Little, value.to_bits());
+	}
+
+	/// Stores an IEEE 754 single-precision float, using [`.store_be()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 32.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_f32_be(&mut self, value: f32) {
+		self.store_float(Endian::Big, value.to_bits());
+	}
+
+	/// Loads an IEEE 754 double-precision float, using [`.load()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_f64(&self) -> f64 {
+		f64::from_bits(self.load_float(native()))
+	}
+
+	/// Loads an IEEE 754 double-precision float, using [`.load_le()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_f64_le(&self) -> f64 {
+		f64::from_bits(self.load_float(Endian::Little))
+	}
+
+	/// Loads an IEEE 754 double-precision float, using [`.load_be()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_f64_be(&self) -> f64 {
+		f64::from_bits(self.load_float(Endian::Big))
+	}
+
+	/// Stores an IEEE 754 double-precision float, using [`.store()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_f64(&mut self, value: f64) {
+		self.store_float(native(), value.to_bits());
+	}
+
+	/// Stores an IEEE 754 double-precision float, using [`.store_le()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_f64_le(&mut self, value: f64) {
+		self.store_float(Endian::Little, value.to_bits());
+	}
+
+	/// Stores an IEEE 754 double-precision float, using [`.store_be()`]
+	/// significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not 64.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_f64_be(&mut self, value: f64) {
+		self.store_float(Endian::Big, value.to_bits());
+	}
+}
+
+/// Binary-coded decimal.
+///
+/// Each decimal digit occupies four bits, with the least significant digit in
+/// the least significant four bits of the field. The bit-slice must be a whole
+/// number of digits long, and no more than 128 bits (32 digits).
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads a binary-coded decimal number, using [`.load()`] significance
+	/// ordering.
+	///
+	/// Returns `None` if any digit is greater than 9, or if the number does not
+	/// fit in `I`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_bcd<I>(&self) -> Option<I>
+	where I: Unsigned {
+		self.load_bcd_with(native())
+	}
+
+	/// Loads a binary-coded decimal number, using [`.load_le()`] significance
+	/// ordering.
+	///
+	/// Returns `None` if any digit is greater than 9, or if the number does not
+	/// fit in `I`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_bcd_le<I>(&self) -> Option<I>
+	where I: Unsigned {
+		self.load_bcd_with(Endian::Little)
+	}
+
+	/// Loads a binary-coded decimal number, using [`.load_be()`] significance
+	/// ordering.
+	///
+	/// Returns `None` if any digit is greater than 9, or if the number does not
+	/// fit in `I`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let raw = [0x12u8, 0x34, 0x5F];
+	/// let bits = raw.view_bits::<Msb0>();
+	/// assert_eq!(bits[.. 20].load_bcd_be::<u32>(), Some(12345));
+	/// assert_eq!(bits[.. 20].load_bcd_be::<u8>(), None);
+	/// assert_eq!(bits[4 ..].load_bcd_be::<u32>(), None);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_bcd_be<I>(&self) -> Option<I>
+	where I: Unsigned {
+		self.load_bcd_with(Endian::Big)
+	}
+
+	/// Stores a number as binary-coded decimal, using [`.store()`]
+	/// significance ordering.
+	///
+	/// As with [`.store()`], a number with more digits than the bit-slice can
+	/// hold is truncated: only its least significant digits are stored.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_bcd<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store_bcd_with(native(), value);
+	}
+
+	/// Stores a number as binary-coded decimal, using [`.store_le()`]
+	/// significance ordering.
+	///
+	/// A number with more digits than the bit-slice can hold is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_bcd_le<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store_bcd_with(Endian::Little, value);
+	}
+
+	/// Stores a number as binary-coded decimal, using [`.store_be()`]
+	/// significance ordering.
+	///
+	/// A number with more digits than the bit-slice can hold is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, is not a multiple of 4, or is greater
+	/// than 128.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut raw = [0u8; 2];
+	/// raw.view_bits_mut::<Msb0>().store_bcd_be(1987u16);
+	/// assert_eq!(raw, [0x19, 0x87]);
+	/// raw.view_bits_mut::<Msb0>().store_bcd_be(2024u32);
+	/// assert_eq!(raw, [0x20, 0x24]);
+	/// raw.view_bits_mut::<Msb0>().store_bcd_be(123_456u32);
+	/// assert_eq!(raw, [0x34, 0x56]);
+	/// ```
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_bcd_be<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store_bcd_with(Endian::Big, value);
+	}
+}
+
+/// Gray code.
+///
+/// Successive integers differ in exactly one bit of their Gray code, which
+/// makes it common in counters and rotary encoders. The bit-slice holds the
+/// code, which these methods convert to and from its ordinary binary value.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads a Gray-coded integer, using [`.load()`] significance ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_gray<I>(&self) -> I
+	where I: Unsigned {
+		from_gray(self.load::<I>())
+	}
+
+	/// Loads a Gray-coded integer, using [`.load_le()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u16, Lsb0; 0, 1, 1, 1];
+	/// assert_eq!(bits.load_le::<u16>(), 0b1110);
+	/// assert_eq!(bits.load_gray_le::<u16>(), 11);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_gray_le<I>(&self) -> I
+	where I: Unsigned {
+		from_gray(self.load_le::<I>())
+	}
+
+	/// Loads a Gray-coded integer, using [`.load_be()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_gray_be<I>(&self) -> I
+	where I: Unsigned {
+		from_gray(self.load_be::<I>())
+	}
+
+	/// Stores an integer as its Gray code, using [`.store()`] significance
+	/// ordering.
+	///
+	/// The value is truncated to `self.len()` bits before it is encoded, so
+	/// that the bit-slice holds the code of the truncated value.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_gray<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store::<I>(to_gray(value, self.len()));
+	}
+
+	/// Stores an integer as its Gray code, using [`.store_le()`] significance
+	/// ordering.
+	///
+	/// The value is truncated to `self.len()` bits before it is encoded.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_gray_le<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store_le::<I>(to_gray(value, self.len()));
+	}
+
+	/// Stores an integer as its Gray code, using [`.store_be()`] significance
+	/// ordering.
+	///
+	/// The value is truncated to `self.len()` bits before it is encoded.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut u8, Msb0; 0; 3];
+	/// bits.store_gray_be(3u8);
+	/// assert_eq!(bits, bits![0, 1, 0]);
+	/// bits.store_gray_be(4u8);
+	/// assert_eq!(bits, bits![1, 1, 0]);
+	/// bits.store_gray_be(12u8);
+	/// assert_eq!(bits.load_gray_be::<u8>(), 4);
+	/// ```
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_gray_be<I>(&mut self, value: I)
+	where I: Unsigned {
+		self.store_be::<I>(to_gray(value, self.len()));
+	}
+}
+
+/// Sign-magnitude integers.
+///
+/// The most significant bit of the field is the sign, and the remaining bits
+/// are the magnitude. The field has two zeros; both load as `0`, and `0` is
+/// stored as positive zero.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads a sign-magnitude integer, using [`.load()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_sign_magnitude<I>(&self) -> I
+	where I: Signed {
+		self.load_sign_magnitude_with(native())
+	}
+
+	/// Loads a sign-magnitude integer, using [`.load_le()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_sign_magnitude_le<I>(&self) -> I
+	where I: Signed {
+		self.load_sign_magnitude_with(Endian::Little)
+	}
+
+	/// Loads a sign-magnitude integer, using [`.load_be()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u8, Msb0; 1, 0, 1, 1, 0];
+	/// assert_eq!(bits.load_sign_magnitude_be::<i16>(), -6);
+	/// assert_eq!(bits[1 ..].load_sign_magnitude_be::<i16>(), 6);
+	/// assert_eq!(bits[.. 2].load_sign_magnitude_be::<i16>(), 0);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_sign_magnitude_be<I>(&self) -> I
+	where I: Signed {
+		self.load_sign_magnitude_with(Endian::Big)
+	}
+
+	/// Stores a sign-magnitude integer, using [`.store()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_sign_magnitude<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_sign_magnitude_with(native(), value);
+	}
+
+	/// Stores a sign-magnitude integer, using [`.store_le()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_sign_magnitude_le<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_sign_magnitude_with(Endian::Little, value);
+	}
+
+	/// Stores a sign-magnitude integer, using [`.store_be()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_sign_magnitude_be<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_sign_magnitude_with(Endian::Big, value);
+	}
+}
+
+/// Ones’-complement integers.
+///
+/// A negative number is stored as the bitwise inverse of its magnitude. The
+/// field has two zeros; both load as `0`, and `0` is stored as positive zero.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads a ones’-complement integer, using [`.load()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_ones_complement<I>(&self) -> I
+	where I: Signed {
+		self.load_ones_complement_with(native())
+	}
+
+	/// Loads a ones’-complement integer, using [`.load_le()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u8, Lsb0; 0, 1, 1, 1];
+	/// assert_eq!(bits.load_le::<i8>(), -2);
+	/// assert_eq!(bits.load_ones_complement_le::<i8>(), -1);
+	/// assert_eq!(bits![1; 4].load_ones_complement_le::<i8>(), 0);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_ones_complement_le<I>(&self) -> I
+	where I: Signed {
+		self.load_ones_complement_with(Endian::Little)
+	}
+
+	/// Loads a ones’-complement integer, using [`.load_be()`] significance
+	/// ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_ones_complement_be<I>(&self) -> I
+	where I: Signed {
+		self.load_ones_complement_with(Endian::Big)
+	}
+
+	/// Stores a ones’-complement integer, using [`.store()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_ones_complement<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_ones_complement_with(native(), value);
+	}
+
+	/// Stores a ones’-complement integer, using [`.store_le()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_ones_complement_le<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_ones_complement_with(Endian::Little, value);
+	}
+
+	/// Stores a ones’-complement integer, using [`.store_be()`] significance
+	/// ordering.
+	///
+	/// A magnitude that does not fit in `self.len() - 1` bits is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than `I::BITS`.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_ones_complement_be<I>(&mut self, value: I)
+	where I: Signed {
+		self.store_ones_complement_with(Endian::Big, value);
+	}
+}
+
+/// Characters and flags.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads a Unicode scalar value, using [`.load()`] significance ordering.
+	///
+	/// Returns `None` if the loaded number is not a valid `char`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn load_char(&self) -> Option<char> {
+		char::from_u32(self.load::<u32>())
+	}
+
+	/// Loads a Unicode scalar value, using [`.load_le()`] significance
+	/// ordering.
+	///
+	/// Returns `None` if the loaded number is not a valid `char`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn load_char_le(&self) -> Option<char> {
+		char::from_u32(self.load_le::<u32>())
+	}
+
+	/// Loads a Unicode scalar value, using [`.load_be()`] significance
+	/// ordering.
+	///
+	/// Returns `None` if the loaded number is not a valid `char`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut raw = [0u8; 3];
+	/// let bits = &mut raw.view_bits_mut::<Msb0>()[3 ..];
+	/// bits.store_char_be('∑');
+	/// assert_eq!(bits.load_char_be(), Some('∑'));
+	///
+	/// bits.store_be::<u32>(0xD800);
+	/// assert_eq!(bits.load_char_be(), None);
+	/// ```
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn load_char_be(&self) -> Option<char> {
+		char::from_u32(self.load_be::<u32>())
+	}
+
+	/// Stores a Unicode scalar value, using [`.store()`] significance
+	/// ordering.
+	///
+	/// As with [`.store()`], a value wider than the bit-slice is truncated.
+	/// All scalar values fit in 21 bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	#[inline]
+	pub fn store_char(&mut self, value: char) {
+		self.store::<u32>(value as u32);
+	}
+
+	/// Stores a Unicode scalar value, using [`.store_le()`] significance
+	/// ordering.
+	///
+	/// A value wider than the bit-slice is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn store_char_le(&mut self, value: char) {
+		self.store_le::<u32>(value as u32);
+	}
+
+	/// Stores a Unicode scalar value, using [`.store_be()`] significance
+	/// ordering.
+	///
+	/// A value wider than the bit-slice is truncated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is 0, or greater than 32.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn store_char_be(&mut self, value: char) {
+		self.store_be::<u32>(value as u32);
+	}
+
+	/// Copies the bit-slice into an array of `bool`s.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not `N`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u8, Msb0; 0, 1, 1, 0, 1];
+	/// let flags: [bool; 3] = bits[1 .. 4].load_bools();
+	/// assert_eq!(flags, [true, true, false]);
+	/// ```
+	#[inline]
+	pub fn load_bools<const N: usize>(&self) -> [bool; N] {
+		check_len("load", N, self.len());
+		array::from_fn(|idx| unsafe { *self.get_unchecked(idx) })
+	}
+
+	/// Copies an array of `bool`s into the bit-slice.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is not `N`.
+	#[inline]
+	pub fn store_bools<const N: usize>(&mut self, value: [bool; N]) {
+		check_len("store", N, self.len());
+		for (idx, bit) in value.into_iter().enumerate() {
+			unsafe {
+				self.set_unchecked(idx, bit);
+			}
+		}
+	}
+}
+
+/// Encoding implementations.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads the bit-slice as an unsigned integer.
+	fn load_with<I>(&self, endian: Endian) -> I
+	where I: Integral {
+		match endian {
+			Endian::Big => self.load_be::<I>(),
+			Endian::Little => self.load_le::<I>(),
+		}
+	}
+
+	/// Stores the low `self.len()` bits of an integer into the bit-slice.
+	fn store_with<I>(&mut self, endian: Endian, value: I)
+	where I: Integral {
+		match endian {
+			Endian::Big => self.store_be::<I>(value),
+			Endian::Little => self.store_le::<I>(value),
+		}
+	}
+
+	/// Loads the bit-pattern of a float, which must fill the bit-slice.
+	fn load_float<I>(&self, endian: Endian) -> I
+	where I: Unsigned {
+		check_len("load", bits_of::<I>(), self.len());
+		self.load_with(endian)
+	}
+
+	/// Stores the bit-pattern of a float, which must fill the bit-slice.
+	fn store_float<I>(&mut self, endian: Endian, value: I)
+	where I: Unsigned {
+		check_len("store", bits_of::<I>(), self.len());
+		self.store_with(endian, value);
+	}
+
+	/// Loads a half-precision float.
+	fn load_f16_with(&self, endian: Endian) -> f32 {
+		f16_to_f32(self.load_float(endian))
+	}
+
+	/// Stores a half-precision float.
+	fn store_f16_with(&mut self, endian: Endian, value: f32) {
+		self.store_float(endian, f32_to_f16(value));
+	}
+
+	/// Loads a binary-coded decimal number.
+	fn load_bcd_with<I>(&self, endian: Endian) -> Option<I>
+	where I: Unsigned {
+		let digits = bcd_digits(self.len());
+		let raw = self.load_with::<u128>(endian);
+		let mut out = 0u128;
+		for digit in (0 .. digits).rev().map(|idx| raw >> (idx * 4) & 0xF) {
+			if digit > 9 {
+				return None;
+			}
+			//  At most 32 digits are loaded, which cannot overflow `u128`.
+			out = out * 10 + digit;
+		}
+		I::try_from(out).ok()
+	}
+
+	/// Stores a number as binary-coded decimal.
+	fn store_bcd_with<I>(&mut self, endian: Endian, value: I)
+	where I: Unsigned {
+		let digits = bcd_digits(self.len());
+		let mut value = value.as_u128();
+		let mut raw = 0u128;
+		for idx in 0 .. digits {
+			raw |= (value % 10) << (idx * 4);
+			value /= 10;
+		}
+		self.store_with(endian, raw);
+	}
+
+	/// Loads a sign-magnitude integer.
+	fn load_sign_magnitude_with<I>(&self, endian: Endian) -> I
+	where I: Signed {
+		let len = self.len();
+		check::<I>("load", len);
+		let raw = self.load_with::<u128>(endian);
+		let magnitude = (raw & mask(len - 1)) as i128;
+		let value = if raw >> (len - 1) & 1 == 1 {
+			-magnitude
+		}
+		else {
+			magnitude
+		};
+		resize(value)
+	}
+
+	/// Stores a sign-magnitude integer.
+	fn store_sign_magnitude_with<I>(&mut self, endian: Endian, value: I)
+	where I: Signed {
+		let len = self.len();
+		check::<I>("store", len);
+		let value = value.as_i128();
+		let sign = (value < 0) as u128;
+		let raw = sign << (len - 1) | value.unsigned_abs() & mask(len - 1);
+		self.store_with(endian, raw);
+	}
+
+	/// Loads a ones’-complement integer.
+	fn load_ones_complement_with<I>(&self, endian: Endian) -> I
+	where I: Signed {
+		let len = self.len();
+		check::<I>("load", len);
+		let raw = self.load_with::<u128>(endian);
+		let value = if raw >> (len - 1) & 1 == 1 {
+			-((!raw & mask(len)) as i128)
+		}
+		else {
+			raw as i128
+		};
+		resize(value)
+	}
+
+	/// Stores a ones’-complement integer.
+	fn store_ones_complement_with<I>(&mut self, endian: Endian, value: I)
+	where I: Signed {
+		let len = self.len();
+		check::<I>("store", len);
+		let value = value.as_i128();
+		let magnitude = value.unsigned_abs() & mask(len - 1);
+		let raw = if value < 0 { !magnitude } else { magnitude };
+		self.store_with(endian, raw & mask(len));
+	}
+}
+
+/// Selects the significance ordering used by [`BitField::load`] and
+/// [`BitField::store`] on the target.
+fn native() -> Endian {
+	if cfg!(target_endian = "big") {
+		Endian::Big
+	}
+	else {
+		Endian::Little
+	}
+}
+
+/// Asserts that a bit-slice has exactly the length a transfer requires.
+fn check_len(action: &'static str, expected: usize, len: usize) {
+	assert_eq!(
+		len, expected,
+		"cannot {} {} bits through a {}-bit region",
+		action, expected, len,
+	);
+}
+
+/// Counts the digits in a binary-coded decimal field of `len` bits.
+///
+/// ## Panics
+///
+/// This panics if `len` is not a whole, non-zero, number of digits, or is wider
+/// than `u128`.
+fn bcd_digits(len: usize) -> usize {
+	assert!(
+		len != 0 && len % 4 == 0 && len <= bits_of::<u128>(),
+		"a binary-coded decimal field must be a non-zero multiple of 4 bits, \
+		 up to 128, not {}",
+		len,
+	);
+	len / 4
+}
+
+/// A mask of the low `width` bits of a `u128`.
+fn mask(width: usize) -> u128 {
+	if width >= bits_of::<u128>() {
+		return !0;
+	}
+	(1 << width) - 1
+}
+
+/// Converts an integer to its Gray code, after truncating it to `width` bits.
+fn to_gray<I>(value: I, width: usize) -> I
+where I: Unsigned {
+	let value = if width < bits_of::<I>() {
+		value & !(!I::ZERO << width)
+	}
+	else {
+		value
+	};
+	value ^ (value >> 1)
+}
+
+/// Converts a Gray code back to its integer.
+fn from_gray<I>(code: I) -> I
+where I: Unsigned {
+	let mut value = code;
+	let mut shamt = 1;
+	while shamt < bits_of::<I>() {
+		value ^= value >> shamt;
+		shamt <<= 1;
+	}
+	value
+}
+
+/// Widens an IEEE 754 half-precision bit-pattern to single precision. Every
+/// half-precision value is exactly representable.
+fn f16_to_f32(half: u16) -> f32 {
+	let sign = (half as u32 & 0x8000) << 16;
+	let exp = half as u32 >> 10 & 0x1F;
+	let man = half as u32 & 0x3FF;
+	let bits = match (exp, man) {
+		(0, 0) => sign,
+		//  Subnormals are normalized by shifting the highest set bit of the
+		//  significand into the implicit bit.
+		(0, _) => {
+			let shamt = man.leading_zeros() - 21;
+			sign | (113 - shamt) << 23 | (man << shamt & 0x3FF) << 13
+		},
+		//  Infinities and NaNs keep their payload.
+		(0x1F, _) => sign | 0x7F80_0000 | man << 13,
+		_ => sign | (exp + 112) << 23 | man << 13,
+	};
+	f32::from_bits(bits)
+}
+
+/// Narrows a single-precision float to an IEEE 754 half-precision bit-pattern,
+/// rounding to the nearest value with ties to even.
+fn f32_to_f16(value: f32) -> u16 {
+	let bits = value.to_bits();
+	let sign = (bits >> 16) as u16 & 0x8000;
+	let exp = (bits >> 23 & 0xFF) as i32;
+	let man = bits & 0x7F_FFFF;
+
+	if exp == 0xFF {
+		//  NaNs remain NaNs, even if their payload is shifted out.
+		let nan = if man == 0 {
+			0
+		}
+		else {
+			0x200 | (man >> 13) as u16
+		};
+		return sign | 0x7C00 | nan;
+	}
+
+	//  The exponent, rebiased for half precision.
+	let exp = exp - 112;
+	if exp >= 0x1F {
+		return sign | 0x7C00;
+	}
+
+	let (full, shamt) = if exp > 0 {
+		(((exp as u32) << 23 | man), 13)
+	}
+	else {
+		//  Half-precision subnormals count in units of 2^-24. Anything smaller
+		//  than half of that rounds to zero.
+		let shamt = (14 - exp) as u32;
+		if shamt > 24 {
+			return sign;
+		}
+		(man | 0x80_0000, shamt)
+	};
+
+	let half = full >> shamt;
+	let rest = full & ((1 << shamt) - 1);
+	let midpoint = 1 << (shamt - 1);
+	let round = (rest > midpoint || rest == midpoint && half & 1 == 1) as u32;
+	//  Rounding up may carry into the exponent, which produces the correct
+	//  next power of two, or infinity.
+	sign | (half + round) as u16
+}
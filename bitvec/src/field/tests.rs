@@ -5,7 +5,13 @@ use std::io;
 
 use rand::prelude::*;
 
-use crate::prelude::*;
+use crate::{
+	field::{
+		BitField,
+		BitFieldError,
+	},
+	prelude::*,
+};
 
 #[test]
 fn lsb0_u8_any_u5() {
@@ -257,6 +263,316 @@ fn msb0_u8_be_u24() {
 	);
 }
 
+#[test]
+fn any_order() {
+	use core::marker::PhantomData;
+
+	use crate::{
+		index::{
+			BitIdx,
+			BitPos,
+		},
+		mem::BitRegister,
+		order::HiLo,
+	};
+
+	/// Forwards to another ordering without declaring its contiguity.
+	struct Undeclared<O>(PhantomData<O>);
+
+	unsafe impl<O> BitOrder for Undeclared<O>
+	where O: BitOrder
+	{
+		fn at<R>(index: BitIdx<R>) -> BitPos<R>
+		where R: BitRegister {
+			O::at::<R>(index)
+		}
+	}
+
+	fn check<O, P>()
+	where
+		O: BitOrder,
+		P: BitOrder,
+	{
+		for (start, width) in [(0, 16), (3, 5), (5, 27), (14, 40), (2, 62)] {
+			let value = random::<u64>() >> (64 - width);
+			let range = start .. start + width;
+
+			let mut a = [0u16; 5];
+			let mut b = [0u16; 5];
+			a.view_bits_mut::<O>()[range.clone()].store_le(value);
+			b.view_bits_mut::<P>()[range.clone()].store_le(value);
+			assert_eq!(a, b);
+			assert_eq!(
+				b.view_bits::<P>()[range.clone()].load_le::<u64>(),
+				value
+			);
+
+			a.view_bits_mut::<O>()[range.clone()].store_be(value);
+			b.view_bits_mut::<P>()[range.clone()].store_be(value);
+			assert_eq!(a, b);
+			assert_eq!(
+				b.view_bits::<P>()[range.clone()].load_be::<u64>(),
+				value
+			);
+			assert_eq!(
+				b.view_bits::<P>()[range.clone()].load_be::<i64>(),
+				a.view_bits::<O>()[range].load_be::<i64>(),
+			);
+		}
+	}
+
+	//  Gathering bits one at a time produces the same layout as the shift and
+	//  mask of a contiguous ordering.
+	check::<Lsb0, Undeclared<Lsb0>>();
+	check::<Msb0, Undeclared<Msb0>>();
+
+	let mut data = [0u8; 4];
+	let bits = data.view_bits_mut::<HiLo>();
+	bits[2 .. 14].store_le(0xABCu16);
+	assert_eq!(bits[2 .. 14].load_le::<u16>(), 0xABC);
+	assert_eq!(bits[2 .. 14].load_le::<i16>(), 0xABCu16 as i16 - 0x1000);
+	//  Indices 2 through 7 of the first byte are positions 6, 7, and 0
+	//  through 3, which hold the low six bits of the value in order of their
+	//  position.
+	assert_eq!(data[0], 0b1100_1100);
+
+	let bits = data.view_bits_mut::<HiLo>();
+	bits[5 .. 29].store_be(-2i32);
+	assert_eq!(bits[5 .. 29].load_be::<i32>(), -2);
+	assert!(bits[5 .. 28].all());
+	assert!(!bits[28]);
+}
+
+#[test]
+fn checked() {
+	let mut data = [0u16; 2];
+	let bits = data.view_bits_mut::<Msb0>();
+
+	assert_eq!(
+		bits[.. 17].try_load_be::<u16>(),
+		Err(BitFieldError::Width {
+			requested: 17,
+			available: 16,
+		}),
+	);
+	assert_eq!(
+		bits[.. 0].try_store_le::<u8>(0),
+		Err(BitFieldError::Width {
+			requested: 0,
+			available: 8,
+		}),
+	);
+
+	assert!(bits[4 .. 20].try_store_le::<u16>(0xFFFF).is_ok());
+	assert_eq!(bits[4 .. 20].try_load_le::<u16>(), Ok(0xFFFF));
+	assert_eq!(bits[4 .. 20].try_load_le::<i16>(), Ok(-1));
+
+	//  Values must survive the round trip.
+	assert!(bits[4 .. 10].try_store_be::<i32>(-32).is_ok());
+	assert_eq!(bits[4 .. 10].load_be::<i32>(), -32);
+	assert!(bits[4 .. 10].try_store_be::<i32>(31).is_ok());
+	assert_eq!(bits[4 .. 10].load_be::<i32>(), 31);
+	for value in [-33, 32, i32::MIN, i32::MAX] {
+		assert_eq!(
+			bits[4 .. 10].try_store_be::<i32>(value),
+			Err(BitFieldError::Overflow { width: 6 }),
+		);
+	}
+	assert!(bits[4 .. 10].try_store_le::<u32>(63).is_ok());
+	assert_eq!(
+		bits[4 .. 10].try_store_le::<u32>(64),
+		Err(BitFieldError::Overflow { width: 6 }),
+	);
+	assert_eq!(bits[4 .. 10].load_le::<u32>(), 63);
+
+	//  Full-width stores accept every value.
+	assert!(bits[.. 8].try_store::<i8>(i8::MIN).is_ok());
+	assert_eq!(bits[.. 8].try_load::<i8>(), Ok(i8::MIN));
+
+	let mut arr = bitarr![u8, Lsb0; 0; 12];
+	assert!(arr.try_store_le::<u16>(0xABCD).is_ok());
+	assert_eq!(arr.try_load_le::<u16>(), Ok(0xABCD));
+	assert_eq!(
+		arr.try_load_le::<u8>(),
+		Err(BitFieldError::Width {
+			requested: 16,
+			available: 8,
+		}),
+	);
+	assert_eq!(
+		arr.try_store_le::<u32>(0x1_0000),
+		Err(BitFieldError::Overflow { width: 16 }),
+	);
+	assert_eq!(
+		BitArray::<[u8; 0], Lsb0>::ZERO.try_load_le::<u8>(),
+		Err(BitFieldError::Width {
+			requested: 0,
+			available: 8,
+		}),
+	);
+}
+
+#[test]
+fn array_checked() {
+	let mut arr = BitArray::<[u8; 2], Lsb0>::new([0x00, 0xFF]);
+	let mut bits = arr;
+	let bits = bits.as_mut_bitslice();
+
+	assert_eq!(arr.try_load_le::<u8>(), bits.try_load_le::<u8>());
+	assert_eq!(arr.try_load_be::<u8>(), bits.try_load_be::<u8>());
+	assert_eq!(arr.try_load_le::<u16>(), bits.try_load_le::<u16>());
+	assert_eq!(arr.try_load_be::<i16>(), bits.try_load_be::<i16>());
+	assert_eq!(
+		arr.try_load_le::<u8>(),
+		Err(BitFieldError::Width {
+			requested: 16,
+			available: 8,
+		}),
+	);
+
+	assert_eq!(arr.try_store_le::<u8>(1), bits.try_store_le::<u8>(1));
+	assert_eq!(arr.try_store_be::<i8>(-1), bits.try_store_be::<i8>(-1));
+	assert_eq!(
+		arr.try_store_le::<u32>(0x1_0000),
+		bits.try_store_le::<u32>(0x1_0000),
+	);
+	assert_eq!(arr.into_inner(), [0x00, 0xFF]);
+
+	assert_eq!(arr.try_store_be::<u16>(0x1234), Ok(()));
+	assert_eq!(arr.try_load_be::<u16>(), Ok(0x1234));
+}
+
+#[test]
+fn foreign_impl() {
+	//  `BitField` implementors outside this crate need only the four unchecked
+	//  transfers.
+	struct Register(u16);
+
+	impl BitField for Register {
+		fn load_le<I>(&self) -> I
+		where I: funty::Integral {
+			self.0.view_bits::<Lsb0>().load_le()
+		}
+
+		fn load_be<I>(&self) -> I
+		where I: funty::Integral {
+			self.0.view_bits::<Lsb0>().load_be()
+		}
+
+		fn store_le<I>(&mut self, value: I)
+		where I: funty::Integral {
+			self.0.view_bits_mut::<Lsb0>().store_le(value);
+		}
+
+		fn store_be<I>(&mut self, value: I)
+		where I: funty::Integral {
+			self.0.view_bits_mut::<Lsb0>().store_be(value);
+		}
+	}
+
+	let mut reg = Register(0);
+	reg.store_le(0x1234u16);
+	assert_eq!(reg.load_le::<u16>(), 0x1234);
+}
+
+#[test]
+fn encodings() {
+	let mut data = [0u8; 20];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 ..];
+
+	for value in [0.0, -0.0, 1.5, -2.75e-5, f32::MAX, f32::MIN_POSITIVE] {
+		bits[.. 32].store_f32_be(value);
+		assert_eq!(bits[.. 32].load_f32_be().to_bits(), value.to_bits());
+		bits[5 .. 37].store_f32_le(value);
+		assert_eq!(bits[5 .. 37].load_f32_le().to_bits(), value.to_bits());
+	}
+	bits[7 .. 71].store_f64_be(-1e300);
+	assert_eq!(bits[7 .. 71].load_f64_be(), -1e300);
+	bits[7 .. 71].store_f64_le(f64::EPSILON);
+	assert_eq!(bits[7 .. 71].load_f64_le(), f64::EPSILON);
+
+	//  Every half-precision pattern, other than NaNs, survives widening and
+	//  narrowing.
+	let half = &mut bits[1 .. 17];
+	for raw in 0 ..= u16::MAX {
+		half.store_be(raw);
+		let value = half.load_f16_be();
+		if value.is_nan() {
+			assert_eq!(raw & 0x7C00, 0x7C00);
+			continue;
+		}
+		half.store_f16_le(value);
+		assert_eq!(half.load_le::<u16>(), raw);
+	}
+	//  The smallest subnormal, and rounding at half of it.
+	let tiny = f32::from_bits(0x3380_0000);
+	half.store_f16_be(tiny);
+	assert_eq!(half.load_be::<u16>(), 1);
+	half.store_f16_be(tiny / 2.0);
+	assert_eq!(half.load_be::<u16>(), 0);
+	half.store_f16_be(tiny * 1.5);
+	assert_eq!(half.load_be::<u16>(), 2);
+	half.store_f16_be(-1e9);
+	assert_eq!(half.load_f16_be(), f32::NEG_INFINITY);
+	half.store_f16_be(f32::NAN);
+	assert!(half.load_f16_be().is_nan());
+
+	bits[2 .. 42].store_bcd_le(9_876_543_210u64);
+	assert_eq!(bits[2 .. 42].load_bcd_le::<u64>(), Some(9_876_543_210));
+	assert_eq!(bits[2 .. 42].load_bcd_le::<u32>(), None);
+	let raw = bits[2 .. 42].load_le::<u64>();
+	bits[2 .. 42].store_le(raw | 0xF0);
+	assert_eq!(bits[2 .. 42].load_bcd_le::<u64>(), None);
+	bits[.. 128].store_bcd_be(u128::MAX);
+	assert_eq!(
+		bits[.. 128].load_bcd_be::<u128>(),
+		Some(u128::MAX % 10u128.pow(32)),
+	);
+
+	for value in 0 .. 64u8 {
+		bits[9 .. 15].store_gray_le(value);
+		let code = bits[9 .. 15].load_le::<u8>();
+		assert_eq!(code, value ^ value >> 1);
+		assert_eq!(bits[9 .. 15].load_gray_le::<u8>(), value);
+	}
+	bits[.. 64].store_gray_be(u64::MAX);
+	assert_eq!(bits[.. 64].load_gray_be::<u64>(), u64::MAX);
+
+	for value in -127 ..= 127i8 {
+		bits[4 .. 12].store_sign_magnitude_le(value);
+		assert_eq!(bits[4 .. 12].load_sign_magnitude_le::<i8>(), value);
+		bits[4 .. 12].store_ones_complement_be(value);
+		assert_eq!(bits[4 .. 12].load_ones_complement_be::<i8>(), value);
+	}
+	bits[4 .. 12].store_sign_magnitude_le(-5i8);
+	assert_eq!(bits[4 .. 12].load_le::<u8>(), 0x85);
+	bits[4 .. 12].store_ones_complement_be(-5i8);
+	assert_eq!(bits[4 .. 12].load_be::<u8>(), !5);
+	//  Out-of-range values keep their sign, and lose only magnitude bits.
+	//  -128 has no magnitude bits left in 7 bits, so it becomes negative zero.
+	bits[4 .. 12].store_ones_complement_be(-128i8);
+	assert_eq!(bits[4 .. 12].load_be::<u8>(), 0xFF);
+	assert_eq!(bits[4 .. 12].load_ones_complement_be::<i8>(), 0);
+	for (value, expect) in [(-200i16, -72), (200, 72)] {
+		bits[4 .. 12].store_ones_complement_le(value);
+		assert_eq!(bits[4 .. 12].load_ones_complement_le::<i16>(), expect);
+		bits[4 .. 12].store_sign_magnitude_le(value);
+		assert_eq!(bits[4 .. 12].load_sign_magnitude_le::<i16>(), expect);
+	}
+	bits[.. 128].store_ones_complement_le(i128::MIN + 1);
+	assert_eq!(
+		bits[.. 128].load_ones_complement_le::<i128>(),
+		i128::MIN + 1
+	);
+
+	bits[10 .. 31].store_char_le('🦀');
+	assert_eq!(bits[10 .. 31].load_char_le(), Some('🦀'));
+
+	bits[20 .. 25].store_bools([true, false, true, true, false]);
+	assert_eq!(bits[20 .. 25], bits![1, 0, 1, 1, 0]);
+	assert_eq!(bits[21 .. 24].load_bools(), [false, true, true]);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn read_bits() {
@@ -28,14 +28,22 @@ pub mod access;
 pub mod array;
 pub mod boxed;
 pub mod domain;
+pub mod encoding;
+pub mod ewah;
 pub mod field;
 pub mod index;
+pub mod matrix;
 pub mod mem;
 pub mod order;
+pub mod packed;
 pub mod ptr;
-mod serdes;
+pub mod rank;
+pub mod roaring;
+pub mod serdes;
+pub mod set;
 pub mod slice;
 pub mod store;
+pub mod stream;
 pub mod vec;
 pub mod view;
 
@@ -45,7 +53,10 @@ pub mod prelude {
 		array::BitArray,
 		bitarr,
 		bits,
-		field::BitField as _,
+		field::{
+			BitField as _,
+			TryBitField as _,
+		},
 		order::{
 			BitOrder,
 			LocalBits,
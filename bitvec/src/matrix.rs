@@ -0,0 +1,711 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/matrix.md")]
+
+use core::cmp;
+
+use crate::{
+	boxed::BitBox,
+	mem,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod iter;
+mod ops;
+mod tests;
+mod traits;
+
+pub use self::iter::{
+	Column,
+	Rows,
+};
+
+/// Processor width, used as the edge of a transposition block.
+const WORD_BITS: usize = mem::bits_of::<usize>();
+
+#[doc = include_str!("../doc/matrix/BitMatrix.md")]
+pub struct BitMatrix<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The packed rows. The buffer always begins at the zeroth bit of its
+	/// first element, so every row begins on an element boundary.
+	bits:   BitBox<T, O>,
+	/// The number of rows.
+	rows:   usize,
+	/// The number of columns, which is the length of each row.
+	cols:   usize,
+	/// The distance, in bits, from the start of one row to the start of the
+	/// next.
+	stride: usize,
+}
+
+/// Constructors.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs a matrix of the given dimensions, with every bit cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if the matrix would be too large to fit in a [`BitBox`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u16, Msb0>::new(3, 20);
+	/// assert_eq!((m.rows(), m.cols()), (3, 20));
+	/// assert_eq!(m.stride(), 32);
+	/// assert_eq!(m.count_ones(), 0);
+	/// ```
+	///
+	/// [`BitBox`]: crate::boxed::BitBox
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let stride = mem::elts::<T::Mem>(cols) * mem::bits_of::<T::Mem>();
+		let len = rows
+			.checked_mul(stride)
+			.expect("bit-matrix dimensions overflow");
+		Self {
+			bits: BitVec::repeat(false, len).into_boxed_bitslice(),
+			rows,
+			cols,
+			stride,
+		}
+	}
+
+	/// Constructs an `n`-by-`n` identity matrix.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::identity(3);
+	/// assert_eq!(m[1], bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn identity(n: usize) -> Self {
+		Self::from_fn(n, n, |row, col| row == col)
+	}
+
+	/// Constructs a matrix by calling a function with the row and column index
+	/// of each bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::from_fn(2, 3, |r, c| r < c);
+	/// assert_eq!(m[0], bits![0, 1, 1]);
+	/// assert_eq!(m[1], bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn from_fn<F>(rows: usize, cols: usize, mut func: F) -> Self
+	where F: FnMut(usize, usize) -> bool {
+		let mut out = Self::new(rows, cols);
+		for row in 0 .. rows {
+			for (col, mut bit) in out.row_mut(row).iter_mut().enumerate() {
+				*bit = func(row, col);
+			}
+		}
+		out
+	}
+
+	/// Constructs a matrix by copying a sequence of equal-length rows.
+	///
+	/// An empty sequence produces a matrix with no rows and no columns.
+	///
+	/// ## Panics
+	///
+	/// This panics if the rows do not all have the same length.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u32, Lsb0>::from_rows([
+	///   bits![u8, Msb0; 1, 0, 1],
+	///   bits![u8, Msb0; 0, 1, 1],
+	/// ]);
+	/// assert_eq!((m.rows(), m.cols()), (2, 3));
+	/// assert_eq!(m[1], bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn from_rows<'a, T2, O2, I>(rows: I) -> Self
+	where
+		T2: 'a + BitStore,
+		O2: BitOrder,
+		I: IntoIterator<Item = &'a BitSlice<T2, O2>>,
+	{
+		let mut rows = rows.into_iter().peekable();
+		let cols = rows.peek().map_or(0, |row| row.len());
+		let stride = mem::elts::<T::Mem>(cols) * mem::bits_of::<T::Mem>();
+		let mut bits = BitVec::<T, O>::new();
+		let mut count = 0;
+		for row in rows {
+			assert_eq!(
+				row.len(),
+				cols,
+				"bit-matrix rows must have equal length"
+			);
+			bits.extend_from_bitslice(row);
+			bits.resize((count + 1) * stride, false);
+			count += 1;
+		}
+		Self {
+			bits: bits.into_boxed_bitslice(),
+			rows: count,
+			cols,
+			stride,
+		}
+	}
+}
+
+/// Accessors.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the number of rows.
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Gets the distance, in bits, between the starts of successive rows.
+	///
+	/// This is the column count rounded up to a whole number of `T` elements.
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn stride(&self) -> usize {
+		self.stride
+	}
+
+	/// Tests if the matrix has as many rows as columns.
+	#[inline]
+	pub fn is_square(&self) -> bool {
+		self.rows == self.cols
+	}
+
+	/// Views one row of the matrix.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::identity(3);
+	/// assert_eq!(m.row(2), bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn row(&self, row: usize) -> &BitSlice<T, O> {
+		self.assert_row(row);
+		unsafe { self.row_unchecked(row) }
+	}
+
+	/// Views one row of the matrix mutably.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8, Lsb0>::new(2, 3);
+	/// m.row_mut(1).fill(true);
+	/// assert_eq!(m.count_ones(), 3);
+	/// ```
+	#[inline]
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<T, O> {
+		self.assert_row(row);
+		let (start, cols) = (row * self.stride, self.cols);
+		unsafe { self.bits.get_unchecked_mut(start .. start + cols) }
+	}
+
+	/// Reads the bit at a row and column, if both are in bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::identity(2);
+	/// assert_eq!(m.get(1, 1), Some(true));
+	/// assert_eq!(m.get(1, 0), Some(false));
+	/// assert_eq!(m.get(2, 0), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+		if row >= self.rows || col >= self.cols {
+			return None;
+		}
+		Some(unsafe { *self.row_unchecked(row).get_unchecked(col) })
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// ## Panics
+	///
+	/// This panics if either index is out of bounds.
+	#[inline]
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.row_mut(row).set(col, value);
+	}
+
+	/// Iterates over the rows of the matrix.
+	#[inline]
+	pub fn iter_rows(&self) -> Rows<T, O> {
+		Rows::new(self)
+	}
+
+	/// Iterates over the bits of one column of the matrix, from the first row
+	/// to the last.
+	///
+	/// ## Panics
+	///
+	/// This panics if `col` is out of bounds.
+	#[inline]
+	pub fn column(&self, col: usize) -> Column<T, O> {
+		assert!(
+			col < self.cols,
+			"column index {} out of bounds: {}",
+			col,
+			self.cols,
+		);
+		Column::new(self, col)
+	}
+
+	/// Counts the bits in the matrix that are set to `1`.
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		//  The padding between rows is always zero.
+		self.bits.count_ones()
+	}
+
+	/// Views a row without checking that it is in bounds.
+	///
+	/// ## Safety
+	///
+	/// `row` must be less than `self.rows`.
+	pub(crate) unsafe fn row_unchecked(&self, row: usize) -> &BitSlice<T, O> {
+		let start = row * self.stride;
+		self.bits.get_unchecked(start .. start + self.cols)
+	}
+
+	/// Asserts that a row index is in bounds.
+	fn assert_row(&self, row: usize) {
+		assert!(
+			row < self.rows,
+			"row index {} out of bounds: {}",
+			row,
+			self.rows,
+		);
+	}
+}
+
+/// Elementary row operations.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Exchanges the contents of two rows.
+	///
+	/// This swaps whole memory elements, rather than individual bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8, Lsb0>::identity(3);
+	/// m.swap_rows(0, 2);
+	/// assert_eq!(m[0], bits![0, 0, 1]);
+	/// assert_eq!(m[2], bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn swap_rows(&mut self, a: usize, b: usize) {
+		self.assert_row(a);
+		self.assert_row(b);
+		if a == b {
+			return;
+		}
+		let (lo, hi) = (cmp::min(a, b), cmp::max(a, b));
+		let elts = self.stride / mem::bits_of::<T::Mem>();
+		let (left, right) = self.bits.as_raw_mut_slice().split_at_mut(hi * elts);
+		left[lo * elts ..][.. elts].swap_with_slice(&mut right[.. elts]);
+	}
+
+	/// Adds row `src` into row `dst`.
+	///
+	/// In GF(2), addition is exclusive-or, so this is `dst ^= src`. It is
+	/// performed one processor word at a time. Adding a row to itself clears
+	/// it.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8, Lsb0>::identity(3);
+	/// m.add_row(0, 2);
+	/// assert_eq!(m[2], bits![1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn add_row(&mut self, src: usize, dst: usize) {
+		self.assert_row(src);
+		self.assert_row(dst);
+		if src == dst {
+			return self.row_mut(dst).fill(false);
+		}
+		let (lo, hi) = (cmp::min(src, dst), cmp::max(src, dst));
+		let (stride, cols) = (self.stride, self.cols);
+		//  The split falls on an element boundary, so neither side aliases.
+		let (left, right) =
+			unsafe { self.bits.split_at_unchecked_mut_noalias(hi * stride) };
+		let lo_row = unsafe { left.get_unchecked_mut(lo * stride ..) };
+		let (lo_row, hi_row) = unsafe {
+			(
+				lo_row.get_unchecked_mut(.. cols),
+				right.get_unchecked_mut(.. cols),
+			)
+		};
+		if src < dst {
+			*hi_row ^= &*lo_row;
+		}
+		else {
+			*lo_row ^= &*hi_row;
+		}
+	}
+}
+
+/// Linear algebra over GF(2).
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Produces the transpose of the matrix.
+	///
+	/// This loads square blocks, one processor word wide, out of the matrix and
+	/// transposes each block in registers with the recursive exchange of
+	/// Hacker’s Delight §7-3, rather than moving one bit at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::from_rows([
+	///   bits![1, 1, 0],
+	///   bits![0, 0, 1],
+	/// ]);
+	/// let t = m.transpose();
+	/// assert_eq!((t.rows(), t.cols()), (3, 2));
+	/// assert_eq!(t[0], bits![1, 0]);
+	/// assert_eq!(t[2], bits![0, 1]);
+	/// ```
+	#[inline]
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::new(self.cols, self.rows);
+		let mut block = [0usize; WORD_BITS];
+		for row_base in (0 .. self.rows).step_by(WORD_BITS) {
+			let row_end = cmp::min(row_base + WORD_BITS, self.rows);
+			for col_base in (0 .. self.cols).step_by(WORD_BITS) {
+				let col_end = cmp::min(col_base + WORD_BITS, self.cols);
+				for (row, word) in (row_base .. row_end).zip(block.iter_mut()) {
+					*word = unsafe {
+						self.row_unchecked(row)
+							.get_unchecked(col_base .. col_end)
+					}
+					.load_word();
+				}
+				block[row_end - row_base ..].fill(0);
+				transpose_block(&mut block);
+				for (col, word) in (col_base .. col_end).zip(block.iter()) {
+					unsafe {
+						out.row_mut(col).get_unchecked_mut(row_base .. row_end)
+					}
+					.store_word(*word);
+				}
+			}
+		}
+		out
+	}
+
+	/// Multiplies the matrix by a column vector.
+	///
+	/// Each bit of the product is the parity of the intersection of one row
+	/// with `vector`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `vector` is not as long as a row.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::from_rows([
+	///   bits![1, 1, 0],
+	///   bits![0, 1, 1],
+	/// ]);
+	/// assert_eq!(m.mul_vec(bits![1, 1, 1]), bits![0, 0]);
+	/// assert_eq!(m.mul_vec(bits![1, 0, 0]), bits![1, 0]);
+	/// ```
+	#[inline]
+	pub fn mul_vec<T2, O2>(&self, vector: &BitSlice<T2, O2>) -> BitVec<T, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			vector.len(),
+			self.cols,
+			"vector length must match the column count",
+		);
+		self.iter_rows()
+			.map(|row| row.intersection_count(vector) & 1 == 1)
+			.collect()
+	}
+
+	/// Transforms the matrix into reduced row-echelon form by Gauss-Jordan
+	/// elimination, and returns its rank.
+	///
+	/// Afterwards, the first `rank` rows each begin with a `1` bit that is the
+	/// only `1` in its column, further right than the leading `1` of each row
+	/// above it, and all remaining rows are zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::<u8, Lsb0>::from_rows([
+	///   bits![0, 1, 1],
+	///   bits![1, 1, 0],
+	///   bits![1, 0, 1],
+	/// ]);
+	/// assert_eq!(m.row_reduce(), 2);
+	/// assert_eq!(m[0], bits![1, 0, 1]);
+	/// assert_eq!(m[1], bits![0, 1, 1]);
+	/// assert_eq!(m[2], bits![0, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn row_reduce(&mut self) -> usize {
+		self.eliminate(self.cols)
+	}
+
+	/// Computes the rank of the matrix: the number of linearly independent
+	/// rows.
+	///
+	/// This performs elimination on a copy of the matrix.
+	#[inline]
+	pub fn rank(&self) -> usize {
+		self.clone().row_reduce()
+	}
+
+	/// Computes the inverse of a square matrix, if it has one.
+	///
+	/// Returns `None` if the matrix is singular.
+	///
+	/// ## Panics
+	///
+	/// This panics if the matrix is not square.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::from_rows([
+	///   bits![1, 1],
+	///   bits![0, 1],
+	/// ]);
+	/// assert_eq!(m.inverse().unwrap(), m);
+	/// assert!(BitMatrix::<u8, Lsb0>::new(2, 2).inverse().is_none());
+	/// ```
+	#[inline]
+	pub fn inverse(&self) -> Option<Self> {
+		assert!(self.is_square(), "only square bit-matrices have inverses");
+		let n = self.rows;
+		let mut aug = self.augment(n, |row, extra| extra.set(row, true));
+		if aug.eliminate(n) < n {
+			return None;
+		}
+		let mut out = Self::new(n, n);
+		for row in 0 .. n {
+			out.row_mut(row).copy_from_bitslice(unsafe {
+				aug.row_unchecked(row).get_unchecked(n ..)
+			});
+		}
+		Some(out)
+	}
+
+	/// Solves the system `self × x = rhs` for `x`.
+	///
+	/// If the system has many solutions, this returns the one whose free
+	/// variables are all `0`. If it has none, this returns `None`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `rhs` is not as long as a column.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8, Lsb0>::from_rows([
+	///   bits![1, 1, 0],
+	///   bits![0, 1, 1],
+	/// ]);
+	/// let x = m.solve(bits![1, 0]).unwrap();
+	/// assert_eq!(m.mul_vec(&x), bits![1, 0]);
+	///
+	/// let singular = BitMatrix::<u8, Lsb0>::from_rows([bits![1, 1], bits![1, 1]]);
+	/// assert!(singular.solve(bits![0, 1]).is_none());
+	/// ```
+	#[inline]
+	pub fn solve<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Option<BitVec<T, O>>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			rhs.len(),
+			self.rows,
+			"right-hand side length must match the row count",
+		);
+		let n = self.cols;
+		let mut aug = self.augment(1, |row, extra| extra.set(0, rhs[row]));
+		let rank = aug.eliminate(n);
+		if (rank .. aug.rows).any(|row| aug.row(row)[n]) {
+			return None;
+		}
+		let mut out = BitVec::repeat(false, n);
+		for row in aug.iter_rows().take(rank) {
+			let (coeffs, value) = row.split_at(n);
+			if let Some(pivot) = coeffs.first_one() {
+				out.set(pivot, value[0]);
+			}
+		}
+		Some(out)
+	}
+
+	/// Copies the matrix into a wider one, filling the new columns of each
+	/// row with `func`.
+	fn augment<F>(&self, extra: usize, mut func: F) -> Self
+	where F: FnMut(usize, &mut BitSlice<T, O>) {
+		let cols = self.cols;
+		let mut out = Self::new(self.rows, cols + extra);
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dest = out.row_mut(idx);
+			dest[.. cols].copy_from_bitslice(row);
+			func(idx, &mut dest[cols ..]);
+		}
+		out
+	}
+
+	/// Performs Gauss-Jordan elimination using only the first `limit` columns
+	/// as pivots, and returns the number of pivots found.
+	fn eliminate(&mut self, limit: usize) -> usize {
+		let mut rank = 0;
+		for col in 0 .. limit {
+			if rank == self.rows {
+				break;
+			}
+			let pivot = match (rank .. self.rows).find(|&row| unsafe {
+				*self.row_unchecked(row).get_unchecked(col)
+			}) {
+				Some(pivot) => pivot,
+				None => continue,
+			};
+			self.swap_rows(rank, pivot);
+			for row in 0 .. self.rows {
+				if row != rank
+					&& unsafe { *self.row_unchecked(row).get_unchecked(col) }
+				{
+					self.add_row(rank, row);
+				}
+			}
+			rank += 1;
+		}
+		rank
+	}
+}
+
+/// Transposes a square block of bits, held as one processor word per row
+/// with column `c` at numerical significance `2^c`.
+///
+/// At each step, every pair of rows `k` and `k + j` exchanges the high `j`
+/// columns of the former with the low `j` columns of the latter, within each
+/// `2j`-wide group of columns. After `log2(WORD_BITS)` steps, this transposes
+/// the whole block.
+fn transpose_block(block: &mut [usize; WORD_BITS]) {
+	let mut width = WORD_BITS / 2;
+	let mut mask = !0usize >> width;
+	while width != 0 {
+		let mut k = 0;
+		while k < WORD_BITS {
+			let swap = ((block[k] >> width) ^ block[k + width]) & mask;
+			block[k] ^= swap << width;
+			block[k + width] ^= swap;
+			k = (k + width + 1) & !width;
+		}
+		width /= 2;
+		mask ^= mask << width;
+	}
+}
@@ -0,0 +1,190 @@
+#![doc = include_str!("../../doc/matrix/iter.md")]
+
+use core::{
+	iter::FusedIterator,
+	ops::Range,
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/matrix/iter/Rows.md")]
+pub struct Rows<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The matrix being iterated.
+	matrix: &'a BitMatrix<T, O>,
+	/// The indices of the rows not yet yielded.
+	range:  Range<usize>,
+}
+
+impl<'a, T, O> Rows<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(matrix: &'a BitMatrix<T, O>) -> Self {
+		Self {
+			range: 0 .. matrix.rows(),
+			matrix,
+		}
+	}
+}
+
+impl<'a, T, O> Iterator for Rows<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let matrix = self.matrix;
+		self.range
+			.next()
+			.map(|row| unsafe { matrix.row_unchecked(row) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let matrix = self.matrix;
+		self.range
+			.nth(n)
+			.map(|row| unsafe { matrix.row_unchecked(row) })
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let matrix = self.matrix;
+		self.range
+			.next_back()
+			.map(|row| unsafe { matrix.row_unchecked(row) })
+	}
+}
+
+impl<T, O> ExactSizeIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.range.len()
+	}
+}
+
+impl<T, O> FusedIterator for Rows<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/matrix/iter/Column.md")]
+pub struct Column<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The matrix being iterated.
+	matrix: &'a BitMatrix<T, O>,
+	/// The index of the column being viewed.
+	col:    usize,
+	/// The indices of the rows not yet yielded.
+	range:  Range<usize>,
+}
+
+impl<'a, T, O> Column<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(matrix: &'a BitMatrix<T, O>, col: usize) -> Self {
+		Self {
+			range: 0 .. matrix.rows(),
+			matrix,
+			col,
+		}
+	}
+
+	/// Reads the bit in the viewed column at some row.
+	fn bit(&self, row: usize) -> bool {
+		unsafe { *self.matrix.row_unchecked(row).get_unchecked(self.col) }
+	}
+}
+
+impl<T, O> Iterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.range.next().map(|row| self.bit(row))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.range.nth(n).map(|row| self.bit(row))
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.range.next_back().map(|row| self.bit(row))
+	}
+}
+
+impl<T, O> ExactSizeIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.range.len()
+	}
+}
+
+impl<T, O> FusedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
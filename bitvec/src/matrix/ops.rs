@@ -0,0 +1,106 @@
+//! Operator trait implementations for bit-matrices.
+
+use core::ops::{
+	Index,
+	IndexMut,
+	Mul,
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// Indexing a matrix produces one of its rows.
+impl<T, O> Index<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSlice<T, O>;
+
+	#[inline]
+	fn index(&self, row: usize) -> &Self::Output {
+		self.row(row)
+	}
+}
+
+impl<T, O> IndexMut<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+		self.row_mut(row)
+	}
+}
+
+/** Multiplies two matrices over GF(2).
+
+Each row of the product is the exclusive-or of the rows of `rhs` selected by the
+`1` bits in the corresponding row of `self`, so the product is accumulated one
+processor word at a time.
+
+## Panics
+
+This panics if the column count of `self` differs from the row count of `rhs`.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::matrix::BitMatrix;
+
+let a = BitMatrix::<u8, Lsb0>::from_rows([bits![1, 1], bits![0, 1]]);
+let b = BitMatrix::<u8, Lsb0>::from_rows([bits![1, 0, 1], bits![1, 1, 0]]);
+let c = &a * &b;
+assert_eq!(c[0], bits![0, 1, 1]);
+assert_eq!(c[1], bits![1, 1, 0]);
+```
+**/
+impl<T, O> Mul<&BitMatrix<T, O>> for &BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitMatrix<T, O>;
+
+	#[inline]
+	fn mul(self, rhs: &BitMatrix<T, O>) -> Self::Output {
+		assert_eq!(
+			self.cols(),
+			rhs.rows(),
+			"matrix dimensions do not agree for multiplication",
+		);
+		let mut out = BitMatrix::new(self.rows(), rhs.cols());
+		for (idx, row) in self.iter_rows().enumerate() {
+			let dest = out.row_mut(idx);
+			for k in row.iter_ones() {
+				*dest ^= unsafe { rhs.row_unchecked(k) };
+			}
+		}
+		out
+	}
+}
+
+/// Multiplies a matrix by a column vector over GF(2).
+///
+/// This is equivalent to [`BitMatrix::mul_vec`].
+impl<T, O, T2, O2> Mul<&BitSlice<T2, O2>> for &BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	type Output = BitVec<T, O>;
+
+	#[inline]
+	fn mul(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.mul_vec(rhs)
+	}
+}
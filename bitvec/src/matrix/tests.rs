@@ -0,0 +1,153 @@
+//! Unit tests for bit-matrices.
+
+#![cfg(test)]
+
+use rand::random;
+
+use crate::{
+	matrix::BitMatrix,
+	prelude::*,
+};
+
+/// Builds a random matrix, one bit at a time.
+fn random_matrix<T, O>(rows: usize, cols: usize) -> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	BitMatrix::from_fn(rows, cols, |_, _| random())
+}
+
+#[test]
+fn construction() {
+	let m = BitMatrix::<u16, Msb0>::from_fn(5, 19, |r, c| (r + c) % 3 == 0);
+	assert_eq!(m.stride(), 32);
+	for (r, row) in m.iter_rows().enumerate() {
+		assert_eq!(row.len(), 19);
+		for c in 0 .. 19 {
+			assert_eq!(row[c], (r + c) % 3 == 0);
+			assert_eq!(m.get(r, c), Some(row[c]));
+		}
+	}
+	assert_eq!(m.get(0, 19), None);
+	assert_eq!(m.iter_rows().next_back().unwrap(), m[4]);
+	assert!(m.column(7).eq((0 .. 5).map(|r| (r + 7) % 3 == 0)));
+	assert_eq!(m.column(0).rev().len(), 5);
+
+	let copy = BitMatrix::<u8, Lsb0>::from_rows(m.iter_rows());
+	assert_eq!(copy, m);
+	assert_eq!(copy.stride(), 24);
+	assert_eq!(copy.count_ones(), m.count_ones());
+
+	let empty =
+		BitMatrix::<u8, Lsb0>::from_rows(core::iter::empty::<&BitSlice>());
+	assert_eq!((empty.rows(), empty.cols()), (0, 0));
+	assert!(BitMatrix::<u8, Lsb0>::new(3, 0)
+		.iter_rows()
+		.all(BitSlice::is_empty));
+
+	let mut m = BitMatrix::<u32, Lsb0>::identity(4);
+	m.set(0, 3, true);
+	m.swap_rows(0, 3);
+	assert_eq!(m[3], bits![1, 0, 0, 1]);
+	m.add_row(3, 0);
+	assert_eq!(m[0], bits![1, 0, 0, 0]);
+	m.add_row(1, 1);
+	assert!(m[1].not_any());
+	assert_eq!(
+		format!("{}", BitMatrix::<u8, Lsb0>::identity(2)),
+		"[[1, 0], [0, 1]]"
+	);
+}
+
+#[test]
+#[should_panic = "bit-matrix rows must have equal length"]
+fn ragged_rows() {
+	BitMatrix::<u8, Lsb0>::from_rows([bits![0, 1], bits![0]]);
+}
+
+#[test]
+fn transpose() {
+	fn check<T, O>(rows: usize, cols: usize)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let m = random_matrix::<T, O>(rows, cols);
+		let t = m.transpose();
+		assert_eq!((t.rows(), t.cols()), (cols, rows));
+		for r in 0 .. rows {
+			for c in 0 .. cols {
+				assert_eq!(m.get(r, c), t.get(c, r));
+			}
+		}
+		assert_eq!(t.transpose(), m);
+	}
+
+	for (rows, cols) in [(0, 5), (1, 1), (8, 8), (13, 70), (64, 64), (130, 65)] {
+		check::<u8, Lsb0>(rows, cols);
+		check::<u64, Msb0>(rows, cols);
+		check::<u16, crate::order::HiLo>(rows, cols);
+	}
+}
+
+#[test]
+fn algebra() {
+	let a = random_matrix::<u8, Msb0>(20, 37);
+	let b = random_matrix::<u8, Msb0>(37, 11);
+	let c = &a * &b;
+	for r in 0 .. 20 {
+		for k in 0 .. 11 {
+			let bit = (0 .. 37).filter(|&i| a[r][i] & b[i][k]).count() % 2 == 1;
+			assert_eq!(c[r][k], bit);
+		}
+	}
+	let v = random::<u64>();
+	let v = &v.view_bits::<Lsb0>()[.. 11];
+	assert_eq!(&c * v, &a * (&b * v).as_bitslice());
+	assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+
+	//  Upper-unitriangular matrices are always invertible.
+	let n = 70;
+	let mut m = BitMatrix::<u32, Lsb0>::from_fn(n, n, |r, c| {
+		r == c || (r < c && random())
+	});
+	for _ in 0 .. 100 {
+		let (src, dst) = (random::<usize>() % n, random::<usize>() % n);
+		if src != dst {
+			m.add_row(src, dst);
+		}
+	}
+	assert_eq!(m.rank(), n);
+	let inv = m.inverse().unwrap();
+	assert_eq!(&m * &inv, BitMatrix::<u32, Lsb0>::identity(n));
+	assert_eq!(&inv * &m, BitMatrix::<u32, Lsb0>::identity(n));
+
+	let rhs = random::<[u32; 3]>();
+	let rhs = &rhs.view_bits::<Msb0>()[.. n];
+	let x = m.solve(rhs).unwrap();
+	assert_eq!(m.mul_vec(&x), rhs);
+
+	//  A duplicated row drops the rank, and makes some systems unsolvable.
+	m.add_row(0, 1);
+	m.add_row(2, 1);
+	let dup = m.row(0).to_bitvec();
+	m.row_mut(1).copy_from_bitslice(&dup);
+	assert_eq!(m.rank(), n - 1);
+	assert!(m.inverse().is_none());
+	let mut rhs = m.mul_vec(&x);
+	let x2 = m.solve(&rhs).unwrap();
+	assert_eq!(m.mul_vec(&x2), rhs);
+	let flip = !rhs[1];
+	rhs.set(1, flip);
+	assert!(m.solve(&rhs).is_none());
+
+	let mut wide = random_matrix::<u16, Lsb0>(5, 40);
+	let rank = wide.clone().rank();
+	assert_eq!(wide.row_reduce(), rank);
+	for r in 0 .. rank {
+		let pivot = wide[r].first_one().unwrap();
+		assert_eq!(wide.column(pivot).filter(|&b| b).count(), 1);
+	}
+	assert!(wide.iter_rows().skip(rank).all(BitSlice::not_any));
+}
@@ -0,0 +1,133 @@
+//! General trait implementations for bit-matrices.
+
+use core::{
+	any,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<T, O> Clone for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits:   self.bits.clone(),
+			rows:   self.rows,
+			cols:   self.cols,
+			stride: self.stride,
+		}
+	}
+}
+
+impl<T, O> Eq for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Matrices are equal when they have the same dimensions and the same bit in
+/// every position.
+impl<T1, T2, O1, O2> PartialEq<BitMatrix<T2, O2>> for BitMatrix<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &BitMatrix<T2, O2>) -> bool {
+		self.rows == other.rows
+			&& self.cols == other.cols
+			&& self.iter_rows().zip(other.iter_rows()).all(|(a, b)| a == b)
+	}
+}
+
+impl<T, O> Hash for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.rows.hash(hasher);
+		self.cols.hash(hasher);
+		for row in self.iter_rows() {
+			row.hash(hasher);
+		}
+	}
+}
+
+impl<T, O> Debug for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"BitMatrix<{}, {}>",
+			any::type_name::<T::Mem>(),
+			any::type_name::<O>(),
+		)?;
+		fmt.debug_struct("")
+			.field("rows", &self.rows)
+			.field("cols", &self.cols)
+			.field("stride", &self.stride)
+			.finish()?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+/// Renders each row as a list of bits, inside a list of rows.
+impl<T, O> Display for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_list()
+			.entries(self.iter_rows().map(RowDisplay))
+			.finish()
+	}
+}
+
+/// Adapts a row to render through its `Display` implementation inside a
+/// debug list.
+struct RowDisplay<'a, T, O>(&'a BitSlice<T, O>)
+where
+	T: BitStore,
+	O: BitOrder;
+
+impl<T, O> Debug for RowDisplay<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.0, fmt)
+	}
+}
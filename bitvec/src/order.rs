@@ -16,6 +16,22 @@ use crate::{
 
 #[doc = include_str!("../doc/order/BitOrder.md")]
 pub unsafe trait BitOrder: 'static {
+	/// Declares how the ordering lays out a run of indices within an element.
+	///
+	/// This is an optional constant, which defaults to
+	/// [`Contiguity::Scattered`]. An ordering which declares itself
+	/// [`Ascending`] or [`Descending`] must translate every index exactly as
+	/// [`Lsb0`] or [`Msb0`] does, respectively, and in exchange has its
+	/// [`BitField`] transfers performed with a single shift and mask in each
+	/// element rather than by gathering its bits one at a time.
+	///
+	/// This requirement is checked by [`verify_for_type`].
+	///
+	/// [`Ascending`]: Contiguity::Ascending
+	/// [`BitField`]: crate::field::BitField
+	/// [`Descending`]: Contiguity::Descending
+	const CONTIGUITY: Contiguity = Contiguity::Scattered;
+
 	/// Translates a semantic bit index into a real bit position.
 	///
 	/// This function is the basis of the trait, and must adhere to a number of
@@ -113,6 +129,17 @@ pub unsafe trait BitOrder: 'static {
 	}
 }
 
+#[doc = include_str!("../doc/order/Contiguity.md")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Contiguity {
+	/// Indices may be placed anywhere in an element.
+	Scattered,
+	/// Index `n` is at position `n`, as in [`Lsb0`].
+	Ascending,
+	/// Index `n` is at position `R::BITS - 1 - n`, as in [`Msb0`].
+	Descending,
+}
+
 #[doc = include_str!("../doc/order/Lsb0.md")]
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Lsb0;
@@ -122,6 +149,8 @@ pub struct Lsb0;
 pub struct Msb0;
 
 unsafe impl BitOrder for Lsb0 {
+	const CONTIGUITY: Contiguity = Contiguity::Ascending;
+
 	#[inline]
 	fn at<R>(index: BitIdx<R>) -> BitPos<R>
 	where R: BitRegister {
@@ -169,6 +198,8 @@ unsafe impl BitOrder for Lsb0 {
 }
 
 unsafe impl BitOrder for Msb0 {
+	const CONTIGUITY: Contiguity = Contiguity::Descending;
+
 	#[inline]
 	fn at<R>(index: BitIdx<R>) -> BitPos<R>
 	where R: BitRegister {
@@ -286,6 +317,25 @@ where
 			);
 		}
 
+		//  If the ordering declares a contiguous layout that the position
+		//  does not follow, fail.
+		let expected = match O::CONTIGUITY {
+			Contiguity::Scattered => pos.into_inner(),
+			Contiguity::Ascending => n,
+			Contiguity::Descending => bits_of::<R>() as u8 - 1 - n,
+		};
+		assert_eq!(
+			pos.into_inner(),
+			expected,
+			"Error when verifying the implementation of `BitOrder` for `{}`: \
+			 Index {} produces a bit position ({}) that does not match its \
+			 declared contiguity ({:?})",
+			ord_name,
+			n,
+			pos.into_inner(),
+			O::CONTIGUITY,
+		);
+
 		//  If the computed position exceeds the valid range, fail.
 		assert!(
 			pos.into_inner() < bits_of::<R>() as u8,
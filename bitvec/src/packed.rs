@@ -0,0 +1,359 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/packed.md")]
+
+use funty::Fundamental;
+
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Contiguity,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod fixed;
+mod iter;
+mod tests;
+mod traits;
+
+pub use self::{
+	fixed::FixedPackedIntVec,
+	iter::Iter,
+};
+
+#[doc = include_str!("../doc/packed/PackedIntVec.md")]
+pub struct PackedIntVec<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The concatenated elements. Element `i` occupies
+	/// `bits[i * width .. (i + 1) * width]`, and this always begins at the
+	/// front of its first memory element.
+	bits:  BitVec<T, O>,
+	/// The number of bits in each element.
+	width: usize,
+}
+
+/// Constructors and conversions.
+impl<T, O> PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs an empty vector of `width`-bit integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero or greater than 64.
+	#[inline]
+	pub fn new(width: usize) -> Self {
+		Self::with_capacity(width, 0)
+	}
+
+	/// Constructs an empty vector of `width`-bit integers, with room for at
+	/// least `capacity` of them before reallocating.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero or greater than 64.
+	#[inline]
+	pub fn with_capacity(width: usize, capacity: usize) -> Self {
+		check_width(width);
+		Self {
+			bits: BitVec::with_capacity(capacity * width),
+			width,
+		}
+	}
+
+	/// Views the concatenated elements as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Unwraps the concatenated elements into a bit-vector.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+}
+
+/// Queries.
+impl<T, O> PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the number of bits in each element.
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Gets the number of elements in the vector.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.len() / self.width
+	}
+
+	/// Tests if the vector has no elements.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Removes all elements from the vector, keeping its allocation.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+}
+
+/// Element access.
+impl<T, O> PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Reads an element.
+	///
+	/// Returns `None` if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut piv = PackedIntVec::<u8>::new(5);
+	/// piv.push(31);
+	/// piv.push(7);
+	/// assert_eq!(piv.get(1), Some(7));
+	/// assert_eq!(piv.get(2), None);
+	/// ```
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<u64> {
+		if index >= self.len() {
+			return None;
+		}
+		Some(load(&self.bits, index, self.width))
+	}
+
+	/// Writes an element.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if `value` does not fit in
+	/// the element width.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut piv = [1, 2, 3].into_iter().collect::<PackedIntVec>();
+	/// piv.set(1, 0);
+	/// assert!(piv.iter().eq([1, 0, 3]));
+	/// ```
+	#[inline]
+	pub fn set(&mut self, index: usize, value: u64) {
+		let len = self.len();
+		assert!(index < len, "index {} out of range: {}", index, len);
+		check_value(value, self.width);
+		store(&mut self.bits, index, self.width, value);
+	}
+
+	/// Appends an element.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` does not fit in the element width.
+	#[inline]
+	pub fn push(&mut self, value: u64) {
+		check_value(value, self.width);
+		let index = self.len();
+		self.bits.resize(self.bits.len() + self.width, false);
+		store(&mut self.bits, index, self.width, value);
+	}
+
+	/// Removes the last element, and returns it.
+	#[inline]
+	pub fn pop(&mut self) -> Option<u64> {
+		let index = self.len().checked_sub(1)?;
+		let out = load(&self.bits, index, self.width);
+		self.bits.truncate(index * self.width);
+		Some(out)
+	}
+
+	/// Iterates over the elements.
+	#[inline]
+	pub fn iter(&self) -> Iter<T, O> {
+		Iter::new(&self.bits, self.width)
+	}
+
+	/// Counts the fewest bits that can hold every element of the vector. This
+	/// is at least 1.
+	#[inline]
+	pub fn min_width(&self) -> usize {
+		width_of(self.iter().fold(0, |max, value| max | value))
+	}
+
+	/// Changes the width of every element in the vector, keeping their values.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero or greater than 64, or if it is too
+	/// narrow for any of the elements. [`.min_width()`] is the narrowest width
+	/// that will succeed.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::packed::PackedIntVec;
+	///
+	/// let mut piv = PackedIntVec::<u16>::new(13);
+	/// piv.extend([5, 9, 300]);
+	/// assert_eq!(piv.min_width(), 9);
+	///
+	/// piv.repack(9);
+	/// assert_eq!(piv.as_bitslice().len(), 27);
+	/// assert!(piv.iter().eq([5, 9, 300]));
+	/// ```
+	///
+	/// [`.min_width()`]: Self::min_width
+	#[inline]
+	pub fn repack(&mut self, width: usize) {
+		check_width(width);
+		if width == self.width {
+			return;
+		}
+		let min = self.min_width();
+		assert!(
+			width >= min,
+			"cannot repack {}-bit elements into {} bits",
+			min,
+			width,
+		);
+		let mut out = Self::with_capacity(width, self.len());
+		out.extend(self.iter());
+		*self = out;
+	}
+}
+
+/// Counts the fewest bits, but at least 1, that can hold `value`.
+fn width_of(value: u64) -> usize {
+	(bits_of::<u64>() - value.leading_zeros() as usize).max(1)
+}
+
+/// Panics if `width` is not a usable element width.
+fn check_width(width: usize) {
+	assert!(
+		(1 ..= bits_of::<u64>()).contains(&width),
+		"element width must be between 1 and 64, not {}",
+		width,
+	);
+}
+
+/// Panics if `value` does not fit in `width` bits.
+fn check_value(value: u64, width: usize) {
+	assert!(
+		width == bits_of::<u64>() || value >> width == 0,
+		"{} does not fit in {} bits",
+		value,
+		width,
+	);
+}
+
+/// Tests if `width`-bit elements can be moved directly to and from memory
+/// elements, without going through `BitField`.
+///
+/// This holds when `width` divides the memory element width, so that no
+/// element crosses a memory element boundary, and `O` is an ordering whose
+/// `BitField` implementation is a shift and mask within one memory element.
+#[inline]
+fn is_aligned<T, O>(width: usize) -> bool
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	width.is_power_of_two()
+		&& width <= bits_of::<T::Mem>()
+		&& O::CONTIGUITY != Contiguity::Scattered
+}
+
+/// Locates an aligned element, as the index of its memory element and the
+/// distance of its least significant bit from the least significant bit of
+/// that memory element.
+#[inline]
+fn locate<T, O>(index: usize, width: usize) -> (usize, usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let start = index * width;
+	let (elem, head) =
+		(start / bits_of::<T::Mem>(), start % bits_of::<T::Mem>());
+	if O::CONTIGUITY == Contiguity::Ascending {
+		(elem, head)
+	}
+	else {
+		(elem, bits_of::<T::Mem>() - head - width)
+	}
+}
+
+/// A mask of the low `width` bits of a `u64`.
+#[inline]
+fn mask(width: usize) -> u64 {
+	!0 >> (bits_of::<u64>() - width)
+}
+
+/// Reads element `index` of `width`-bit elements.
+#[inline]
+pub(crate) fn load<T, O>(bits: &BitVec<T, O>, index: usize, width: usize) -> u64
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	if is_aligned::<T, O>(width) {
+		let (elem, shift) = locate::<T, O>(index, width);
+		let raw = bits.as_raw_slice()[elem].load_value().as_u64();
+		return raw >> shift & mask(width);
+	}
+	bits[index * width .. (index + 1) * width].load_le()
+}
+
+/// Writes element `index` of `width`-bit elements. `value` must fit in
+/// `width` bits.
+#[inline]
+pub(crate) fn store<T, O>(
+	bits: &mut BitVec<T, O>,
+	index: usize,
+	width: usize,
+	value: u64,
+) where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	if is_aligned::<T, O>(width) {
+		let (elem, shift) = locate::<T, O>(index, width);
+		let slot = &mut bits.as_raw_mut_slice()[elem];
+		let raw = slot.load_value().as_u64() & !(mask(width) << shift)
+			| value << shift;
+		//  The element is no wider than the memory element it replaces part
+		//  of, so the result fits.
+		match T::Mem::try_from(raw) {
+			Ok(raw) => slot.store_value(raw),
+			Err(_) => unreachable!("an aligned element fits in its memory"),
+		}
+		return;
+	}
+	bits[index * width .. (index + 1) * width].store_le(value);
+}
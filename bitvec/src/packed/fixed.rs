@@ -0,0 +1,162 @@
+//! Packed integers with a compile-time width.
+
+use super::{
+	check_value,
+	load,
+	store,
+	Iter,
+	PackedIntVec,
+};
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/packed/FixedPackedIntVec.md")]
+pub struct FixedPackedIntVec<const W: usize, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The elements, which are always `W` bits wide.
+	pub(super) inner: PackedIntVec<T, O>,
+}
+
+/// Constructors and conversions.
+impl<const W: usize, T, O> FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs an empty vector of `W`-bit integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `W` is zero or greater than 64.
+	#[inline]
+	pub fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	/// Constructs an empty vector of `W`-bit integers, with room for at least
+	/// `capacity` of them before reallocating.
+	///
+	/// ## Panics
+	///
+	/// This panics if `W` is zero or greater than 64.
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			inner: PackedIntVec::with_capacity(W, capacity),
+		}
+	}
+
+	/// Views the elements as a vector whose width is only known at runtime.
+	#[inline]
+	pub fn as_packed(&self) -> &PackedIntVec<T, O> {
+		&self.inner
+	}
+
+	/// Unwraps the elements into a vector whose width is only known at
+	/// runtime.
+	#[inline]
+	pub fn into_packed(self) -> PackedIntVec<T, O> {
+		self.inner
+	}
+
+	/// Views the concatenated elements as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.inner.as_bitslice()
+	}
+
+	/// Gets the number of elements in the vector.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.inner.bits.len() / W
+	}
+
+	/// Tests if the vector has no elements.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+
+	/// Removes all elements from the vector, keeping its allocation.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.inner.clear();
+	}
+}
+
+/// Element access.
+///
+/// These behave as the [`PackedIntVec`] methods of the same name. Because the
+/// width is a constant, the choice between the direct memory access used for
+/// widths that divide the memory element and the general `BitField` access
+/// is made at compile time.
+impl<const W: usize, T, O> FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Reads an element.
+	///
+	/// Returns `None` if `index` is out of bounds.
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<u64> {
+		if index >= self.len() {
+			return None;
+		}
+		Some(load(&self.inner.bits, index, W))
+	}
+
+	/// Writes an element.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if `value` does not fit in
+	/// `W` bits.
+	#[inline]
+	pub fn set(&mut self, index: usize, value: u64) {
+		let len = self.len();
+		assert!(index < len, "index {} out of range: {}", index, len);
+		check_value(value, W);
+		store(&mut self.inner.bits, index, W, value);
+	}
+
+	/// Appends an element.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` does not fit in `W` bits.
+	#[inline]
+	pub fn push(&mut self, value: u64) {
+		check_value(value, W);
+		let index = self.len();
+		let bits = &mut self.inner.bits;
+		bits.resize(bits.len() + W, false);
+		store(bits, index, W, value);
+	}
+
+	/// Removes the last element, and returns it.
+	#[inline]
+	pub fn pop(&mut self) -> Option<u64> {
+		let index = self.len().checked_sub(1)?;
+		let out = load(&self.inner.bits, index, W);
+		self.inner.bits.truncate(index * W);
+		Some(out)
+	}
+
+	/// Iterates over the elements.
+	#[inline]
+	pub fn iter(&self) -> Iter<T, O> {
+		self.inner.iter()
+	}
+}
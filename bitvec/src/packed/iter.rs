@@ -0,0 +1,111 @@
+#![doc = include_str!("../../doc/packed/iter.md")]
+
+use core::iter::FusedIterator;
+
+use super::load;
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[doc = include_str!("../../doc/packed/iter/Iter.md")]
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The concatenated elements.
+	bits:  &'a BitVec<T, O>,
+	/// The number of bits in each element.
+	width: usize,
+	/// The index of the next element to yield from the front.
+	front: usize,
+	/// One past the index of the next element to yield from the back.
+	back:  usize,
+}
+
+impl<'a, T, O> Iter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(bits: &'a BitVec<T, O>, width: usize) -> Self {
+		Self {
+			bits,
+			width,
+			front: 0,
+			back: bits.len() / width,
+		}
+	}
+}
+
+impl<T, O> Iterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type Item = u64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		let out = load(self.bits, self.front, self.width);
+		self.front += 1;
+		Some(out)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.front += n.min(self.back - self.front);
+		self.next()
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(load(self.bits, self.back, self.width))
+	}
+}
+
+impl<T, O> ExactSizeIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
+
+impl<T, O> FusedIterator for Iter<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
@@ -0,0 +1,134 @@
+//! Unit tests for packed integer vectors.
+
+#![cfg(test)]
+
+use core::cell::Cell;
+
+use super::*;
+use crate::order::{
+	HiLo,
+	Msb0,
+};
+
+/// Produces `count` pseudo-random values of `width` bits.
+fn values(width: usize, count: usize) -> impl Iterator<Item = u64> {
+	let mut state = 0x2545_F491_4F6C_DD1Du64;
+	(0 .. count).map(move |_| {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state & mask(width)
+	})
+}
+
+fn exercise<T, O>()
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	for width in [1, 3, 4, 5, 8, 13, 16, 31, 32, 64] {
+		let expected = values(width, 100).collect::<Vec<_>>();
+		let mut piv = PackedIntVec::<T, O>::new(width);
+		piv.extend(expected.iter());
+		assert_eq!(piv.len(), 100);
+		assert_eq!(piv.as_bitslice().len(), 100 * width);
+
+		//  Every element, whether it took the aligned path or not, is laid out
+		//  exactly as `BitField` would lay it out.
+		for (idx, (chunk, &value)) in
+			piv.as_bitslice().chunks(width).zip(&expected).enumerate()
+		{
+			assert_eq!(chunk.load_le::<u64>(), value, "width {}", width);
+			assert_eq!(piv.get(idx), Some(value));
+		}
+		assert!(piv.iter().eq(expected.iter().copied()));
+		assert!(piv.iter().rev().eq(expected.iter().rev().copied()));
+		assert_eq!(piv.get(100), None);
+
+		piv.set(7, mask(width));
+		piv.set(8, 0);
+		assert_eq!(piv.get(6), Some(expected[6]));
+		assert_eq!(piv.get(7), Some(mask(width)));
+		assert_eq!(piv.get(8), Some(0));
+		assert_eq!(piv.get(9), Some(expected[9]));
+
+		assert_eq!(piv.pop(), Some(expected[99]));
+		assert_eq!(piv.len(), 99);
+
+		let mut fixed = FixedPackedIntVec::<13, T, O>::new();
+		let mut runtime = PackedIntVec::<T, O>::new(13);
+		for value in values(13, 50) {
+			fixed.push(value);
+			runtime.push(value);
+		}
+		assert_eq!(fixed.as_bitslice(), runtime.as_bitslice());
+		assert_eq!(fixed.as_packed(), &runtime);
+	}
+}
+
+#[test]
+fn layout() {
+	exercise::<u8, Lsb0>();
+	exercise::<u16, Msb0>();
+	exercise::<u32, Lsb0>();
+	exercise::<u64, Msb0>();
+	exercise::<usize, Lsb0>();
+	exercise::<Cell<u8>, Msb0>();
+	exercise::<u16, HiLo>();
+}
+
+#[test]
+fn packed_int_vec() {
+	let mut piv = PackedIntVec::<u8, Msb0>::new(4);
+	assert!(piv.is_empty());
+	assert_eq!(piv.pop(), None);
+	piv.extend([0xA, 0x5, 0xF]);
+	assert_eq!(piv.as_bitslice(), bits![1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1]);
+	assert_eq!(format!("{:?}", piv), "[10, 5, 15]");
+	assert_eq!(piv.min_width(), 4);
+
+	piv.repack(9);
+	assert_eq!(piv.width(), 9);
+	assert_eq!(piv.as_bitslice().len(), 27);
+	piv.repack(4);
+	assert_eq!(piv.as_bitslice().len(), 12);
+	assert_eq!(piv, [10u64, 5, 15].iter().collect::<PackedIntVec>());
+
+	piv.clear();
+	assert_eq!(piv.min_width(), 1);
+	assert_eq!(piv.len(), 0);
+
+	let piv = [0u64; 10].iter().collect::<PackedIntVec<u16, Lsb0>>();
+	assert_eq!(piv.width(), 1);
+	assert_eq!(piv.into_bitvec(), bits![0; 10]);
+
+	let mut fixed = [1u64, 2, 3].iter().collect::<FixedPackedIntVec<2>>();
+	assert_eq!(fixed.len(), 3);
+	fixed.set(0, 3);
+	assert_eq!(fixed.pop(), Some(3));
+	assert!(fixed.iter().eq([3, 2]));
+	fixed.clear();
+	assert!(fixed.is_empty());
+	assert_eq!(PackedIntVec::from(fixed).width(), 2);
+}
+
+#[test]
+#[should_panic = "does not fit"]
+fn push_overflow() {
+	PackedIntVec::<u8>::new(3).push(8);
+}
+
+#[test]
+#[should_panic = "cannot repack"]
+fn repack_overflow() {
+	let mut piv = PackedIntVec::<u8>::new(8);
+	piv.push(200);
+	piv.repack(7);
+}
+
+#[test]
+#[should_panic = "element width"]
+fn zero_width() {
+	FixedPackedIntVec::<0>::new();
+}
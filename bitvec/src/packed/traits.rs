@@ -0,0 +1,359 @@
+//! General trait implementations for packed integer vectors.
+
+use alloc::vec::Vec;
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+use super::{
+	width_of,
+	FixedPackedIntVec,
+	Iter,
+	PackedIntVec,
+};
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits:  self.bits.clone(),
+			width: self.width,
+		}
+	}
+}
+
+impl<T, O> Eq for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
+
+/// Vectors are equal when they hold the same sequence of values, regardless of
+/// their element widths.
+impl<T1, T2, O1, O2> PartialEq<PackedIntVec<T2, O2>> for PackedIntVec<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+	BitSlice<T1, O1>: BitField,
+	BitSlice<T2, O2>: BitField,
+{
+	#[inline]
+	fn eq(&self, other: &PackedIntVec<T2, O2>) -> bool {
+		self.iter().eq(other.iter())
+	}
+}
+
+impl<T, O> Hash for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		//  Equal vectors may have different widths, so only the values
+		//  participate.
+		self.len().hash(hasher);
+		for value in self.iter() {
+			value.hash(hasher);
+		}
+	}
+}
+
+impl<T, O> Debug for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_list().entries(self.iter()).finish()
+	}
+}
+
+/// ## Panics
+///
+/// This panics if any value does not fit in the element width.
+impl<T, O> Extend<u64> for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u64> {
+		let iter = iter.into_iter();
+		self.bits.reserve(iter.size_hint().0 * self.width);
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> Extend<&'a u64> for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a u64> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+/// Collects values into a vector whose width is the fewest bits that can hold
+/// all of them.
+///
+/// The values are buffered in order to find their width before they are
+/// packed. Use [`PackedIntVec::new`] and `.extend()` to pack them directly into
+/// a known width.
+impl<T, O> FromIterator<u64> for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u64> {
+		let values = iter.into_iter().collect::<Vec<_>>();
+		let width = width_of(values.iter().fold(0, |max, &value| max | value));
+		let mut out = Self::with_capacity(width, values.len());
+		out.extend(values);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> FromIterator<&'a u64> for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a u64> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> IntoIterator for &'a PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type IntoIter = Iter<'a, T, O>;
+	type Item = u64;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> Clone for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> Default for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const W: usize, T, O> Eq for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+}
+
+impl<const W1: usize, const W2: usize, T1, T2, O1, O2>
+	PartialEq<FixedPackedIntVec<W2, T2, O2>> for FixedPackedIntVec<W1, T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+	BitSlice<T1, O1>: BitField,
+	BitSlice<T2, O2>: BitField,
+{
+	#[inline]
+	fn eq(&self, other: &FixedPackedIntVec<W2, T2, O2>) -> bool {
+		self.inner == other.inner
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> Hash for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.inner.hash(hasher);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> Debug for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Debug::fmt(&self.inner, fmt)
+	}
+}
+
+/// ## Panics
+///
+/// This panics if any value does not fit in `W` bits.
+impl<const W: usize, T, O> Extend<u64> for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u64> {
+		let iter = iter.into_iter();
+		self.inner.bits.reserve(iter.size_hint().0 * W);
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, const W: usize, T, O> Extend<&'a u64> for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a u64> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+/// ## Panics
+///
+/// This panics if any value does not fit in `W` bits.
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> FromIterator<u64> for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u64> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, const W: usize, T, O> FromIterator<&'a u64>
+	for FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a u64> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, const W: usize, T, O> IntoIterator for &'a FixedPackedIntVec<W, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	type IntoIter = Iter<'a, T, O>;
+	type Item = u64;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const W: usize, T, O> From<FixedPackedIntVec<W, T, O>>
+	for PackedIntVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(fixed: FixedPackedIntVec<W, T, O>) -> Self {
+		fixed.into_packed()
+	}
+}
@@ -0,0 +1,466 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/rank.md")]
+
+use alloc::vec::Vec;
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+use crate::{
+	boxed::BitBox,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+mod tests;
+
+/// The number of bits covered by one entry in the block directory.
+const BLOCK_BITS: usize = 512;
+
+/// The number of bits covered by one entry in the superblock directory.
+///
+/// This must be small enough that the number of `1` bits in a superblock that
+/// precede any of its blocks fits in a `u16`.
+const SUPER_BITS: usize = 1 << 16;
+
+/// The number of blocks in one superblock.
+const BLOCKS_PER_SUPER: usize = SUPER_BITS / BLOCK_BITS;
+
+#[doc = include_str!("../doc/rank/RankSelect.md")]
+pub struct RankSelect<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The indexed bits.
+	bits:   Bits<'a, T, O>,
+	/// The number of `1` bits preceding each superblock.
+	supers: Vec<usize>,
+	/// The number of `1` bits preceding each block, counted from the start of
+	/// its superblock.
+	blocks: Vec<u16>,
+	/// The total number of `1` bits in the index.
+	ones:   usize,
+}
+
+/// Either a borrowed or an owned bit-slice.
+enum Bits<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// A bit-slice borrowed for the lifetime of the index.
+	Borrowed(&'a BitSlice<T, O>),
+	/// A bit-box owned by the index.
+	Owned(BitBox<T, O>),
+}
+
+/// Constructors.
+impl<'a, T, O> RankSelect<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Builds a rank/select index over a borrowed bit-slice.
+	///
+	/// This walks the bit-slice once, counting each block of bits with
+	/// [`.count_ones()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let data = [0x0Fu8; 4];
+	/// let index = RankSelect::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(index.count_ones(), 16);
+	/// ```
+	///
+	/// [`.count_ones()`]: crate::slice::BitSlice::count_ones
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>) -> Self {
+		Self::build(Bits::Borrowed(bits))
+	}
+
+	/// Constructs the directories over some bits.
+	fn build(bits: Bits<'a, T, O>) -> Self {
+		let slice = bits.as_bitslice();
+		let len = slice.len();
+		let mut supers = Vec::with_capacity((len + SUPER_BITS - 1) / SUPER_BITS);
+		let mut blocks = Vec::with_capacity((len + BLOCK_BITS - 1) / BLOCK_BITS);
+
+		let mut ones = 0;
+		let mut in_super = 0;
+		for (idx, block) in slice.chunks(BLOCK_BITS).enumerate() {
+			if idx % BLOCKS_PER_SUPER == 0 {
+				supers.push(ones);
+				in_super = 0;
+			}
+			blocks.push(in_super as u16);
+			let count = block.count_ones();
+			in_super += count;
+			ones += count;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+		}
+	}
+}
+
+impl<T, O> RankSelect<'static, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Builds a rank/select index that takes ownership of its bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let index = RankSelect::from_bitbox(bitbox![1, 0, 1, 1]);
+	/// assert_eq!(index.rank1(3), 2);
+	/// ```
+	#[inline]
+	pub fn from_bitbox(bits: BitBox<T, O>) -> Self {
+		Self::build(Bits::Owned(bits))
+	}
+}
+
+/// Queries.
+impl<T, O> RankSelect<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Views the indexed bits.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Gets the number of bits in the index.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	/// Tests if the index has no bits.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.as_bitslice().is_empty()
+	}
+
+	/// Gets the total number of bits set to `1`.
+	///
+	/// This is computed during construction, and does not re-scan the bits.
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Gets the total number of bits cleared to `0`.
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+
+	/// Counts the bits set to `1` before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_ones()`, but only counts
+	/// the bits in the one block that contains `index`.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the counted range. This may be any value in `0 ..=
+	///   self.len()`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(3), 2);
+	/// assert_eq!(index.rank1(5), 3);
+	/// ```
+	#[inline]
+	pub fn rank1(&self, index: usize) -> usize {
+		let bits = self.as_bitslice();
+		bits.assert_in_bounds(index, 0 ..= bits.len());
+		let block = index / BLOCK_BITS;
+		if block == self.blocks.len() {
+			return self.ones;
+		}
+		let start = block * BLOCK_BITS;
+		self.ones_before(block)
+			+ unsafe { bits.get_unchecked(start .. index) }.count_ones()
+	}
+
+	/// Counts the bits cleared to `0` before an index.
+	///
+	/// This is equivalent to `index - self.rank1(index)`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank0(3), 1);
+	/// assert_eq!(index.rank0(5), 2);
+	/// ```
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of the `rank`th bit set to `1`.
+	///
+	/// `rank` is zero-based, so `.select1(0)` is the same as
+	/// [`.first_one()`]. This is the inverse of [`.rank1()`]: whenever it
+	/// returns `Some(idx)`, `self.rank1(idx)` is `rank` and `self[idx]` is
+	/// set.
+	///
+	/// ## Returns
+	///
+	/// The index of the requested bit, or `None` if there are not more than
+	/// `rank` bits set to `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select1(0), Some(1));
+	/// assert_eq!(index.select1(1), Some(4));
+	/// assert_eq!(index.select1(2), None);
+	/// ```
+	///
+	/// [`.first_one()`]: crate::slice::BitSlice::first_one
+	/// [`.rank1()`]: Self::rank1
+	#[inline]
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.ones {
+			return None;
+		}
+		let block = self.search(|block| self.ones_before(block) <= rank);
+		let rest = rank - self.ones_before(block);
+		select_in(
+			self.block(block),
+			rest,
+			BitSlice::count_ones,
+			BitSlice::iter_ones,
+		)
+		.map(|idx| block * BLOCK_BITS + idx)
+	}
+
+	/// Finds the index of the `rank`th bit cleared to `0`.
+	///
+	/// This is the inverse of [`.rank0()`].
+	///
+	/// ## Returns
+	///
+	/// The index of the requested bit, or `None` if there are not more than
+	/// `rank` bits cleared to `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select0(0), Some(0));
+	/// assert_eq!(index.select0(2), Some(3));
+	/// assert_eq!(index.select0(3), None);
+	/// ```
+	///
+	/// [`.rank0()`]: Self::rank0
+	#[inline]
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let block = self.search(|block| self.zeros_before(block) <= rank);
+		let rest = rank - self.zeros_before(block);
+		select_in(
+			self.block(block),
+			rest,
+			BitSlice::count_zeros,
+			BitSlice::iter_zeros,
+		)
+		.map(|idx| block * BLOCK_BITS + idx)
+	}
+}
+
+/// Crate internals.
+impl<T, O> RankSelect<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the `1` bits preceding a block.
+	fn ones_before(&self, block: usize) -> usize {
+		self.supers[block / BLOCKS_PER_SUPER] + self.blocks[block] as usize
+	}
+
+	/// Counts the `0` bits preceding a block.
+	fn zeros_before(&self, block: usize) -> usize {
+		block * BLOCK_BITS - self.ones_before(block)
+	}
+
+	/// Views the bits in one block.
+	fn block(&self, block: usize) -> &BitSlice<T, O> {
+		let bits = self.as_bitslice();
+		let start = block * BLOCK_BITS;
+		let end = (start + BLOCK_BITS).min(bits.len());
+		unsafe { bits.get_unchecked(start .. end) }
+	}
+
+	/// Finds the last block for which `pred` holds.
+	///
+	/// `pred` must be monotonic (a run of `true` followed by a run of
+	/// `false`), and must hold for the zeroth block.
+	fn search(&self, pred: impl Fn(usize) -> bool) -> usize {
+		let (mut lo, mut hi) = (0, self.blocks.len());
+		while hi - lo > 1 {
+			let mid = lo + (hi - lo) / 2;
+			if pred(mid) {
+				lo = mid;
+			}
+			else {
+				hi = mid;
+			}
+		}
+		lo
+	}
+}
+
+impl<T, O> Bits<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Views the bits, whether borrowed or owned.
+	fn as_bitslice(&self) -> &BitSlice<T, O> {
+		match self {
+			Self::Borrowed(bits) => bits,
+			Self::Owned(bits) => bits.as_bitslice(),
+		}
+	}
+}
+
+impl<T, O> Clone for RankSelect<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits:   match &self.bits {
+				Bits::Borrowed(bits) => Bits::Borrowed(bits),
+				Bits::Owned(bits) => Bits::Owned(bits.clone()),
+			},
+			supers: self.supers.clone(),
+			blocks: self.blocks.clone(),
+			ones:   self.ones,
+		}
+	}
+}
+
+impl<T, O> Debug for RankSelect<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RankSelect")
+			.field("bits", &self.as_bitslice())
+			.field("ones", &self.ones)
+			.finish()
+	}
+}
+
+impl<'a, T, O> From<&'a BitSlice<T, O>> for RankSelect<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &'a BitSlice<T, O>) -> Self {
+		Self::new(bits)
+	}
+}
+
+impl<T, O> From<BitBox<T, O>> for RankSelect<'static, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitBox<T, O>) -> Self {
+		Self::from_bitbox(bits)
+	}
+}
+
+/// Finds the `rank`th bit selected by `iter` within a single block.
+///
+/// The block is scanned one processor word at a time with `count`, and only
+/// the word containing the requested bit is walked with `iter`.
+fn select_in<'a, T, O, I>(
+	block: &'a BitSlice<T, O>,
+	mut rank: usize,
+	count: fn(&BitSlice<T, O>) -> usize,
+	iter: fn(&'a BitSlice<T, O>) -> I,
+) -> Option<usize>
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Iterator<Item = usize>,
+{
+	for (idx, word) in block.chunks(bits_of::<usize>()).enumerate() {
+		let live = count(word);
+		if rank < live {
+			return iter(word)
+				.nth(rank)
+				.map(|bit| idx * bits_of::<usize>() + bit);
+		}
+		rank -= live;
+	}
+	None
+}
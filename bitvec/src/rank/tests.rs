@@ -0,0 +1,69 @@
+//! Unit tests for rank/select indices.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::RankSelect;
+use crate::prelude::*;
+
+#[test]
+fn empty() {
+	let index = RankSelect::new(BitSlice::<u8, Lsb0>::empty());
+	assert!(index.is_empty());
+	assert_eq!(index.rank1(0), 0);
+	assert_eq!(index.rank0(0), 0);
+	assert!(index.select1(0).is_none());
+	assert!(index.select0(0).is_none());
+}
+
+#[test]
+fn against_scan() {
+	let data = (0 .. 2100).map(|_| random::<u64>()).collect::<Vec<_>>();
+	//  Start in the middle of an element, and end in the middle of a block.
+	let bits = &data.view_bits::<Msb0>()[13 .. 134_000];
+	let index = RankSelect::new(bits);
+	assert_eq!(index.count_ones(), bits.count_ones());
+	assert_eq!(index.count_zeros(), bits.count_zeros());
+
+	for idx in (0 ..= bits.len()).step_by(97).chain([bits.len()]) {
+		assert_eq!(index.rank1(idx), bits[.. idx].count_ones());
+		assert_eq!(index.rank0(idx), bits[.. idx].count_zeros());
+	}
+	for (rank, idx) in bits.iter_ones().enumerate().step_by(31) {
+		assert_eq!(index.select1(rank), Some(idx));
+		assert_eq!(index.rank1(idx), rank);
+	}
+	for (rank, idx) in bits.iter_zeros().enumerate().step_by(31) {
+		assert_eq!(index.select0(rank), Some(idx));
+		assert_eq!(index.rank0(idx), rank);
+	}
+	assert_eq!(index.select1(index.count_ones() - 1), bits.last_one());
+	assert_eq!(index.select0(index.count_zeros() - 1), bits.last_zero());
+	assert!(index.select1(index.count_ones()).is_none());
+	assert!(index.select0(index.count_zeros()).is_none());
+}
+
+#[test]
+fn sparse_and_dense() {
+	let mut bv = bitvec![u16, Lsb0; 0; 70_000];
+	bv.set(5, true);
+	bv.set(69_999, true);
+	let index = RankSelect::from_bitbox(bv.clone().into_boxed_bitslice());
+	assert_eq!(index.select1(0), Some(5));
+	assert_eq!(index.select1(1), Some(69_999));
+	assert_eq!(index.rank1(69_999), 1);
+	assert_eq!(index.select0(5), Some(6));
+
+	let index = RankSelect::from((!bv).into_boxed_bitslice());
+	assert_eq!(index.count_ones(), 69_998);
+	assert_eq!(index.select0(1), Some(69_999));
+	assert_eq!(index.select1(5), Some(6));
+	assert_eq!(index.select1(69_997), Some(69_998));
+}
+
+#[test]
+#[should_panic]
+fn rank_out_of_bounds() {
+	RankSelect::new(bits![0, 1]).rank1(3);
+}
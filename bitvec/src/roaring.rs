@@ -0,0 +1,343 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/roaring.md")]
+
+use alloc::vec::Vec;
+
+use self::container::Container;
+
+mod container;
+mod iter;
+mod serial;
+mod tests;
+mod traits;
+
+pub use self::{
+	iter::Iter,
+	serial::RoaringError,
+};
+
+#[doc = include_str!("../doc/roaring/RoaringBitmap.md")]
+pub struct RoaringBitmap {
+	/// The non-empty containers, in ascending order of their keys. Each
+	/// container holds the low halves of the values whose high half is its
+	/// key.
+	containers: Vec<(u16, Container)>,
+}
+
+/// Splits a value into its container key and its position in that container.
+#[inline]
+fn split(value: u32) -> (u16, u16) {
+	((value >> 16) as u16, value as u16)
+}
+
+/// Joins a container key and a position in that container into a value.
+#[inline]
+fn join(key: u16, low: u16) -> u32 {
+	(key as u32) << 16 | low as u32
+}
+
+/// Constructors and membership.
+impl RoaringBitmap {
+	/// Constructs an empty bitmap. This does not allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = RoaringBitmap::new();
+	/// assert!(rb.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			containers: Vec::new(),
+		}
+	}
+
+	/// Finds the container for a key, or the index at which it would be
+	/// inserted.
+	#[inline]
+	fn find(&self, key: u16) -> Result<usize, usize> {
+		self.containers.binary_search_by_key(&key, |&(k, _)| k)
+	}
+
+	/// Adds a value to the bitmap.
+	///
+	/// Returns `true` if the value was not already present.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let mut rb = RoaringBitmap::new();
+	/// assert!(rb.insert(1 << 20));
+	/// assert!(!rb.insert(1 << 20));
+	/// assert_eq!(rb.len(), 1);
+	/// ```
+	#[inline]
+	pub fn insert(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		let idx = match self.find(key) {
+			Ok(idx) => idx,
+			Err(idx) => {
+				self.containers
+					.insert(idx, (key, Container::Array(Vec::new())));
+				idx
+			},
+		};
+		self.containers[idx].1.insert(low)
+	}
+
+	/// Removes a value from the bitmap.
+	///
+	/// Returns `true` if the value was present.
+	#[inline]
+	pub fn remove(&mut self, value: u32) -> bool {
+		let (key, low) = split(value);
+		let idx = match self.find(key) {
+			Ok(idx) => idx,
+			Err(_) => return false,
+		};
+		let container = &mut self.containers[idx].1;
+		let removed = container.remove(low);
+		if container.len() == 0 {
+			self.containers.remove(idx);
+		}
+		removed
+	}
+
+	/// Tests if a value is in the bitmap.
+	#[inline]
+	pub fn contains(&self, value: u32) -> bool {
+		let (key, low) = split(value);
+		self.find(key)
+			.map_or(false, |idx| self.containers[idx].1.contains(low))
+	}
+
+	/// Counts the values in the bitmap.
+	///
+	/// This is a `u64`, as a bitmap may hold all 2<sup>32</sup> `u32` values.
+	#[inline]
+	pub fn len(&self) -> u64 {
+		self.containers.iter().map(|(_, c)| c.len() as u64).sum()
+	}
+
+	/// Tests if the bitmap has no values.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.containers.is_empty()
+	}
+
+	/// Removes every value from the bitmap.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.containers.clear();
+	}
+
+	/// Gets the smallest value in the bitmap.
+	#[inline]
+	pub fn min(&self) -> Option<u32> {
+		self.containers.first().map(|(key, c)| join(*key, c.min()))
+	}
+
+	/// Gets the largest value in the bitmap.
+	#[inline]
+	pub fn max(&self) -> Option<u32> {
+		self.containers.last().map(|(key, c)| join(*key, c.max()))
+	}
+
+	/// Iterates over the values in the bitmap, in ascending order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = [70_000, 3, 9].into_iter().collect::<RoaringBitmap>();
+	/// assert!(rb.iter().eq([3, 9, 70_000]));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> Iter {
+		Iter::new(&self.containers)
+	}
+}
+
+/// Order statistics.
+impl RoaringBitmap {
+	/// Counts the values in the bitmap that are less than or equal to `value`.
+	///
+	/// This sums the lengths of the containers below `value`, then counts
+	/// within the container that would hold `value`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = [2, 4, 100_000].into_iter().collect::<RoaringBitmap>();
+	/// assert_eq!(rb.rank(1), 0);
+	/// assert_eq!(rb.rank(4), 2);
+	/// assert_eq!(rb.rank(99_999), 2);
+	/// assert_eq!(rb.rank(u32::MAX), 3);
+	/// ```
+	#[inline]
+	pub fn rank(&self, value: u32) -> u64 {
+		let (key, low) = split(value);
+		let (idx, partial) = match self.find(key) {
+			Ok(idx) => (idx, self.containers[idx].1.rank(low) as u64),
+			Err(idx) => (idx, 0),
+		};
+		self.containers[.. idx]
+			.iter()
+			.map(|(_, c)| c.len() as u64)
+			.sum::<u64>()
+			+ partial
+	}
+
+	/// Finds the `n`th-smallest value in the bitmap, counting from zero.
+	///
+	/// Returns `None` if the bitmap has `n` or fewer values. This is the
+	/// inverse of [`.rank()`]: when `rb.select(n)` is `Some(value)`,
+	/// `rb.rank(value)` is `n + 1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = [2, 4, 100_000].into_iter().collect::<RoaringBitmap>();
+	/// assert_eq!(rb.select(0), Some(2));
+	/// assert_eq!(rb.select(2), Some(100_000));
+	/// assert_eq!(rb.select(3), None);
+	/// ```
+	///
+	/// [`.rank()`]: Self::rank
+	#[inline]
+	pub fn select(&self, mut n: u64) -> Option<u32> {
+		for (key, container) in &self.containers {
+			let len = container.len() as u64;
+			if n < len {
+				return container.select(n as usize).map(|low| join(*key, low));
+			}
+			n -= len;
+		}
+		None
+	}
+}
+
+/// Set algebra.
+///
+/// Each operation pairs up containers with equal keys. Pairs of array
+/// containers are merged as sorted lists; all other pairs are combined as
+/// [`BitArray`] membership tables, a processor word at a time.
+///
+/// [`BitArray`]: crate::array::BitArray
+impl RoaringBitmap {
+	/// Produces a bitmap of the values that are in `self`, `other`, or both.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let a = [1, 2, 70_000].into_iter().collect::<RoaringBitmap>();
+	/// let b = [2, 3].into_iter().collect::<RoaringBitmap>();
+	/// assert!(a.union(&b).iter().eq([1, 2, 3, 70_000]));
+	/// assert!(a.intersection(&b).iter().eq([2]));
+	/// assert!(a.difference(&b).iter().eq([1, 70_000]));
+	/// ```
+	#[inline]
+	pub fn union(&self, other: &Self) -> Self {
+		let mut containers = Vec::with_capacity(
+			self.containers.len().max(other.containers.len()),
+		);
+		let (mut a, mut b) = (self.containers.iter(), other.containers.iter());
+		let (mut x, mut y) = (a.next(), b.next());
+		loop {
+			match (x, y) {
+				(Some((k1, c1)), Some((k2, c2))) if k1 == k2 => {
+					containers.push((*k1, c1.union(c2)));
+					x = a.next();
+					y = b.next();
+				},
+				(Some(left), Some((k2, _))) if left.0 < *k2 => {
+					containers.push(left.clone());
+					x = a.next();
+				},
+				(_, Some(right)) => {
+					containers.push(right.clone());
+					y = b.next();
+				},
+				(Some(left), None) => {
+					containers.push(left.clone());
+					x = a.next();
+				},
+				(None, None) => break,
+			}
+		}
+		Self { containers }
+	}
+
+	/// Produces a bitmap of the values that are in both `self` and `other`.
+	#[inline]
+	pub fn intersection(&self, other: &Self) -> Self {
+		let containers = self
+			.containers
+			.iter()
+			.filter_map(|(key, c1)| {
+				let c2 = &other.containers[other.find(*key).ok()?].1;
+				c1.intersection(c2).map(|c| (*key, c))
+			})
+			.collect();
+		Self { containers }
+	}
+
+	/// Produces a bitmap of the values that are in `self` but not in `other`.
+	#[inline]
+	pub fn difference(&self, other: &Self) -> Self {
+		let containers = self
+			.containers
+			.iter()
+			.filter_map(|(key, c1)| match other.find(*key) {
+				Ok(idx) => {
+					c1.difference(&other.containers[idx].1).map(|c| (*key, c))
+				},
+				Err(_) => Some((*key, c1.clone())),
+			})
+			.collect();
+		Self { containers }
+	}
+}
+
+/// Compression.
+impl RoaringBitmap {
+	/// Converts each container to whichever of the array, run, or bitmap
+	/// representations is smallest.
+	///
+	/// Run containers are only produced by this method and by
+	/// [`.deserialize()`]. Modifying a run container converts it back to an
+	/// array or bitmap container. Returns `true` if any container is now a run
+	/// container.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let mut rb = (0 .. 50_000).collect::<RoaringBitmap>();
+	/// let dense = rb.serialized_size();
+	/// assert!(rb.run_optimize());
+	/// assert!(rb.serialized_size() < dense);
+	/// assert_eq!(rb.len(), 50_000);
+	/// ```
+	///
+	/// [`.deserialize()`]: Self::deserialize
+	#[inline]
+	pub fn run_optimize(&mut self) -> bool {
+		self.containers
+			.iter_mut()
+			.fold(false, |any, (_, c)| c.optimize() | any)
+	}
+}
@@ -0,0 +1,387 @@
+//! Roaring containers.
+//!
+//! Each container holds the low 16 bits of every value in a 64 Ki chunk of the
+//! `u32` domain, in whichever of three representations is smallest.
+
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+use core::cmp::Ordering;
+
+use crate::{
+	array::BitArray,
+	order::Lsb0,
+	slice::BitSlice,
+	view::BitView,
+};
+
+/// A dense membership table for one chunk. Index `n` is set when the low bits
+/// `n` are present.
+///
+/// Using `u64` elements in `Lsb0` order makes the memory layout of this array
+/// identical to a bitmap container in the Roaring portable format.
+pub(super) type Dense = BitArray<[u64; 1024], Lsb0>;
+
+/// Array containers hold at most this many values. Any more, and a bitmap
+/// container is smaller.
+pub(super) const ARRAY_MAX: usize = 4096;
+
+/// The size, in bytes, of a serialized bitmap container.
+pub(super) const DENSE_BYTES: usize = 8192;
+
+/// The values in one chunk of a Roaring bitmap.
+///
+/// Containers are never empty. Array containers never hold more than
+/// `ARRAY_MAX` values, and bitmap containers always hold more. Run containers
+/// may hold any number of values.
+#[derive(Clone, Debug)]
+pub(super) enum Container {
+	/// The values, in ascending order.
+	Array(Vec<u16>),
+	/// Inclusive ranges of values, in ascending order. Adjacent runs are
+	/// always separated by at least one absent value.
+	Run(Vec<(u16, u16)>),
+	/// A membership table, and the number of bits set in it.
+	Bitmap(Box<Dense>, usize),
+}
+
+impl Container {
+	/// Builds a container from a membership table, choosing the smaller of the
+	/// array and bitmap representations. Returns `None` if the table is empty.
+	pub(super) fn from_dense(bits: Box<Dense>) -> Option<Self> {
+		match bits.count_ones() {
+			0 => None,
+			len if len <= ARRAY_MAX => {
+				Some(Self::Array(bits.iter_ones().map(|n| n as u16).collect()))
+			},
+			len => Some(Self::Bitmap(bits, len)),
+		}
+	}
+
+	/// Builds a container from ascending values, choosing the smaller of the
+	/// array and bitmap representations. Returns `None` if there are no
+	/// values.
+	fn from_sorted(values: Vec<u16>) -> Option<Self> {
+		if values.is_empty() {
+			return None;
+		}
+		if values.len() <= ARRAY_MAX {
+			return Some(Self::Array(values));
+		}
+		let mut bits = Box::new(Dense::ZERO);
+		for &value in &values {
+			bits.set(value as usize, true);
+		}
+		Some(Self::Bitmap(bits, values.len()))
+	}
+
+	/// Counts the values in the container.
+	pub(super) fn len(&self) -> usize {
+		match self {
+			Self::Array(values) => values.len(),
+			Self::Run(runs) => runs
+				.iter()
+				.map(|&(start, end)| (end - start) as usize + 1)
+				.sum(),
+			Self::Bitmap(_, len) => *len,
+		}
+	}
+
+	/// Tests if a value is in the container.
+	pub(super) fn contains(&self, value: u16) -> bool {
+		match self {
+			Self::Array(values) => values.binary_search(&value).is_ok(),
+			Self::Run(runs) => {
+				let idx = runs.partition_point(|&(start, _)| start <= value);
+				idx != 0 && runs[idx - 1].1 >= value
+			},
+			Self::Bitmap(bits, _) => bits[value as usize],
+		}
+	}
+
+	/// Adds a value to the container. Returns `true` if it was not already
+	/// present.
+	pub(super) fn insert(&mut self, value: u16) -> bool {
+		if let Self::Run(_) = self {
+			if self.contains(value) {
+				return false;
+			}
+			self.unrun();
+		}
+		match self {
+			Self::Array(values) => match values.binary_search(&value) {
+				Ok(_) => false,
+				Err(idx) => {
+					values.insert(idx, value);
+					if values.len() > ARRAY_MAX {
+						*self =
+							Self::from_sorted(core::mem::take(values)).unwrap();
+					}
+					true
+				},
+			},
+			Self::Bitmap(bits, len) => {
+				let added = !bits.replace(value as usize, true);
+				*len += added as usize;
+				added
+			},
+			Self::Run(_) => unreachable!("run containers were expanded"),
+		}
+	}
+
+	/// Removes a value from the container. Returns `true` if it was present.
+	///
+	/// The container may be left empty.
+	pub(super) fn remove(&mut self, value: u16) -> bool {
+		if let Self::Run(_) = self {
+			if !self.contains(value) {
+				return false;
+			}
+			self.unrun();
+		}
+		match self {
+			Self::Array(values) => match values.binary_search(&value) {
+				Ok(idx) => {
+					values.remove(idx);
+					true
+				},
+				Err(_) => false,
+			},
+			Self::Bitmap(bits, len) => {
+				if !bits.replace(value as usize, false) {
+					return false;
+				}
+				*len -= 1;
+				if *len <= ARRAY_MAX {
+					*self = Self::Array(
+						bits.iter_ones().map(|n| n as u16).collect(),
+					);
+				}
+				true
+			},
+			Self::Run(_) => unreachable!("run containers were expanded"),
+		}
+	}
+
+	/// Gets the smallest value in the container.
+	pub(super) fn min(&self) -> u16 {
+		match self {
+			Self::Array(values) => values[0],
+			Self::Run(runs) => runs[0].0,
+			Self::Bitmap(bits, _) => bits.first_one().unwrap() as u16,
+		}
+	}
+
+	/// Gets the largest value in the container.
+	pub(super) fn max(&self) -> u16 {
+		match self {
+			Self::Array(values) => values[values.len() - 1],
+			Self::Run(runs) => runs[runs.len() - 1].1,
+			Self::Bitmap(bits, _) => bits.last_one().unwrap() as u16,
+		}
+	}
+
+	/// Counts the values in the container that are less than or equal to
+	/// `value`.
+	pub(super) fn rank(&self, value: u16) -> usize {
+		match self {
+			Self::Array(values) => values.partition_point(|&v| v <= value),
+			Self::Run(runs) => runs
+				.iter()
+				.take_while(|&&(start, _)| start <= value)
+				.map(|&(start, end)| (end.min(value) - start) as usize + 1)
+				.sum(),
+			Self::Bitmap(bits, _) => bits[..= value as usize].count_ones(),
+		}
+	}
+
+	/// Finds the `n`th-smallest value in the container, counting from zero.
+	pub(super) fn select(&self, mut n: usize) -> Option<u16> {
+		match self {
+			Self::Array(values) => values.get(n).copied(),
+			Self::Run(runs) => {
+				for &(start, end) in runs {
+					let len = (end - start) as usize + 1;
+					if n < len {
+						return Some(start + n as u16);
+					}
+					n -= len;
+				}
+				None
+			},
+			Self::Bitmap(bits, _) => {
+				//  Skip whole elements by their population count.
+				for (idx, elem) in bits.as_raw_slice().iter().enumerate() {
+					let ones = elem.count_ones() as usize;
+					if n < ones {
+						let word = elem.view_bits::<Lsb0>();
+						return word
+							.iter_ones()
+							.nth(n)
+							.map(|bit| (idx * 64 + bit) as u16);
+					}
+					n -= ones;
+				}
+				None
+			},
+		}
+	}
+
+	/// Copies the container into a membership table.
+	pub(super) fn to_dense(&self) -> Box<Dense> {
+		let mut bits = Box::new(Dense::ZERO);
+		match self {
+			Self::Array(values) => {
+				for &value in values {
+					bits.set(value as usize, true);
+				}
+			},
+			Self::Run(runs) => {
+				for &(start, end) in runs {
+					bits[start as usize ..= end as usize].fill(true);
+				}
+			},
+			Self::Bitmap(dense, _) => bits.clone_from(dense),
+		}
+		bits
+	}
+
+	/// Replaces a run container with the smaller of the array and bitmap
+	/// representations.
+	fn unrun(&mut self) {
+		*self = match &*self {
+			Self::Run(runs) if self.len() <= ARRAY_MAX => Self::Array(
+				runs.iter()
+					.flat_map(|&(start, end)| start ..= end)
+					.collect(),
+			),
+			Self::Run(_) => Self::from_dense(self.to_dense()).unwrap(),
+			_ => return,
+		};
+	}
+
+	/// Computes the runs of consecutive values in the container.
+	pub(super) fn runs(&self) -> Vec<(u16, u16)> {
+		match self {
+			Self::Run(runs) => runs.clone(),
+			Self::Array(values) => {
+				let mut runs = Vec::<(u16, u16)>::new();
+				for &value in values {
+					match runs.last_mut() {
+						Some((_, end)) if *end as u32 + 1 == value as u32 => {
+							*end = value;
+						},
+						_ => runs.push((value, value)),
+					}
+				}
+				runs
+			},
+			Self::Bitmap(bits, _) => {
+				let bits: &BitSlice<u64, Lsb0> = bits.as_bitslice();
+				let mut runs = Vec::new();
+				let mut pos = 0;
+				while let Some(start) = bits[pos ..].first_one().map(|n| n + pos)
+				{
+					let end = bits[start ..]
+						.first_zero()
+						.map_or(bits.len(), |n| n + start);
+					runs.push((start as u16, (end - 1) as u16));
+					pos = end;
+				}
+				runs
+			},
+		}
+	}
+
+	/// Converts the container to whichever representation is smallest when
+	/// serialized. Returns `true` if the result is a run container.
+	pub(super) fn optimize(&mut self) -> bool {
+		let runs = self.runs();
+		let len = self.len();
+		let current = if len <= ARRAY_MAX {
+			2 * len
+		}
+		else {
+			DENSE_BYTES
+		};
+		if 2 + 4 * runs.len() < current {
+			*self = Self::Run(runs);
+			return true;
+		}
+		self.unrun();
+		false
+	}
+
+	/// Computes the union of two containers.
+	pub(super) fn union(&self, other: &Self) -> Self {
+		if let (Self::Array(a), Self::Array(b)) = (self, other) {
+			let mut out = Vec::with_capacity(a.len() + b.len());
+			let (mut a, mut b) = (a.as_slice(), b.as_slice());
+			while let (Some(&x), Some(&y)) = (a.first(), b.first()) {
+				match x.cmp(&y) {
+					Ordering::Less => {
+						out.push(x);
+						a = &a[1 ..];
+					},
+					Ordering::Greater => {
+						out.push(y);
+						b = &b[1 ..];
+					},
+					Ordering::Equal => {
+						out.push(x);
+						a = &a[1 ..];
+						b = &b[1 ..];
+					},
+				}
+			}
+			out.extend_from_slice(a);
+			out.extend_from_slice(b);
+			return Self::from_sorted(out).unwrap();
+		}
+		let mut bits = self.to_dense();
+		*bits |= other.to_dense().as_bitslice();
+		Self::from_dense(bits).unwrap()
+	}
+
+	/// Computes the intersection of two containers. Returns `None` if it is
+	/// empty.
+	pub(super) fn intersection(&self, other: &Self) -> Option<Self> {
+		match (self, other) {
+			(Self::Array(values), _) => Self::from_sorted(
+				values
+					.iter()
+					.copied()
+					.filter(|&v| other.contains(v))
+					.collect(),
+			),
+			(_, Self::Array(_)) => other.intersection(self),
+			_ => {
+				let mut bits = self.to_dense();
+				*bits &= other.to_dense().as_bitslice();
+				Self::from_dense(bits)
+			},
+		}
+	}
+
+	/// Computes the values in `self` that are not in `other`. Returns `None`
+	/// if there are none.
+	pub(super) fn difference(&self, other: &Self) -> Option<Self> {
+		if let Self::Array(values) = self {
+			return Self::from_sorted(
+				values
+					.iter()
+					.copied()
+					.filter(|&v| !other.contains(v))
+					.collect(),
+			);
+		}
+		let mut bits = self.to_dense();
+		bits.bitop_assign(
+			other.to_dense().as_bitslice(),
+			|a, b| a & !b,
+			|a, b| a & !b,
+		);
+		Self::from_dense(bits)
+	}
+}
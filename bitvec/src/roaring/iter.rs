@@ -0,0 +1,96 @@
+#![doc = include_str!("../../doc/roaring/iter.md")]
+
+use core::{
+	iter::FusedIterator,
+	ops::RangeInclusive,
+	slice,
+};
+
+use super::{
+	container::Container,
+	join,
+};
+use crate::{
+	order::Lsb0,
+	slice::IterOnes,
+};
+
+#[doc = include_str!("../../doc/roaring/iter/Iter.md")]
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+	/// The containers that have not yet been entered.
+	containers: slice::Iter<'a, (u16, Container)>,
+	/// The key of the current container.
+	key:        u16,
+	/// The values of the current container that have not been yielded.
+	inner:      Inner<'a>,
+}
+
+impl<'a> Iter<'a> {
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(containers: &'a [(u16, Container)]) -> Self {
+		Self {
+			containers: containers.iter(),
+			key:        0,
+			inner:      Inner::Array([].iter()),
+		}
+	}
+}
+
+impl Iterator for Iter<'_> {
+	type Item = u32;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(low) = self.inner.next() {
+				return Some(join(self.key, low));
+			}
+			let (key, container) = self.containers.next()?;
+			self.key = *key;
+			self.inner = Inner::new(container);
+		}
+	}
+}
+
+impl FusedIterator for Iter<'_> {
+}
+
+/// Walks the values in a single container.
+#[derive(Clone, Debug)]
+enum Inner<'a> {
+	/// Walks an array container.
+	Array(slice::Iter<'a, u16>),
+	/// Walks a run container: the runs not yet entered, and the rest of the
+	/// current run.
+	Run(slice::Iter<'a, (u16, u16)>, RangeInclusive<u16>),
+	/// Walks a bitmap container.
+	Bitmap(IterOnes<'a, u64, Lsb0>),
+}
+
+impl<'a> Inner<'a> {
+	/// Begins walking a container.
+	fn new(container: &'a Container) -> Self {
+		match container {
+			Container::Array(values) => Self::Array(values.iter()),
+			#[allow(clippy::reversed_empty_ranges)]
+			Container::Run(runs) => Self::Run(runs.iter(), 1 ..= 0),
+			Container::Bitmap(bits, _) => Self::Bitmap(bits.iter_ones()),
+		}
+	}
+
+	/// Yields the next value in the container.
+	fn next(&mut self) -> Option<u16> {
+		match self {
+			Self::Array(values) => values.next().copied(),
+			Self::Run(runs, range) => loop {
+				if let Some(low) = range.next() {
+					return Some(low);
+				}
+				let &(start, end) = runs.next()?;
+				*range = start ..= end;
+			},
+			Self::Bitmap(bits) => bits.next().map(|n| n as u16),
+		}
+	}
+}
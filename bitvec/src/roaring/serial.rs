@@ -0,0 +1,336 @@
+//! Reading and writing the Roaring portable serialization format.
+//!
+//! The format is specified at
+//! <https://github.com/RoaringBitmap/RoaringFormatSpec>. All integers are
+//! little-endian.
+
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+use core::fmt::{
+	self,
+	Debug,
+	Display,
+	Formatter,
+};
+
+use super::{
+	container::{
+		Container,
+		Dense,
+		ARRAY_MAX,
+		DENSE_BYTES,
+	},
+	RoaringBitmap,
+};
+use crate::{
+	order::Lsb0,
+	vec::BitVec,
+	view::BitView,
+};
+
+/// Begins streams that have no run containers. It is followed by a `u32`
+/// container count.
+const COOKIE_NO_RUNS: u32 = 12346;
+
+/// Begins streams that have run containers. It occupies the low half of a
+/// `u32` whose high half is one less than the container count.
+const COOKIE_RUNS: u32 = 12347;
+
+/// Streams with run containers and fewer than this many containers omit the
+/// offset table.
+const NO_OFFSET_THRESHOLD: usize = 4;
+
+/// Serialization.
+impl RoaringBitmap {
+	/// Tests if the offset table is written, and if any container is a run
+	/// container.
+	fn layout(&self) -> (bool, bool) {
+		let has_runs = self
+			.containers
+			.iter()
+			.any(|(_, c)| matches!(c, Container::Run(_)));
+		let offsets = !has_runs || self.containers.len() >= NO_OFFSET_THRESHOLD;
+		(offsets, has_runs)
+	}
+
+	/// Computes the number of bytes that [`.serialize()`] produces.
+	///
+	/// [`.serialize()`]: Self::serialize
+	#[inline]
+	pub fn serialized_size(&self) -> usize {
+		let (offsets, has_runs) = self.layout();
+		let count = self.containers.len();
+		let header = if has_runs { 4 + (count + 7) / 8 } else { 8 };
+		let payload = self
+			.containers
+			.iter()
+			.map(|(_, c)| payload_size(c))
+			.sum::<usize>();
+		header + 4 * count + if offsets { 4 * count } else { 0 } + payload
+	}
+
+	/// Writes the bitmap in the Roaring portable serialization format, which
+	/// the Roaring libraries for other languages can read.
+	///
+	/// Array and bitmap containers are written as-is; run containers are only
+	/// written if [`.run_optimize()`] produced them.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = [1, 2, 3].into_iter().collect::<RoaringBitmap>();
+	/// let bytes = rb.serialize();
+	/// assert_eq!(bytes, [
+	///   0x3A, 0x30, 0, 0, // cookie
+	///   1, 0, 0, 0,       // one container
+	///   0, 0, 2, 0,       // key 0, three values
+	///   16, 0, 0, 0,      // payload offset
+	///   1, 0, 2, 0, 3, 0, // payload
+	/// ]);
+	/// assert_eq!(RoaringBitmap::deserialize(&bytes), Ok(rb));
+	/// ```
+	///
+	/// [`.run_optimize()`]: Self::run_optimize
+	#[inline]
+	pub fn serialize(&self) -> Vec<u8> {
+		let (offsets, has_runs) = self.layout();
+		let count = self.containers.len();
+		let mut out = Vec::with_capacity(self.serialized_size());
+
+		if has_runs {
+			let cookie = COOKIE_RUNS | ((count - 1) as u32) << 16;
+			out.extend_from_slice(&cookie.to_le_bytes());
+			let flags = self
+				.containers
+				.iter()
+				.map(|(_, c)| matches!(c, Container::Run(_)))
+				.collect::<BitVec<u8, Lsb0>>();
+			out.extend_from_slice(flags.as_raw_slice());
+		}
+		else {
+			out.extend_from_slice(&COOKIE_NO_RUNS.to_le_bytes());
+			out.extend_from_slice(&(count as u32).to_le_bytes());
+		}
+
+		for (key, container) in &self.containers {
+			out.extend_from_slice(&key.to_le_bytes());
+			out.extend_from_slice(&((container.len() - 1) as u16).to_le_bytes());
+		}
+
+		if offsets {
+			let mut offset = out.len() + 4 * count;
+			for (_, container) in &self.containers {
+				out.extend_from_slice(&(offset as u32).to_le_bytes());
+				offset += payload_size(container);
+			}
+		}
+
+		for (_, container) in &self.containers {
+			match container {
+				Container::Array(values) => {
+					for value in values {
+						out.extend_from_slice(&value.to_le_bytes());
+					}
+				},
+				Container::Run(runs) => {
+					out.extend_from_slice(&(runs.len() as u16).to_le_bytes());
+					for &(start, end) in runs {
+						out.extend_from_slice(&start.to_le_bytes());
+						out.extend_from_slice(&(end - start).to_le_bytes());
+					}
+				},
+				Container::Bitmap(bits, _) => {
+					for elem in bits.as_raw_slice() {
+						out.extend_from_slice(&elem.to_le_bytes());
+					}
+				},
+			}
+		}
+
+		out
+	}
+
+	/// Reads a bitmap written in the Roaring portable serialization format.
+	///
+	/// Bytes after the end of the bitmap are ignored; the bitmap occupies
+	/// exactly [`.serialized_size()`] bytes of `bytes`.
+	///
+	/// Run containers in the input are kept as run containers. Other
+	/// containers are read as array or bitmap containers according to their
+	/// length, as the format requires.
+	///
+	/// [`.serialized_size()`]: Self::serialized_size
+	#[inline]
+	pub fn deserialize(bytes: &[u8]) -> Result<Self, RoaringError> {
+		let mut reader = Reader { bytes };
+		let cookie = reader.u32()?;
+		let (count, runs) = if cookie == COOKIE_NO_RUNS {
+			(reader.u32()? as usize, None)
+		}
+		else if cookie & 0xFFFF == COOKIE_RUNS {
+			let count = (cookie >> 16) as usize + 1;
+			(count, Some(reader.take((count + 7) / 8)?))
+		}
+		else {
+			return Err(RoaringError::Cookie(cookie));
+		};
+		if count > 1 << 16 {
+			return Err(RoaringError::Malformed);
+		}
+
+		let mut headers = Vec::with_capacity(count);
+		for _ in 0 .. count {
+			let key = reader.u16()?;
+			let len = reader.u16()? as usize + 1;
+			if headers.last().map_or(false, |&(prev, _)| prev >= key) {
+				return Err(RoaringError::Malformed);
+			}
+			headers.push((key, len));
+		}
+		if runs.is_none() || count >= NO_OFFSET_THRESHOLD {
+			reader.take(4 * count)?;
+		}
+
+		let mut containers = Vec::with_capacity(count);
+		for (idx, (key, len)) in headers.into_iter().enumerate() {
+			let is_run =
+				runs.map_or(false, |flags| flags.view_bits::<Lsb0>()[idx]);
+			let container = if is_run {
+				let mut runs = Vec::<(u16, u16)>::new();
+				for _ in 0 .. reader.u16()? {
+					let start = reader.u16()?;
+					let end = start
+						.checked_add(reader.u16()?)
+						.ok_or(RoaringError::Malformed)?;
+					if runs.last().map_or(false, |&(_, prev)| {
+						prev as u32 + 1 >= start as u32
+					}) {
+						return Err(RoaringError::Malformed);
+					}
+					runs.push((start, end));
+				}
+				Container::Run(runs)
+			}
+			else if len <= ARRAY_MAX {
+				let mut values = Vec::with_capacity(len);
+				for _ in 0 .. len {
+					let value = reader.u16()?;
+					if values.last().map_or(false, |&prev| prev >= value) {
+						return Err(RoaringError::Malformed);
+					}
+					values.push(value);
+				}
+				Container::Array(values)
+			}
+			else {
+				let mut bits = Box::new(Dense::ZERO);
+				let words = reader.take(DENSE_BYTES)?.chunks_exact(8);
+				for (elem, word) in bits.as_raw_mut_slice().iter_mut().zip(words)
+				{
+					*elem = u64::from_le_bytes(word.try_into().unwrap());
+				}
+				//  Count the bitmap rather than trusting the header, so the
+				//  check below rejects a cardinality that does not match.
+				let ones = bits.count_ones();
+				Container::Bitmap(bits, ones)
+			};
+			if container.len() != len {
+				return Err(RoaringError::Malformed);
+			}
+			containers.push((key, container));
+		}
+
+		Ok(Self { containers })
+	}
+}
+
+/// Computes the number of bytes in a container’s serialized payload.
+fn payload_size(container: &Container) -> usize {
+	match container {
+		Container::Array(values) => 2 * values.len(),
+		Container::Run(runs) => 2 + 4 * runs.len(),
+		Container::Bitmap(..) => DENSE_BYTES,
+	}
+}
+
+/// Reads little-endian integers from the front of a byte slice.
+struct Reader<'a> {
+	/// The bytes that have not yet been read.
+	bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	/// Removes `len` bytes from the front of the input.
+	fn take(&mut self, len: usize) -> Result<&'a [u8], RoaringError> {
+		if self.bytes.len() < len {
+			return Err(RoaringError::Truncated);
+		}
+		let (head, rest) = self.bytes.split_at(len);
+		self.bytes = rest;
+		Ok(head)
+	}
+
+	/// Reads a `u16`.
+	fn u16(&mut self) -> Result<u16, RoaringError> {
+		self.take(2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+	}
+
+	/// Reads a `u32`.
+	fn u32(&mut self) -> Result<u32, RoaringError> {
+		self.take(4)
+			.map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+	}
+}
+
+#[doc = include_str!("../../doc/roaring/RoaringError.md")]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RoaringError {
+	/// The input does not begin with either of the format’s cookies.
+	Cookie(u32),
+	/// The input ended before the bitmap did.
+	Truncated,
+	/// The input describes an invalid bitmap: its containers or their values
+	/// are out of order, or a container’s length disagrees with its header.
+	Malformed,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for RoaringError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("RoaringError::")?;
+		match self {
+			Self::Cookie(cookie) => {
+				fmt.debug_tuple("Cookie").field(cookie).finish()
+			},
+			Self::Truncated => fmt.write_str("Truncated"),
+			Self::Malformed => fmt.write_str("Malformed"),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for RoaringError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Cookie(cookie) => write!(
+				fmt,
+				"{:#010x} is not a Roaring serialization cookie",
+				cookie,
+			),
+			Self::Truncated => {
+				fmt.write_str("the input ended before the Roaring bitmap did")
+			},
+			Self::Malformed => fmt
+				.write_str("the input does not describe a valid Roaring bitmap"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RoaringError {}
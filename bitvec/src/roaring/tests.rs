@@ -0,0 +1,155 @@
+//! Unit tests for Roaring bitmaps.
+
+#![cfg(test)]
+
+use std::collections::BTreeSet;
+
+use rand::random;
+
+use super::*;
+
+/// Builds a pair of equivalent random bitmaps, drawing values from a few
+/// chunks so that each chunk gets a different container type.
+fn random_bitmaps(count: usize) -> (RoaringBitmap, BTreeSet<u32>) {
+	let values = (0 .. count)
+		.map(|n| match n % 3 {
+			//  Sparse: array containers.
+			0 => random::<u32>(),
+			//  Dense: bitmap containers.
+			1 => random::<u16>() as u32 | 1 << 16,
+			//  Clustered: long runs after optimization.
+			_ => 5 << 16 | (random::<u32>() % 100 * 300 + n as u32 % 200),
+		})
+		.collect::<Vec<_>>();
+	(values.iter().collect(), values.iter().copied().collect())
+}
+
+#[test]
+fn membership() {
+	let mut rb = RoaringBitmap::new();
+	assert!(rb.is_empty());
+	assert_eq!((rb.min(), rb.max()), (None, None));
+	assert!(rb.insert(70_000));
+	assert!(rb.insert(3));
+	assert!(!rb.insert(3));
+	assert!(rb.contains(3) && rb.contains(70_000));
+	assert!(!rb.contains(4) && !rb.contains(u32::MAX));
+	assert_eq!(rb.len(), 2);
+	assert_eq!((rb.min(), rb.max()), (Some(3), Some(70_000)));
+	assert_eq!(format!("{:?}", rb), "{3, 70000}");
+
+	assert!(rb.remove(70_000));
+	assert!(!rb.remove(70_000));
+	assert_eq!(rb.containers.len(), 1);
+	rb.clear();
+	assert!(rb.is_empty());
+}
+
+#[test]
+fn containers() {
+	let mut rb = (0 .. 4096).map(|n| n * 2).collect::<RoaringBitmap>();
+	assert!(matches!(rb.containers[0].1, Container::Array(_)));
+	rb.insert(1);
+	assert!(matches!(rb.containers[0].1, Container::Bitmap(_, 4097)));
+	rb.remove(1);
+	assert!(matches!(rb.containers[0].1, Container::Array(_)));
+
+	let mut rb = (100 .. 60_000).collect::<RoaringBitmap>();
+	assert!(rb.run_optimize());
+	assert!(matches!(rb.containers[0].1, Container::Run(_)));
+	assert_eq!(rb.len(), 59_900);
+	assert_eq!(rb.rank(99), 0);
+	assert_eq!(rb.rank(1000), 901);
+	assert_eq!(rb.select(900), Some(1000));
+	assert!(rb.iter().eq(100 .. 60_000));
+
+	//  Modifying a run container expands it.
+	assert!(!rb.insert(500));
+	assert!(rb.remove(500));
+	assert!(matches!(rb.containers[0].1, Container::Bitmap(_, 59_899)));
+	assert!(rb.insert(500));
+	assert!(rb.run_optimize());
+	assert!(rb.iter().eq(100 .. 60_000));
+}
+
+#[test]
+fn algebra() {
+	let (mut a, a2) = random_bitmaps(30_000);
+	let (mut b, b2) = random_bitmaps(30_000);
+	a.run_optimize();
+
+	for _ in 0 .. 2 {
+		assert!(a.union(&b).iter().eq(a2.union(&b2).copied()));
+		assert!(a.intersection(&b).iter().eq(a2.intersection(&b2).copied()));
+		assert!(a.difference(&b).iter().eq(a2.difference(&b2).copied()));
+		assert!(b.difference(&a).iter().eq(b2.difference(&a2).copied()));
+		b.run_optimize();
+	}
+
+	assert_eq!(a.len(), a2.len() as u64);
+	for (n, value) in a2.iter().copied().enumerate() {
+		assert_eq!(a.select(n as u64), Some(value));
+		assert_eq!(a.rank(value), n as u64 + 1);
+	}
+	assert_eq!(a.select(a.len()), None);
+}
+
+#[test]
+fn serialization() {
+	let mut rb = (0 .. 10).collect::<RoaringBitmap>();
+	assert!(rb.run_optimize());
+	let bytes = rb.serialize();
+	assert_eq!(bytes, [
+		0x3B, 0x30, 0, 0, // cookie, one container
+		1, // run flags
+		0, 0, 9, 0, // key 0, ten values
+		1, 0, 0, 0, 9, 0, // one run, from 0 for 10 values
+	]);
+	assert_eq!(bytes.len(), rb.serialized_size());
+	assert_eq!(RoaringBitmap::deserialize(&bytes), Ok(rb));
+
+	let (mut rb, _) = random_bitmaps(30_000);
+	for _ in 0 .. 2 {
+		let bytes = rb.serialize();
+		assert_eq!(bytes.len(), rb.serialized_size());
+		let out = RoaringBitmap::deserialize(&bytes).unwrap();
+		assert_eq!(out, rb);
+		assert_eq!(out.serialize(), bytes);
+		rb.run_optimize();
+	}
+
+	let bytes = RoaringBitmap::new().serialize();
+	assert_eq!(bytes, [0x3A, 0x30, 0, 0, 0, 0, 0, 0]);
+	assert_eq!(RoaringBitmap::deserialize(&bytes), Ok(RoaringBitmap::new()));
+
+	let bytes = [1u32, 2].iter().collect::<RoaringBitmap>().serialize();
+	for len in 0 .. bytes.len() {
+		assert_eq!(
+			RoaringBitmap::deserialize(&bytes[.. len]),
+			Err(RoaringError::Truncated)
+		);
+	}
+	let mut bad = bytes.clone();
+	bad[18] = 1;
+	assert_eq!(
+		RoaringBitmap::deserialize(&bad),
+		Err(RoaringError::Malformed)
+	);
+
+	//  A bitmap container must hold as many values as its header claims.
+	let mut bad = vec![
+		0x3A, 0x30, 0, 0, 1, 0, 0, 0, // cookie, one container
+		0, 0, 0x87, 0x13, // key 0, 5000 values
+		16, 0, 0, 0, // offset
+	];
+	bad.resize(bad.len() + 8192, 0);
+	assert_eq!(
+		RoaringBitmap::deserialize(&bad),
+		Err(RoaringError::Malformed)
+	);
+	bad[16] = 1;
+	assert_eq!(
+		RoaringBitmap::deserialize(&bad),
+		Err(RoaringError::Malformed)
+	);
+}
@@ -0,0 +1,120 @@
+//! General trait implementations for Roaring bitmaps.
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+use super::{
+	Iter,
+	RoaringBitmap,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl Clone for RoaringBitmap {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			containers: self.containers.clone(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Default for RoaringBitmap {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Eq for RoaringBitmap {
+}
+
+/// Bitmaps are equal when they hold the same values, regardless of how their
+/// containers are represented.
+impl PartialEq for RoaringBitmap {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.containers.len() == other.containers.len()
+			&& self.len() == other.len()
+			&& self.iter().eq(other.iter())
+	}
+}
+
+impl Hash for RoaringBitmap {
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		//  Equal bitmaps may represent their containers differently, so only
+		//  the values participate.
+		self.len().hash(hasher);
+		for value in self {
+			value.hash(hasher);
+		}
+	}
+}
+
+impl Debug for RoaringBitmap {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl Extend<u32> for RoaringBitmap {
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = u32> {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a> Extend<&'a u32> for RoaringBitmap {
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a u32> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+impl FromIterator<u32> for RoaringBitmap {
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = u32> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a> FromIterator<&'a u32> for RoaringBitmap {
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a u32> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a> IntoIterator for &'a RoaringBitmap {
+	type IntoIter = Iter<'a>;
+	type Item = u32;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
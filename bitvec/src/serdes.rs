@@ -2,20 +2,38 @@
 #![doc = include_str!("../doc/serdes.md")]
 
 mod array;
+pub mod bytes;
+pub mod packed_bool_array;
+pub mod packed_bools;
 mod slice;
 mod utils;
 
-use core::fmt::{
-	self,
-	Formatter,
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+		Write,
+	},
+	str::FromStr,
 };
 
 use serde::de::{
 	Deserialize,
 	Deserializer,
+	Error,
+	MapAccess,
+	SeqAccess,
 	Visitor,
 };
 
+use crate::{
+	encoding::text::ParseBitsError,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
 /// A result of serialization.
 type Result<S> = core::result::Result<
 	<S as serde::Serializer>::Ok,
@@ -66,6 +84,93 @@ impl<'de> Visitor<'de> for FieldVisitor {
 	}
 }
 
+/// Renders a bit-slice as a string of `0` and `1` characters, which is its
+/// human-readable transport format.
+struct BitString<'a, T, O>(&'a BitSlice<T, O>)
+where
+	T: BitStore,
+	O: BitOrder;
+
+impl<T, O> Display for BitString<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for bit in self.0.iter().by_vals() {
+			fmt.write_char(if bit { '1' } else { '0' })?;
+		}
+		Ok(())
+	}
+}
+
+/// Deserializes a bit-sequence from either its text or its transport struct.
+///
+/// Human-readable formats may hold either, so the format is asked to describe
+/// its contents; other formats only hold the struct.
+fn deserialize_text<'de, D, V>(
+	deserializer: D,
+	name: &'static str,
+	visitor: V,
+) -> core::result::Result<V::Value, D::Error>
+where
+	D: Deserializer<'de>,
+	V: Visitor<'de>,
+	V::Value: FromStr<Err = ParseBitsError>,
+{
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_any(TextVisitor { inner: visitor })
+	}
+	else {
+		deserializer.deserialize_struct(name, FIELDS, visitor)
+	}
+}
+
+/// Visits either the text of a bit-sequence, which it parses, or its transport
+/// struct, which it passes to an inner visitor.
+struct TextVisitor<V> {
+	/// The visitor for the transport struct.
+	inner: V,
+}
+
+impl<'de, V> Visitor<'de> for TextVisitor<V>
+where
+	V: Visitor<'de>,
+	V::Value: FromStr<Err = ParseBitsError>,
+{
+	type Value = V::Value;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a bit string, or ")?;
+		self.inner.expecting(fmt)
+	}
+
+	fn visit_str<E>(self, value: &str) -> core::result::Result<Self::Value, E>
+	where E: Error {
+		value.parse().map_err(E::custom)
+	}
+
+	fn visit_seq<A>(
+		self,
+		seq: A,
+	) -> core::result::Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		self.inner.visit_seq(seq)
+	}
+
+	fn visit_map<A>(
+		self,
+		map: A,
+	) -> core::result::Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		self.inner.visit_map(map)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use serde::{
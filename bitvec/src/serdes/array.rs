@@ -30,6 +30,7 @@ use super::{
 		Array,
 		TypeName,
 	},
+	BitString,
 	Field,
 	FIELDS,
 };
@@ -50,6 +51,9 @@ where
 	#[inline]
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
 	where S: Serializer {
+		if serializer.is_human_readable() {
+			return serializer.collect_str(&BitString(self.as_bitslice()));
+		}
 		let mut state = serializer.serialize_struct("BitArr", FIELDS.len())?;
 
 		state.serialize_field("order", &any::type_name::<O>())?;
@@ -73,6 +77,9 @@ where
 	#[inline]
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
 	where S: Serializer {
+		if serializer.is_human_readable() {
+			return serializer.collect_str(&BitString(self.as_bitslice()));
+		}
 		let mut state = serializer.serialize_struct("BitArr", FIELDS.len())?;
 
 		state.serialize_field("order", &any::type_name::<O>())?;
@@ -93,9 +100,12 @@ where
 	#[inline]
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer
-			.deserialize_struct("BitArr", FIELDS, BitArrVisitor::<T, O, 1>::THIS)
-			.map(|BitArray { data: [elem], .. }| BitArray::new(elem))
+		super::deserialize_text(
+			deserializer,
+			"BitArr",
+			BitArrVisitor::<T, O, 1>::THIS,
+		)
+		.map(|BitArray { data: [elem], .. }| BitArray::new(elem))
 	}
 }
 
@@ -108,9 +118,9 @@ where
 	#[inline]
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer.deserialize_struct(
+		super::deserialize_text(
+			deserializer,
 			"BitArr",
-			FIELDS,
 			BitArrVisitor::<T, O, N>::THIS,
 		)
 	}
@@ -255,6 +265,8 @@ mod tests {
 		assert_de_tokens,
 		assert_de_tokens_error,
 		assert_ser_tokens,
+		Compact,
+		Configure,
 		Token,
 	};
 
@@ -326,7 +338,7 @@ mod tests {
 			Token::StructEnd,
 		];
 
-		assert_ser_tokens(&array, tokens);
+		assert_ser_tokens(&array.compact(), tokens);
 
 		tokens[1 .. 4].copy_from_slice(&[
 			Token::BorrowedStr("order"),
@@ -337,7 +349,17 @@ mod tests {
 		tokens[7] = Token::BorrowedStr("index");
 		tokens[10] = Token::BorrowedStr("bits");
 		tokens[12] = Token::BorrowedStr("data");
-		assert_de_tokens(&array, tokens);
+		assert_de_tokens(&array.compact(), tokens);
+
+		assert_ser_tokens(&array.readable(), &[Token::Str("0011110010100101")]);
+		assert_de_tokens(&array.readable(), &[Token::Str("0x3CA5")]);
+		assert_de_tokens(&BitArray::<u8, Lsb0>::new(0x3C).readable(), &[
+			Token::Str("0011_1100"),
+		]);
+		assert_de_tokens_error::<serde_test::Readable<BitArray<u8, Lsb0>>>(
+			&[Token::Str("0x3CA")],
+			"expected 8 bits, found 12",
+		);
 	}
 
 	#[test]
@@ -349,7 +371,7 @@ mod tests {
 			Token::BorrowedStr(any::type_name::<Msb0>()),
 		];
 
-		assert_de_tokens_error::<BitArr!(for 8, in u8, Lsb0)>(
+		assert_de_tokens_error::<Compact<BitArr!(for 8, in u8, Lsb0)>>(
 			&tokens,
 			&format!(
 				"invalid value: string \"{}\", expected the string \"{}\"",
@@ -371,20 +393,20 @@ mod tests {
 		]);
 
 		tokens[6] = Token::U64(7);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&tokens,
 			"invalid length 7, expected a `BitArray<[u8; 1], \
 			 bitvec::order::Msb0>`",
 		);
 
 		tokens[4] = Token::U8(1);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&tokens,
 			"invalid value: integer `1`, expected `BitArray` must have a \
 			 head-bit of `0`",
 		);
 
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&[
 				Token::Struct {
 					name: "BitArr",
@@ -397,7 +419,7 @@ mod tests {
 				super::FIELDS.join("`, `"),
 			),
 		);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&[
 				Token::Struct {
 					name: "BitArr",
@@ -411,7 +433,7 @@ mod tests {
 			],
 			"duplicate field `order`",
 		);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&[
 				Token::Struct {
 					name: "BitArr",
@@ -431,7 +453,7 @@ mod tests {
 			],
 			"duplicate field `head`",
 		);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&[
 				Token::Struct {
 					name: "BitArr",
@@ -445,7 +467,7 @@ mod tests {
 			],
 			"duplicate field `bits`",
 		);
-		assert_de_tokens_error::<BA>(
+		assert_de_tokens_error::<Compact<BA>>(
 			&[
 				Token::Struct {
 					name: "BitArr",
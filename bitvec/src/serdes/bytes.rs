@@ -0,0 +1,255 @@
+//! Transports a `BitVec` field as a byte string.
+//!
+//! This is used with `#[serde(with = "bitvec::serdes::bytes")]` on a
+//! `BitVec<T, O>` field. Rather than the raw memory elements and metadata of
+//! the default transport format, the field is sent as a pair of its length in
+//! bits, as a `u64`, and its contents packed into bytes. The first bit is the
+//! most significant bit of the first byte, and the last byte is filled out with
+//! `0` bits, so the bytes are those of the equivalent `BitVec<u8, Msb0>`.
+//!
+//! The bytes are serialized with [`Serializer::serialize_bytes`], which formats
+//! such as `bincode` and CBOR store as a single length-prefixed block. The
+//! transport does not depend on the storage type or ordering of the
+//! bit-vector, so it may be deserialized into a different one.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use bitvec::prelude::*;
+//! use bitvec::serdes::bytes;
+//!
+//! let bv = bitvec![u32, Lsb0; 1, 0, 1, 1, 0, 0, 0, 0, 1];
+//! let mut json = Vec::new();
+//! bytes::serialize(&bv, &mut serde_json::Serializer::new(&mut json))
+//!   .unwrap();
+//! assert_eq!(json, b"[9,[176,128]]");
+//!
+//! let mut de = serde_json::Deserializer::from_slice(&json);
+//! let bv2: BitVec<u8, Msb0> = bytes::deserialize(&mut de).unwrap();
+//! assert_eq!(bv, bv2);
+//! ```
+//!
+//! [`Serializer::serialize_bytes`]: serde::Serializer::serialize_bytes
+
+#![cfg(feature = "alloc")]
+
+use core::{
+	fmt::{
+		self,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{
+		Error,
+		SeqAccess,
+		Visitor,
+	},
+	ser::{
+		Serialize,
+		SerializeTuple,
+	},
+	Deserializer,
+	Serializer,
+};
+
+use super::utils::ByteBuf;
+use crate::{
+	order::{
+		BitOrder,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// Serializes a bit-slice as its length and its contents packed into bytes.
+#[inline]
+pub fn serialize<T, O, S>(
+	bits: &BitSlice<T, O>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	T: BitStore,
+	O: BitOrder,
+	S: Serializer,
+{
+	let mut packed = BitVec::<u8, Msb0>::with_capacity(bits.len());
+	packed.extend_from_bitslice(bits);
+	packed.set_uninitialized(false);
+
+	let mut state = serializer.serialize_tuple(2)?;
+	state.serialize_element(&(bits.len() as u64))?;
+	state.serialize_element(&Bytes(packed.as_raw_slice()))?;
+	state.end()
+}
+
+/// Deserializes a bit-vector from its length and its packed bytes.
+///
+/// The bytes may be transported as a byte string or as a sequence of `u8`. This
+/// fails if there are not exactly enough bytes to hold the length.
+#[inline]
+pub fn deserialize<'de, T, O, D>(
+	deserializer: D,
+) -> Result<BitVec<T, O>, D::Error>
+where
+	T: BitStore,
+	O: BitOrder,
+	D: Deserializer<'de>,
+{
+	deserializer.deserialize_tuple(2, BytesVisitor { _out: PhantomData })
+}
+
+/// Serializes a byte slice as a byte string, rather than as a sequence.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+	fn serialize<S>(&self, serializer: S) -> super::Result<S>
+	where S: Serializer {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+/// Assists in deserialization of a bit-vector from its packed bytes.
+struct BytesVisitor<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// This produces a bit-vector.
+	_out: PhantomData<BitVec<T, O>>,
+}
+
+impl<'de, T, O> Visitor<'de> for BytesVisitor<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Value = BitVec<T, O>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a bit count and a byte buffer")
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let bits = seq
+			.next_element::<u64>()?
+			.ok_or_else(|| V::Error::invalid_length(0, &self))?;
+		let ByteBuf(bytes) = seq
+			.next_element()?
+			.ok_or_else(|| V::Error::invalid_length(1, &self))?;
+
+		let bits = usize::try_from(bits)
+			.ok()
+			.filter(|&bits| {
+				bits.checked_add(7).map(|n| n / 8) == Some(bytes.len())
+			})
+			.ok_or_else(|| {
+				V::Error::invalid_length(
+					bytes.len(),
+					&"enough bytes for the bit count",
+				)
+			})?;
+		let packed = BitVec::<u8, Msb0>::from_vec(bytes);
+		let mut out = BitVec::with_capacity(bits);
+		out.extend_from_bitslice(&packed[.. bits]);
+		Ok(out)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_test::{
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_ser_tokens,
+		Token,
+	};
+
+	use crate::prelude::*;
+
+	/// Carries a `BitVec` through the adapter, as a derived implementation
+	/// would.
+	#[derive(Debug, PartialEq)]
+	struct Packed(BitVec<u16, Lsb0>);
+
+	impl serde::Serialize for Packed {
+		fn serialize<S>(&self, serializer: S) -> super::super::Result<S>
+		where S: serde::Serializer {
+			super::serialize(&self.0, serializer)
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for Packed {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: serde::Deserializer<'de> {
+			super::deserialize(deserializer).map(Self)
+		}
+	}
+
+	#[test]
+	fn bytes() {
+		let packed = Packed(bitvec![u16, Lsb0; 0, 1, 1, 0, 1, 0, 0, 1, 1, 1]);
+		let tokens = &[
+			Token::Tuple { len: 2 },
+			Token::U64(10),
+			Token::Bytes(&[0x69, 0xC0]),
+			Token::TupleEnd,
+		];
+		assert_ser_tokens(&packed, tokens);
+		assert_de_tokens(&packed, tokens);
+		assert_de_tokens(&packed, &[
+			Token::Seq { len: Some(2) },
+			Token::U64(10),
+			Token::Seq { len: Some(2) },
+			Token::U8(0x69),
+			Token::U8(0xC0),
+			Token::SeqEnd,
+			Token::SeqEnd,
+		]);
+		assert_de_tokens(&packed, &[
+			Token::Seq { len: Some(2) },
+			Token::U64(10),
+			Token::Seq {
+				len: Some(usize::MAX),
+			},
+			Token::U8(0x69),
+			Token::U8(0xC0),
+			Token::SeqEnd,
+			Token::SeqEnd,
+		]);
+
+		let bytes = bincode::serialize(&packed).unwrap();
+		assert_eq!(bytes, [
+			10, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0x69, 0xC0
+		]);
+		assert_eq!(bincode::deserialize::<Packed>(&bytes).unwrap(), packed);
+
+		assert_de_tokens_error::<Packed>(
+			&[
+				Token::Tuple { len: 2 },
+				Token::U64(7),
+				Token::Bytes(&[0x69, 0xC0]),
+				Token::TupleEnd,
+			],
+			"invalid length 2, expected enough bytes for the bit count",
+		);
+		assert_de_tokens_error::<Packed>(
+			&[
+				Token::Tuple { len: 2 },
+				Token::U64(u64::MAX),
+				Token::Bytes(&[]),
+				Token::TupleEnd,
+			],
+			"invalid length 0, expected enough bytes for the bit count",
+		);
+
+		let mut bytes = [0; 16];
+		bytes[.. 8].copy_from_slice(&u64::MAX.to_le_bytes());
+		assert!(bincode::deserialize::<Packed>(&bytes).is_err());
+	}
+}
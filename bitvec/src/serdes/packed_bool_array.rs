@@ -0,0 +1,123 @@
+//! Packs a `[bool; N]` field into bits.
+//!
+//! This is used with `#[serde(with = "bitvec::serdes::packed_bool_array")]` on
+//! a `[bool; N]` field, for any `N`. It transports the field in the same form
+//! as [`packed_bools`], and fails to deserialize if the transported bit-vector
+//! does not have exactly `N` bits.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use bitvec::serdes::packed_bool_array;
+//!
+//! let flags = [false, true, true];
+//! let mut json = Vec::new();
+//! let mut ser = serde_json::Serializer::new(&mut json);
+//! packed_bool_array::serialize(&flags, &mut ser).unwrap();
+//! assert_eq!(json, br#""011""#);
+//!
+//! let mut de = serde_json::Deserializer::from_slice(&json);
+//! assert_eq!(packed_bool_array::deserialize(&mut de).unwrap(), flags);
+//!
+//! let mut de = serde_json::Deserializer::from_slice(br#""0110""#);
+//! assert!(packed_bool_array::deserialize::<_, 3>(&mut de).is_err());
+//! ```
+//!
+//! [`packed_bools`]: super::packed_bools
+
+#![cfg(feature = "alloc")]
+
+use core::fmt::{
+	self,
+	Formatter,
+};
+
+use serde::{
+	de::{
+		Error,
+		Expected,
+	},
+	Deserializer,
+	Serializer,
+};
+
+use super::packed_bools;
+
+/// Serializes an array of bools as a bit-vector.
+#[inline]
+pub fn serialize<S, const N: usize>(
+	bools: &[bool; N],
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	packed_bools::serialize(bools, serializer)
+}
+
+/// Deserializes a bit-vector of exactly `N` bits into an array of bools.
+#[inline]
+pub fn deserialize<'de, D, const N: usize>(
+	deserializer: D,
+) -> Result<[bool; N], D::Error>
+where D: Deserializer<'de> {
+	let bools = packed_bools::deserialize(deserializer)?;
+	let len = bools.len();
+	bools
+		.try_into()
+		.map_err(|_| D::Error::invalid_length(len, &Width(N)))
+}
+
+/// Describes the bit-vector that an `N`-bool array deserializes from.
+struct Width(usize);
+
+impl Expected for Width {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a bit-vector of {} bits", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_test::{
+		assert_de_tokens_error,
+		assert_tokens,
+		Configure,
+		Token,
+	};
+
+	/// Carries a `[bool; N]` through the adapter, as a derived implementation
+	/// would.
+	#[derive(Debug, PartialEq)]
+	struct Packed<const N: usize>([bool; N]);
+
+	impl<const N: usize> serde::Serialize for Packed<N> {
+		fn serialize<S>(&self, serializer: S) -> super::super::Result<S>
+		where S: serde::Serializer {
+			super::serialize(&self.0, serializer)
+		}
+	}
+
+	impl<'de, const N: usize> serde::Deserialize<'de> for Packed<N> {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: serde::Deserializer<'de> {
+			super::deserialize(deserializer).map(Self)
+		}
+	}
+
+	#[test]
+	fn packed_bool_array() {
+		let packed = Packed([true, true, false, true]);
+		assert_tokens(&packed.readable(), &[Token::Str("1101")]);
+
+		let flags = Packed([true; 40]);
+		let bytes = bincode::serialize(&flags).unwrap();
+		assert_eq!(bincode::deserialize::<Packed<40>>(&bytes).unwrap(), flags);
+		assert!(bincode::deserialize::<Packed<39>>(&bytes).is_err());
+
+		assert_de_tokens_error::<serde_test::Readable<Packed<3>>>(
+			&[Token::Str("01")],
+			"invalid length 2, expected a bit-vector of 3 bits",
+		);
+	}
+}
@@ -0,0 +1,98 @@
+//! Packs a `Vec<bool>` field into bits.
+//!
+//! This is used with `#[serde(with = "bitvec::serdes::packed_bools")]` on a
+//! `Vec<bool>` field. The field is transported as a `BitVec<u8, Msb0>` with the
+//! same contents, rather than as a sequence of single-byte bools. In compact
+//! formats, long fields take about an eighth of the space that they otherwise
+//! would.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use bitvec::serdes::packed_bools;
+//!
+//! let bools = vec![true, false, true, true];
+//! let mut json = Vec::new();
+//! packed_bools::serialize(&bools, &mut serde_json::Serializer::new(&mut json))
+//!   .unwrap();
+//! assert_eq!(json, br#""1011""#);
+//!
+//! let mut de = serde_json::Deserializer::from_slice(&json);
+//! assert_eq!(packed_bools::deserialize(&mut de).unwrap(), bools);
+//! ```
+
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+
+use serde::{
+	Deserialize,
+	Deserializer,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	order::Msb0,
+	vec::BitVec,
+};
+
+/// Serializes a sequence of bools as a bit-vector.
+#[inline]
+pub fn serialize<S>(bools: &[bool], serializer: S) -> Result<S::Ok, S::Error>
+where S: Serializer {
+	bools
+		.iter()
+		.copied()
+		.collect::<BitVec<u8, Msb0>>()
+		.serialize(serializer)
+}
+
+/// Deserializes a bit-vector into a sequence of bools.
+///
+/// This accepts any representation that `BitVec<u8, Msb0>` does.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<bool>, D::Error>
+where D: Deserializer<'de> {
+	BitVec::<u8, Msb0>::deserialize(deserializer)
+		.map(|bits| bits.iter().by_vals().collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_test::{
+		assert_tokens,
+		Configure,
+		Token,
+	};
+
+	/// Carries a `Vec<bool>` through the adapter, as a derived implementation
+	/// would.
+	#[derive(Debug, PartialEq)]
+	struct Packed(Vec<bool>);
+
+	impl serde::Serialize for Packed {
+		fn serialize<S>(&self, serializer: S) -> super::super::Result<S>
+		where S: serde::Serializer {
+			super::serialize(&self.0, serializer)
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for Packed {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: serde::Deserializer<'de> {
+			super::deserialize(deserializer).map(Self)
+		}
+	}
+
+	#[test]
+	fn packed_bools() {
+		let packed = Packed(vec![true, false, false, true, true]);
+		assert_tokens(&packed.readable(), &[Token::Str("10011")]);
+
+		let bools = Packed((0 .. 1000).map(|n| n % 3 == 0).collect());
+		let bytes = bincode::serialize(&bools).unwrap();
+		assert!(bytes.len() < 200);
+		assert_eq!(bincode::deserialize::<Packed>(&bytes).unwrap(), bools);
+	}
+}
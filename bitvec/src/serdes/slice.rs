@@ -30,6 +30,7 @@ use wyz::comu::Const;
 
 use super::{
 	utils::TypeName,
+	BitString,
 	Field,
 	FIELDS,
 };
@@ -60,6 +61,9 @@ where
 	#[inline]
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
 	where S: Serializer {
+		if serializer.is_human_readable() {
+			return serializer.collect_str(&BitString(self));
+		}
 		let head = self.as_bitspan().head();
 		let mut state = serializer.serialize_struct("BitSeq", FIELDS.len())?;
 
@@ -144,9 +148,9 @@ where
 	#[inline]
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer.deserialize_struct(
+		super::deserialize_text(
+			deserializer,
 			"BitSeq",
-			FIELDS,
 			BitSeqVisitor::<T, O, Vec<T>, Self, _>::new(
 				|vec, head, bits| unsafe {
 					let addr = vec.as_ptr().into_address();
@@ -303,6 +307,7 @@ mod tests {
 		assert_de_tokens,
 		assert_de_tokens_error,
 		assert_ser_tokens,
+		Configure,
 		Token,
 	};
 
@@ -346,10 +351,10 @@ mod tests {
 			Token::SeqEnd,
 			Token::StructEnd,
 		];
-		assert_ser_tokens(&slice, tokens);
+		assert_ser_tokens(&slice.compact(), tokens);
 		tokens[8] = Token::U8(1);
 		tokens[11] = Token::U64(4);
-		assert_ser_tokens(&&slice[1 ..], tokens);
+		assert_ser_tokens(&(&slice[1 ..]).compact(), tokens);
 
 		let tokens = &[
 			Token::Seq { len: Some(4) },
@@ -365,6 +370,51 @@ mod tests {
 		assert_de_tokens(&slice, tokens);
 	}
 
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn readable() {
+		let bv = bitvec![u16, Msb0; 0, 1, 0, 0, 1];
+		assert_ser_tokens(&(&bv).readable(), &[Token::Str("01001")]);
+		assert_ser_tokens(&(&bv[1 ..]).readable(), &[Token::Str("1001")]);
+		assert_de_tokens(&bv.clone().readable(), &[Token::Str("01001")]);
+		assert_de_tokens(&bv.clone().readable(), &[Token::Str("0b0100_1")]);
+		assert_de_tokens(&bv.clone().into_boxed_bitslice().readable(), &[
+			Token::Str("[0, 1, 0, 0, 1]"),
+		]);
+		assert_de_tokens(&bv.clone().readable(), &[
+			Token::Map { len: Some(4) },
+			Token::Str("order"),
+			Token::Str(any::type_name::<Msb0>()),
+			Token::Str("head"),
+			Token::Map { len: Some(2) },
+			Token::Str("width"),
+			Token::U8(16),
+			Token::Str("index"),
+			Token::U8(0),
+			Token::MapEnd,
+			Token::Str("bits"),
+			Token::U64(5),
+			Token::Str("data"),
+			Token::Seq { len: Some(1) },
+			Token::U16(0x4800),
+			Token::SeqEnd,
+			Token::MapEnd,
+		]);
+		assert_de_tokens_error::<serde_test::Readable<BitVec>>(
+			&[Token::Str("01201")],
+			"unexpected '2' at position 2",
+		);
+		assert_de_tokens_error::<serde_test::Readable<BitVec>>(
+			&[Token::U8(1)],
+			&format!(
+				"invalid type: integer `1`, expected a bit string, or a \
+				 `BitSlice<u{}, {}>`",
+				usize::BITS,
+				any::type_name::<Lsb0>(),
+			),
+		);
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn errors() {
@@ -1,5 +1,7 @@
 #![doc=include_str!("../../doc/serdes/utils.md")]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::{
 	any,
 	fmt::{
@@ -269,6 +271,56 @@ where
 	}
 }
 
+/// An owned byte buffer, which deserializes from any of the byte
+/// representations that `serde` offers, or from a sequence of `u8`.
+#[cfg(feature = "alloc")]
+pub(super) struct ByteBuf(pub(super) Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for ByteBuf {
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_byte_buf(ByteBufVisitor)
+	}
+}
+
+/// Assists in deserialization of a `ByteBuf`.
+#[cfg(feature = "alloc")]
+struct ByteBufVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for ByteBufVisitor {
+	type Value = ByteBuf;
+
+	#[inline]
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a byte buffer")
+	}
+
+	#[inline]
+	fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(ByteBuf(value.to_vec()))
+	}
+
+	#[inline]
+	fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(ByteBuf(value))
+	}
+
+	#[inline]
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+		while let Some(byte) = seq.next_element::<u8>()? {
+			out.push(byte);
+		}
+		Ok(ByteBuf(out))
+	}
+}
+
 /// Assists in deserialization of a `BitIdx` value.
 struct BitIdxVisitor<R>
 where R: BitRegister
@@ -0,0 +1,387 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../doc/set.md")]
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod iter;
+mod tests;
+mod traits;
+
+pub use self::iter::{
+	Difference,
+	Intersection,
+	SymmetricDifference,
+	Union,
+};
+
+#[doc = include_str!("../doc/set/BitSet.md")]
+pub struct BitSet<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The membership table: index `n` is set when `n` is in the set. Bits
+	/// beyond the end of the table are not in the set.
+	bits: BitVec<T, O>,
+}
+
+/// Constructors and conversions.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs an empty set. This does not allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::<u8, Lsb0>::new();
+	/// assert!(set.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+
+	/// Constructs an empty set that can hold every value below `capacity`
+	/// without reallocating.
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(capacity),
+		}
+	}
+
+	/// Constructs a set from a membership table: each index of `bits` that is
+	/// set to `1` becomes a member of the set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::from_bitvec(bitvec![0, 1, 0, 0, 1]);
+	/// assert!(set.iter().eq([1, 4]));
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<T, O>) -> Self {
+		Self { bits }
+	}
+
+	/// Unwraps the set into its membership table.
+	///
+	/// The table may have any number of `0` bits after its last `1` bit.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+
+	/// Views the set’s membership table.
+	///
+	/// The table may have any number of `0` bits after its last `1` bit.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+}
+
+/// Membership.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds a value to the set, growing the membership table if needed.
+	///
+	/// Returns `true` if the value was not already present.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` is `usize::MAX`, or if the membership table
+	/// cannot grow to hold it.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::insert`](alloc::collections::BTreeSet::insert)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u8, Lsb0>::new();
+	/// assert!(set.insert(10));
+	/// assert!(!set.insert(10));
+	/// assert_eq!(set.len(), 1);
+	/// ```
+	#[inline]
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			let len = value
+				.checked_add(1)
+				.expect("cannot insert `usize::MAX` into a bit-set");
+			self.bits.resize(len, false);
+		}
+		!self.bits.replace(value, true)
+	}
+
+	/// Removes a value from the set.
+	///
+	/// Returns `true` if the value was present. This never shrinks the
+	/// membership table; use [`.shrink_to_fit()`] to do so.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::remove`](alloc::collections::BTreeSet::remove)
+	///
+	/// [`.shrink_to_fit()`]: Self::shrink_to_fit
+	#[inline]
+	pub fn remove(&mut self, value: usize) -> bool {
+		value < self.bits.len() && self.bits.replace(value, false)
+	}
+
+	/// Tests if a value is in the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::contains`](alloc::collections::BTreeSet::contains)
+	#[inline]
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).map_or(false, |bit| *bit)
+	}
+
+	/// Counts the values in the set.
+	///
+	/// This counts the `1` bits in the membership table, a processor word at a
+	/// time.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no values.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Removes every value from the set, keeping its allocation.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Gets the smallest value in the set.
+	#[inline]
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Gets the largest value in the set.
+	#[inline]
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Shrinks the membership table to end at the largest value in the set,
+	/// and releases any excess allocation.
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		self.bits.truncate(self.last().map_or(0, |last| last + 1));
+		self.bits.shrink_to_fit();
+	}
+
+	/// Iterates over the values in the set, in ascending order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = [9, 2, 5].into_iter().collect::<BitSet>();
+	/// assert!(set.iter().eq([2, 5, 9]));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> IterOnes<T, O> {
+		self.bits.iter_ones()
+	}
+}
+
+/// Set algebra.
+///
+/// The in-place operations modify `self` one processor word at a time. The
+/// lazy operations produce iterators that combine a processor word of each set
+/// at a time, and yield values in ascending order.
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds every value in `other` to `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a = [1, 2].into_iter().collect::<BitSet>();
+	/// let b = [2, 70].into_iter().collect::<BitSet>();
+	/// a.union_with(&b);
+	/// assert!(a.iter().eq([1, 2, 70]));
+	/// ```
+	#[inline]
+	pub fn union_with(&mut self, other: &Self) {
+		let len = other.last().map_or(0, |last| last + 1);
+		if len > self.bits.len() {
+			self.bits.resize(len, false);
+		}
+		*self.bits.as_mut_bitslice() |= &other.bits[.. len];
+	}
+
+	/// Removes every value from `self` that is not also in `other`.
+	#[inline]
+	pub fn intersect_with(&mut self, other: &Self) {
+		*self.bits.as_mut_bitslice() &= other.bits.as_bitslice();
+	}
+
+	/// Removes every value in `other` from `self`.
+	#[inline]
+	pub fn difference_with(&mut self, other: &Self) {
+		self.bits.bitop_assign(
+			other.bits.as_bitslice(),
+			|a, b| a & !b,
+			|a, b| a & !b,
+		);
+	}
+
+	/// Keeps the values that are in exactly one of `self` and `other`.
+	#[inline]
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		let len = other.last().map_or(0, |last| last + 1);
+		if len > self.bits.len() {
+			self.bits.resize(len, false);
+		}
+		*self.bits.as_mut_bitslice() ^= &other.bits[.. len];
+	}
+
+	/// Iterates over the values that are in `self`, `other`, or both.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::union`](alloc::collections::BTreeSet::union)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let a = [1, 2].into_iter().collect::<BitSet>();
+	/// let b = [2, 70].into_iter().collect::<BitSet>();
+	/// assert!(a.union(&b).eq([1, 2, 70]));
+	/// ```
+	#[inline]
+	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, O> {
+		Union::new(&self.bits, &other.bits)
+	}
+
+	/// Iterates over the values that are in both `self` and `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::intersection`](alloc::collections::BTreeSet::intersection)
+	#[inline]
+	pub fn intersection<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Intersection<'a, T, O> {
+		Intersection::new(&self.bits, &other.bits)
+	}
+
+	/// Iterates over the values that are in `self` but not in `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::difference`](alloc::collections::BTreeSet::difference)
+	#[inline]
+	pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, O> {
+		Difference::new(&self.bits, &other.bits)
+	}
+
+	/// Iterates over the values that are in exactly one of `self` and `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::symmetric_difference`](alloc::collections::BTreeSet::symmetric_difference)
+	#[inline]
+	pub fn symmetric_difference<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> SymmetricDifference<'a, T, O> {
+		SymmetricDifference::new(&self.bits, &other.bits)
+	}
+
+	/// Tests if every value in `self` is also in `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_subset`](alloc::collections::BTreeSet::is_subset)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let a = [1, 2].into_iter().collect::<BitSet>();
+	/// let b = [1, 2, 70].into_iter().collect::<BitSet>();
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// assert!(b.is_superset(&a));
+	/// ```
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.bits.intersection_count(&other.bits) == self.len()
+	}
+
+	/// Tests if every value in `other` is also in `self`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_superset`](alloc::collections::BTreeSet::is_superset)
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Tests if `self` and `other` have no values in common.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_disjoint`](alloc::collections::BTreeSet::is_disjoint)
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.bits.intersection_count(&other.bits) == 0
+	}
+}
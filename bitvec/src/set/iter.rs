@@ -0,0 +1,173 @@
+#![doc = include_str!("../../doc/set/iter.md")]
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+};
+
+use crate::{
+	mem,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Processor width, used for chunking.
+const WORD_BITS: usize = mem::bits_of::<usize>();
+
+/// Walks two membership tables in lock-step, one processor word at a time,
+/// and yields the indices of the `1` bits in some combination of each pair of
+/// words.
+#[derive(Clone, Debug)]
+struct Merge<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The left-hand table.
+	a:    &'a BitSlice<T, O>,
+	/// The right-hand table.
+	b:    &'a BitSlice<T, O>,
+	/// Combines a word of each table into a word of the output.
+	op:   fn(usize, usize) -> usize,
+	/// The index of the next word to load from both tables.
+	next: usize,
+	/// The index of the bit at significance `2^0` in `word`.
+	base: usize,
+	/// The `1` bits of the current output word that have not been yielded.
+	word: usize,
+	/// The index at which the merge stops.
+	end:  usize,
+}
+
+impl<'a, T, O> Merge<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins a merge. Tables are zero-extended to the longer of the two,
+	/// unless `end` stops the merge earlier.
+	fn new(
+		a: &'a BitSlice<T, O>,
+		b: &'a BitSlice<T, O>,
+		op: fn(usize, usize) -> usize,
+		end: usize,
+	) -> Self {
+		Self {
+			a,
+			b,
+			op,
+			next: 0,
+			base: 0,
+			word: 0,
+			end,
+		}
+	}
+
+	/// Loads the word of a table that begins at `start`, zero-extending it.
+	fn load(bits: &BitSlice<T, O>, start: usize) -> usize {
+		bits.get(start .. cmp::min(start + WORD_BITS, bits.len()))
+			.map_or(0, BitSlice::load_word)
+	}
+
+	/// Yields the next index in the output.
+	fn next(&mut self) -> Option<usize> {
+		while self.word == 0 {
+			if self.next >= self.end {
+				return None;
+			}
+			self.base = self.next;
+			self.word = (self.op)(
+				Self::load(self.a, self.base),
+				Self::load(self.b, self.base),
+			);
+			self.next += WORD_BITS;
+		}
+		let idx = self.base + self.word.trailing_zeros() as usize;
+		self.word &= self.word - 1;
+		//  The final word may extend past `end`.
+		(idx < self.end).then_some(idx)
+	}
+
+	/// Bounds the number of indices remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let rest = self.end.saturating_sub(self.next);
+		(0, Some(self.word.count_ones() as usize + rest))
+	}
+}
+
+/// Generates a named set-operation iterator over a [`Merge`].
+macro_rules! set_op {
+	($(
+		$(#[$attr:meta])*
+		$name:ident => $op:expr, $end:expr;
+	)+) => { $(
+		$(#[$attr])*
+		#[derive(Clone, Debug)]
+		pub struct $name<'a, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			/// The lock-step walk over both sets.
+			inner: Merge<'a, T, O>,
+		}
+
+		impl<'a, T, O> $name<'a, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+			pub(super) fn new(
+				a: &'a BitSlice<T, O>,
+				b: &'a BitSlice<T, O>,
+			) -> Self {
+				let end: fn(usize, usize) -> usize = $end;
+				Self {
+					inner: Merge::new(a, b, $op, end(a.len(), b.len())),
+				}
+			}
+		}
+
+		impl<T, O> Iterator for $name<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			type Item = usize;
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				self.inner.next()
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				self.inner.size_hint()
+			}
+		}
+
+		impl<T, O> FusedIterator for $name<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+		}
+	)+ };
+}
+
+set_op! {
+	#[doc = include_str!("../../doc/set/iter/Union.md")]
+	Union => |a, b| a | b, cmp::max;
+
+	#[doc = include_str!("../../doc/set/iter/Intersection.md")]
+	Intersection => |a, b| a & b, cmp::min;
+
+	#[doc = include_str!("../../doc/set/iter/Difference.md")]
+	Difference => |a, b| a & !b, |a, _| a;
+
+	#[doc = include_str!("../../doc/set/iter/SymmetricDifference.md")]
+	SymmetricDifference => |a, b| a ^ b, cmp::max;
+}
@@ -0,0 +1,113 @@
+//! Unit tests for bit-sets.
+
+#![cfg(test)]
+
+use std::collections::BTreeSet;
+
+use rand::random;
+
+use crate::{
+	prelude::*,
+	set::BitSet,
+};
+
+/// Builds a pair of equivalent random sets.
+fn random_sets<O>(
+	count: usize,
+	max: usize,
+) -> (BitSet<u16, O>, BTreeSet<usize>)
+where
+	O: BitOrder,
+{
+	let values = (0 .. count)
+		.map(|_| random::<usize>() % max)
+		.collect::<Vec<_>>();
+	(values.iter().collect(), values.iter().copied().collect())
+}
+
+#[test]
+fn membership() {
+	let mut set = BitSet::<u8, Msb0>::new();
+	assert!(set.is_empty());
+	assert_eq!((set.first(), set.last()), (None, None));
+	assert!(set.insert(17));
+	assert!(set.insert(3));
+	assert!(!set.insert(17));
+	assert!(set.contains(3) && set.contains(17));
+	assert!(!set.contains(4) && !set.contains(1000));
+	assert_eq!(set.len(), 2);
+	assert_eq!((set.first(), set.last()), (Some(3), Some(17)));
+	assert_eq!(format!("{:?}", set), "{3, 17}");
+
+	assert!(set.remove(17));
+	assert!(!set.remove(17));
+	assert!(!set.remove(1000));
+	assert_eq!(set.as_bitslice().len(), 18);
+	set.shrink_to_fit();
+	assert_eq!(set.as_bitslice().len(), 4);
+	assert_eq!(set, [3].into_iter().collect::<BitSet<u64, Lsb0>>());
+
+	set.extend([40, 5]);
+	set.extend(&[6]);
+	assert!(set.iter().eq([3, 5, 6, 40]));
+	assert!((&set).into_iter().rev().eq([40, 6, 5, 3]));
+	set.clear();
+	assert!(set.is_empty());
+
+	let bits = BitVec::<u8, Msb0>::from(BitSet::from(bitvec![u8, Msb0; 0, 1]));
+	assert_eq!(bits, bits![0, 1]);
+}
+
+#[test]
+fn algebra() {
+	let (a, a_ref) = random_sets::<Lsb0>(100, 300);
+	let (b, b_ref) = random_sets::<Lsb0>(50, 200);
+	let (c, _) = random_sets::<Msb0>(100, 300);
+	let (c, c_ref) = (
+		c.iter().collect::<BitSet<u16, Lsb0>>(),
+		c.iter().collect::<BTreeSet<_>>(),
+	);
+
+	for (x, x_ref, y, y_ref) in [
+		(&a, &a_ref, &b, &b_ref),
+		(&b, &b_ref, &a, &a_ref),
+		(&a, &a_ref, &c, &c_ref),
+	] {
+		assert!(x.union(y).eq(x_ref.union(y_ref).copied()));
+		assert!(x.intersection(y).eq(x_ref.intersection(y_ref).copied()));
+		assert!(x.difference(y).eq(x_ref.difference(y_ref).copied()));
+		assert!(x
+			.symmetric_difference(y)
+			.eq(x_ref.symmetric_difference(y_ref).copied()));
+
+		let mut z = x.clone();
+		z.union_with(y);
+		assert!(z.iter().eq(x_ref.union(y_ref).copied()));
+		z = x.clone();
+		z.intersect_with(y);
+		assert!(z.iter().eq(x_ref.intersection(y_ref).copied()));
+		z = x.clone();
+		z.difference_with(y);
+		assert!(z.iter().eq(x_ref.difference(y_ref).copied()));
+		z = x.clone();
+		z.symmetric_difference_with(y);
+		assert!(z.iter().eq(x_ref.symmetric_difference(y_ref).copied()));
+
+		assert_eq!(x.is_subset(y), x_ref.is_subset(y_ref));
+		assert_eq!(x.is_disjoint(y), x_ref.is_disjoint(y_ref));
+	}
+
+	let ab = a.intersection(&b).collect::<BitSet<u16, Lsb0>>();
+	assert!(ab.is_subset(&a) && ab.is_subset(&b) && a.is_superset(&ab));
+	assert!(a
+		.difference(&b)
+		.collect::<BitSet<u16, Lsb0>>()
+		.is_disjoint(&b));
+	assert!(BitSet::<u16, Lsb0>::new().is_subset(&b));
+}
+
+#[test]
+#[should_panic = "cannot insert `usize::MAX` into a bit-set"]
+fn insert_max() {
+	BitSet::<usize, Lsb0>::new().insert(usize::MAX);
+}
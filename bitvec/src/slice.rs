@@ -3,6 +3,7 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::{
+	cmp,
 	marker::PhantomData,
 	ops::RangeBounds,
 };
@@ -27,6 +28,10 @@ use crate::{
 		BitDomain,
 		Domain,
 	},
+	field::{
+		self,
+		BitField,
+	},
 	mem,
 	order::{
 		BitOrder,
@@ -41,18 +46,45 @@ use crate::{
 		BitSpanError,
 	},
 	store::BitStore,
+	stream::Endian,
 };
 
 mod api;
+mod arith;
 mod iter;
+mod morton;
 mod ops;
+mod par;
 mod specialization;
 mod tests;
 mod traits;
 
+#[cfg(feature = "rayon")]
+pub use self::par::{
+	ParChunks,
+	ParChunksMut,
+	ParIterOnes,
+};
+#[cfg(feature = "alloc")]
+use self::specialization::pext;
 pub use self::{
 	api::*,
 	iter::*,
+	morton::{
+		deinterleave,
+		interleave,
+	},
+};
+use self::{
+	iter::{
+		check_field_width,
+		field_mask,
+		is_bitstream,
+	},
+	specialization::{
+		pdep,
+		WORD_BITS,
+	},
 };
 
 #[repr(transparent)]
@@ -1186,6 +1218,40 @@ where
 		IterZeros::new(self)
 	}
 
+	/// Enumerates the maximal runs of equal bits in a bit-slice.
+	///
+	/// Each run is yielded as its bit-value and the range of indices it
+	/// covers. Adjacent runs always have opposite values, and together the
+	/// ranges cover the whole bit-slice. This can be reversed by
+	/// [`BitVec::from_runs`].
+	///
+	/// Each run is found by seeking the next bit of the opposite value, so this
+	/// is eligible for the same element-wise specializations as
+	/// [`.iter_ones()`] and [`.iter_zeros()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut runs = bits.iter_runs();
+	///
+	/// assert_eq!(runs.next(), Some((false, 0 .. 2)));
+	/// assert_eq!(runs.next_back(), Some((true, 6 .. 7)));
+	/// assert_eq!(runs.next(), Some((true, 2 .. 5)));
+	/// assert_eq!(runs.next(), Some((false, 5 .. 6)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	///
+	/// [`BitVec::from_runs`]: crate::vec::BitVec::from_runs
+	/// [`.iter_ones()`]: Self::iter_ones
+	/// [`.iter_zeros()`]: Self::iter_zeros
+	#[inline]
+	pub fn iter_runs(&self) -> IterRuns<T, O> {
+		IterRuns::new(self)
+	}
+
 	/// Finds the index of the first bit in the bit-slice set to `1`.
 	///
 	/// Returns `None` if there is no `true` bit in the bit-slice.
@@ -1429,223 +1495,1052 @@ where
 	}
 }
 
-/// Buffer manipulation.
+/// Population-count similarity metrics.
+///
+/// These treat a pair of bit-slices as sets of indices, and count them in a
+/// single pass without allocating. A shorter bit-slice behaves as if it were
+/// zero-extended to the length of the longer.
 impl<T, O> BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
-	/// Shifts the contents of a bit-slice “left” (towards the zero-index),
-	/// clearing the “right” bits to `0`.
-	///
-	/// This is a strictly-worse analogue to taking `bits = &bits[by ..]`: it
-	/// has to modify the entire memory region that `bits` governs, and destroys
-	/// contained information. Unless the actual memory layout and contents of
-	/// your bit-slice matters to your program, you should *probably* prefer to
-	/// munch your way forward through a bit-slice handle.
-	///
-	/// Note also that the “left” here is semantic only, and **does not**
-	/// necessarily correspond to a left-shift instruction applied to the
-	/// underlying integer storage.
-	///
-	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
-	/// bit-slice is entirely cleared to `0`.
-	///
-	/// ## Panics
-	///
-	/// This panics if `by` is not less than `self.len()`.
+	/// Counts the indices at which two bit-slices hold different bits.
 	///
 	/// ## Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = bits![mut 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1];
-	/// // these bits are retained ^--------------------------^
-	/// bits.shift_left(2);
-	/// assert_eq!(bits, bits![1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0]);
-	/// // and move here       ^--------------------------^
-	///
-	/// let bits = bits![mut 1; 2];
-	/// bits.shift_left(2);
-	/// assert_eq!(bits, bits![0; 2]);
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u16, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.hamming_distance(b), 3);
+	/// assert_eq!(a.hamming_distance(a), 0);
 	/// ```
 	#[inline]
-	pub fn shift_start(&mut self, by: usize) {
-		if by == 0 {
-			return;
-		}
-
-		let len = self.len();
-		if by == len {
-			return self.fill(false);
-		}
-		assert!(
-			by <= len,
-			"shift must be less than the length of the bit-slice: {} >= {}",
-			by,
-			len,
-		);
-
-		unsafe {
-			self.copy_within_unchecked(by .., 0);
-			self.get_unchecked_mut(len - by ..).fill(false);
-		}
+	pub fn hamming_distance<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let (intersection, union) = self.overlap(other);
+		union - intersection
 	}
 
+	/// Counts the indices at which both bit-slices hold `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u16, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.intersection_count(b), 2);
+	/// ```
 	#[inline]
-	#[doc(hidden)]
-	#[allow(deprecated)]
-	#[deprecated(note = "Use `.shift_start()` instead", since = "1.1.0")]
-	pub fn shift_left(&mut self, by: usize) {
-		self.shift_start(by);
+	pub fn intersection_count<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overlap(other).0
 	}
 
-	/// Shifts the contents of a bit-slice “right” (away from the zero-index),
-	/// clearing the “left” bits to `0`.
-	///
-	/// This is a strictly-worse analogue to taking `bits = &bits[.. bits.len()
-	/// - by]`: it must modify the entire memory region that `bits` governs, and
-	/// destroys contained information. Unless the actual memory layout and
-	/// contents of your bit-slice matters to your program, you should
-	/// *probably* prefer to munch your way backward through a bit-slice handle.
+	/// Counts the indices at which either bit-slice holds `1`.
 	///
-	/// Note also that the “right” here is semantic only, and **does not**
-	/// necessarily correspond to a right-shift instruction applied to the
-	/// underlying integer storage.
+	/// ## Examples
 	///
-	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
-	/// bit-slice is entirely cleared to `0`.
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// ## Panics
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u16, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.union_count(b), 5);
+	/// ```
+	#[inline]
+	pub fn union_count<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.overlap(other).1
+	}
+
+	/// Computes the Jaccard similarity of two bit-slices: the size of their
+	/// intersection divided by the size of their union.
 	///
-	/// This panics if `by` is not less than `self.len()`.
+	/// For bit-vectors this is the same as the Tanimoto coefficient. Two
+	/// bit-slices with no `1` bits at all are considered identical, and have a
+	/// similarity of `1.0`.
 	///
 	/// ## Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let bits = bits![mut 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1];
-	/// // these bits stay   ^--------------------------^
-	/// bits.shift_right(2);
-	/// assert_eq!(bits, bits![0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1]);
-	/// // and move here             ^--------------------------^
-	///
-	/// let bits = bits![mut 1; 2];
-	/// bits.shift_right(2);
-	/// assert_eq!(bits, bits![0; 2]);
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![u16, Msb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.jaccard(b), 0.4);
+	/// assert_eq!(bits![0; 4].jaccard(bits![]), 1.0);
 	/// ```
 	#[inline]
-	pub fn shift_end(&mut self, by: usize) {
-		if by == 0 {
-			return;
+	pub fn jaccard<T2, O2>(&self, other: &BitSlice<T2, O2>) -> f64
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		match self.overlap(other) {
+			(_, 0) => 1.0,
+			(intersection, union) => intersection as f64 / union as f64,
 		}
+	}
 
-		let len = self.len();
-		if by == len {
-			return self.fill(false);
-		}
-		assert!(
-			by <= len,
-			"shift must be less than the length of the bit-slice: {} >= {}",
-			by,
-			len,
-		);
+	/// Computes the Tanimoto coefficient of two bit-slices.
+	///
+	/// This is an alias of [`.jaccard()`], under the name used for chemical
+	/// fingerprints.
+	///
+	/// [`.jaccard()`]: Self::jaccard
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn tanimoto<T2, O2>(&self, other: &BitSlice<T2, O2>) -> f64
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.jaccard(other)
+	}
 
-		unsafe {
-			self.copy_within_unchecked(.. len - by, by);
-			self.get_unchecked_mut(.. by).fill(false);
+	/// Counts the intersection and union of two bit-slices in one pass.
+	fn overlap<T2, O2>(&self, other: &BitSlice<T2, O2>) -> (usize, usize)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = cmp::min(self.len(), other.len());
+		let (this, this_rest) = unsafe { self.split_at_unchecked(len) };
+		let (that, that_rest) = unsafe { other.split_at_unchecked(len) };
+		let (intersection, union) = this
+			.overlap_elements(that)
+			.unwrap_or_else(|| this.overlap_words(that));
+		(
+			intersection,
+			union + this_rest.count_ones() + that_rest.count_ones(),
+		)
+	}
+
+	/// Counts the overlap of two equal-length bit-slices with the same type
+	/// parameters and starting bit, by pairing up the elements of their
+	/// domains.
+	///
+	/// This returns `None` when the bit-slices do not have matching domains.
+	fn overlap_elements<T2, O2>(
+		&self,
+		other: &BitSlice<T2, O2>,
+	) -> Option<(usize, usize)>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let other = other.coerce::<T, O>()?;
+		if self.as_bitspan().head() != other.as_bitspan().head() {
+			return None;
+		}
+		let count = |(and, or): (usize, usize), (a, b): (T::Mem, T::Mem)| {
+			(
+				and + (a & b).count_ones() as usize,
+				or + (a | b).count_ones() as usize,
+			)
+		};
+		match (self.domain(), other.domain()) {
+			(Domain::Enclave(a), Domain::Enclave(b)) => {
+				Some(count((0, 0), (a.load_value(), b.load_value())))
+			},
+			(
+				Domain::Region {
+					head: h1,
+					body: b1,
+					tail: t1,
+				},
+				Domain::Region {
+					head: h2,
+					body: b2,
+					tail: t2,
+				},
+			) => {
+				let head =
+					h1.zip(h2).map(|(a, b)| (a.load_value(), b.load_value()));
+				let tail =
+					t1.zip(t2).map(|(a, b)| (a.load_value(), b.load_value()));
+				let body = b1
+					.iter()
+					.map(BitStore::load_value)
+					.zip(b2.iter().map(BitStore::load_value));
+				Some(
+					head.into_iter().chain(body).chain(tail).fold((0, 0), count),
+				)
+			},
+			_ => None,
 		}
 	}
 
-	#[inline]
-	#[doc(hidden)]
-	#[allow(deprecated)]
-	#[deprecated(note = "Use `.shift_end() instead", since = "1.1.0")]
-	pub fn shift_right(&mut self, by: usize) {
-		self.shift_end(by);
+	/// Counts the overlap of two equal-length bit-slices one processor word at
+	/// a time.
+	///
+	/// The `Lsb0` and `Msb0` orderings load each word with a `BitField`
+	/// access, which shifts the word into place across element boundaries when
+	/// the two bit-slices begin at different offsets.
+	fn overlap_words<T2, O2>(&self, other: &BitSlice<T2, O2>) -> (usize, usize)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.chunks(WORD_BITS)
+			.zip(other.chunks(WORD_BITS))
+			.map(|(a, b)| (a.load_word(), b.load_word()))
+			.fold((0, 0), |(and, or), (a, b)| {
+				(
+					and + (a & b).count_ones() as usize,
+					or + (a | b).count_ones() as usize,
+				)
+			})
 	}
 }
 
-/// Crate internals.
+/// Subsequence search.
 impl<T, O> BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
-	/// Gets the structural form of the encoded reference.
-	pub(crate) fn as_bitspan(&self) -> BitSpan<Const, T, O> {
-		BitSpan::from_bitslice_ptr(self)
-	}
-
-	/// Gets the structural form of the encoded reference.
-	pub(crate) fn as_mut_bitspan(&mut self) -> BitSpan<Mut, T, O> {
-		BitSpan::from_bitslice_ptr_mut(self)
-	}
-
-	/// Asserts that `index` is within the given bounds.
+	/// Finds the index of the first occurrence of a bit-sequence.
 	///
-	/// ## Parameters
+	/// The needle does not need to share type parameters with the bit-slice
+	/// being searched. The search loads both the needle and the haystack one
+	/// processor word at a time, and tests each word of the haystack against
+	/// every bit-offset of the needle’s first word with shifts, rather than
+	/// comparing individual bits. Only candidate positions that match the
+	/// first word of the needle are compared in full.
 	///
-	/// - `&self`
-	/// - `index`: The bit index to test against the bit-slice.
-	/// - `bounds`: The bounds to check. cannot exceed `0 ..= self.len()`.
+	/// The `Lsb0` and `Msb0` orderings are loaded with `BitField`; other
+	/// orderings gather each bit of a word individually.
 	///
-	/// ## Panics
+	/// ## Original
 	///
-	/// This panics if `bounds` is outside `index`.
-	pub(crate) fn assert_in_bounds<R>(&self, index: usize, bounds: R)
-	where R: RangeExt<usize> {
-		let bounds = bounds.normalize(0, self.len());
-		assert!(
-			bounds.contains(&index),
-			"index {} out of range: {:?}",
-			index,
-			bounds.end_bound()
-		);
-	}
-
-	/// Marks an exclusive bit-slice as covering an aliased memory region.
-	pub(crate) fn alias_mut(&mut self) -> &mut BitSlice<T::Alias, O> {
-		unsafe { self.as_mut_bitspan().cast::<T::Alias>().into_bitslice_mut() }
-	}
-
-	/// Removes an aliasing marker from an exclusive bit-slice handle.
+	/// [`str::find`](https://doc.rust-lang.org/std/primitive.str.html#method.find)
 	///
-	/// ## Safety
+	/// ## Returns
 	///
-	/// This may only be used when the bit-slice is either known to be
-	/// unaliased, or this call is combined with an operation that adds an
-	/// aliasing marker and the total number of aliasing markers remains
-	/// unchanged.
-	pub(crate) unsafe fn unalias_mut(
-		this: &mut BitSlice<T::Alias, O>,
-	) -> &mut Self {
-		this.as_mut_bitspan().cast::<T>().into_bitslice_mut()
-	}
-
-	/// Splits a mutable bit-slice at a midpoint, without either doing bounds
-	/// checks or adding an alias marker to the returned sections.
+	/// The index in `self` at which `needle` begins, if it is present. An empty
+	/// needle is always found at index `0`.
 	///
-	/// This method has the same behavior as [`.split_at_unchecked_mut()`],
-	/// except that it does not apply an aliasing marker to the partitioned
-	/// subslices.
+	/// ## Examples
 	///
-	/// ## Safety
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// See `split_at_unchecked_mut`. Additionally, this is only safe when `T`
-	/// is alias-safe.
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.find(bits![1, 1]), Some(4));
+	/// assert_eq!(bits.find(bits![1, 1, 1]), None);
 	///
-	/// [`.split_at_unchecked_mut()`]: Self::split_at_unchecked_mut
-	pub(crate) unsafe fn split_at_unchecked_mut_noalias(
-		&mut self,
-		mid: usize,
-	) -> (&mut Self, &mut Self) {
-		//  Split the slice at the requested midpoint, adding an alias layer
-		let (head, tail) = self.split_at_unchecked_mut(mid);
-		//  Remove the new alias layer.
-		(Self::unalias_mut(head), Self::unalias_mut(tail))
+	/// let stream = [0b0000_0111u8, 0b1110_0000];
+	/// let flag = 0x7Eu8;
+	/// assert_eq!(
+	///   stream.view_bits::<Msb0>().find(flag.view_bits::<Msb0>()),
+	///   Some(4),
+	/// );
+	/// ```
+	#[inline]
+	pub fn find<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let (len, width) = (self.len(), needle.len());
+		if width > len {
+			return None;
+		}
+		let last = len - width;
+		let (key, mask) = search_key(needle);
+
+		let mut base = 0;
+		while base <= last {
+			let (lo, hi) = (self.word_at(base), self.word_at(base + WORD_BITS));
+			for shamt in 0 .. WORD_BITS.min(last - base + 1) {
+				if shifted(lo, hi, shamt) & mask == key
+					&& self.matches_tail(base + shamt, needle)
+				{
+					return Some(base + shamt);
+				}
+			}
+			base += WORD_BITS;
+		}
+		None
+	}
+
+	/// Finds the index of the last occurrence of a bit-sequence.
+	///
+	/// This uses the same word-at-a-time search as [`.find()`], proceeding
+	/// from the back of the bit-slice.
+	///
+	/// ## Original
+	///
+	/// [`str::rfind`](https://doc.rust-lang.org/std/primitive.str.html#method.rfind)
+	///
+	/// ## Returns
+	///
+	/// The index in `self` at which the last occurrence of `needle` begins, if
+	/// it is present. An empty needle is always found at index `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.rfind(bits![1, 1]), Some(7));
+	/// assert_eq!(bits.rfind(bits![0, 0]), Some(0));
+	/// assert_eq!(bits.rfind(bits![1; 3]), None);
+	/// ```
+	///
+	/// [`.find()`]: Self::find
+	#[inline]
+	pub fn rfind<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let (len, width) = (self.len(), needle.len());
+		if width > len {
+			return None;
+		}
+		let last = len - width;
+		let (key, mask) = search_key(needle);
+
+		let mut base = last - last % WORD_BITS;
+		loop {
+			let (lo, hi) = (self.word_at(base), self.word_at(base + WORD_BITS));
+			for shamt in (0 .. WORD_BITS.min(last - base + 1)).rev() {
+				if shifted(lo, hi, shamt) & mask == key
+					&& self.matches_tail(base + shamt, needle)
+				{
+					return Some(base + shamt);
+				}
+			}
+			if base == 0 {
+				return None;
+			}
+			base -= WORD_BITS;
+		}
+	}
+
+	/// Iterates over the starting index of each occurrence of a bit-sequence,
+	/// including occurrences that overlap each other.
+	///
+	/// Each step of the iterator is a call to [`.find()`] on the remainder of
+	/// the bit-slice after the previous match.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1];
+	/// let found = bits.find_iter(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 1, 4]);
+	/// ```
+	///
+	/// [`.find()`]: Self::find
+	#[inline]
+	pub fn find_iter<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> FindIter<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		FindIter::new(self, needle)
+	}
+
+	/// Iterates over the non-overlapping occurrences of a bit-sequence,
+	/// yielding the index of each match along with the matched bits.
+	///
+	/// Unlike [`.find_iter()`], the search resumes after the *end* of each
+	/// match, so overlapping occurrences are not reported.
+	///
+	/// ## Original
+	///
+	/// [`str::match_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.match_indices)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1];
+	/// let needle = bits![1, 1];
+	/// let mut matches = bits.match_indices(needle);
+	/// assert_eq!(matches.next(), Some((0, bits![1, 1])));
+	/// assert_eq!(matches.next(), Some((4, bits![1, 1])));
+	/// assert!(matches.next().is_none());
+	/// ```
+	///
+	/// [`.find_iter()`]: Self::find_iter
+	#[inline]
+	pub fn match_indices<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> MatchIndices<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		MatchIndices::new(self, needle)
+	}
+
+	/// Loads up to one processor word starting at `index`. Bits past the end
+	/// of the bit-slice are zero.
+	fn word_at(&self, index: usize) -> usize {
+		let end = self.len().min(index + WORD_BITS);
+		self.get(index .. end).map(Self::load_word).unwrap_or(0)
+	}
+
+	/// Tests if the part of a needle after its first word matches the
+	/// bit-slice at `index`. The first word must already have been compared.
+	fn matches_tail<T2, O2>(
+		&self,
+		index: usize,
+		needle: &BitSlice<T2, O2>,
+	) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let width = needle.len();
+		width <= WORD_BITS
+			|| unsafe {
+				self.get_unchecked(index + WORD_BITS .. index + width)
+					.word_eq(needle.get_unchecked(WORD_BITS ..))
+			}
+	}
+}
+
+/// Parallel bit deposition.
+///
+/// This selects bits of a bit-slice by the `1` bits of a mask bit-slice, and
+/// processes one processor word of both at a time with a portable emulation
+/// of the `pdep` instruction. The mask does not need to share type parameters
+/// with the bit-slice. Bits of the bit-slice beyond the end of the mask are
+/// never selected, and bits of the mask beyond the end of the bit-slice are
+/// ignored.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Scatters the bits of `src`, in order, into the positions of `self`
+	/// selected by `mask`.
+	///
+	/// This is the inverse of [`.extract()`]. Bits of `self` that are not
+	/// selected by `mask` are left unchanged. If `src` runs out before the
+	/// selected positions do, the remaining selected positions are cleared to
+	/// `0`; excess bits of `src` are not used.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 0, 0, 1, 1];
+	/// let mask = bits![1, 0, 1, 1, 0, 1];
+	/// bits.deposit(mask, bits![1, 1, 0]);
+	/// assert_eq!(bits, bits![1, 1, 1, 0, 1, 0]);
+	/// ```
+	///
+	/// [`.extract()`]: Self::extract
+	#[inline]
+	pub fn deposit<T2, O2, T3, O3>(
+		&mut self,
+		mask: &BitSlice<T2, O2>,
+		src: &BitSlice<T3, O3>,
+	) where
+		T2: BitStore,
+		O2: BitOrder,
+		T3: BitStore,
+		O3: BitOrder,
+	{
+		let limit = cmp::min(self.len(), mask.len());
+		let mut taken = 0;
+		for start in (0 .. limit).step_by(WORD_BITS) {
+			let end = cmp::min(start + WORD_BITS, limit);
+			let mask = unsafe { mask.get_unchecked(start .. end) }.load_word();
+			if mask == 0 {
+				continue;
+			}
+			let count = mask.count_ones() as usize;
+			let bits = src
+				.get(taken .. cmp::min(taken + count, src.len()))
+				.map_or(0, BitSlice::load_word);
+			taken += count;
+			let dest = unsafe { self.get_unchecked_mut(start .. end) };
+			dest.store_word(dest.load_word() & !mask | pdep(bits, mask));
+		}
+	}
+}
+
+/// Field iteration.
+///
+/// These walk a bit-slice as a sequence of equal-width fields, each of which is
+/// moved in or out of an integer as by [`BitField`]. They behave as
+/// `.chunks_exact(width)` combined with a `BitField` method on each chunk, but
+/// where each field falls in a bitstream (little-endian fields of `Lsb0`, or
+/// big-endian fields of `Msb0`), they read whole words at once and cut the
+/// fields out of those words, rather than finding the memory of each field
+/// separately.
+///
+/// Signed integers are sign-extended from the highest bit of each field, as
+/// `BitField` does. Bits after the last whole field are not visited.
+///
+/// [`BitField`]: crate::field::BitField
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Iterates over successive `width`-bit fields, loading each with
+	/// [`BitField::load_le`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0b1001_0110u8, 0b0000_0111];
+	/// let bits = data.view_bits::<Lsb0>();
+	///
+	/// let mut fields = bits.iter_uints::<u8>(5);
+	/// assert_eq!(fields.next(), Some(0b1_0110));
+	/// assert_eq!(fields.next(), Some(0b1_1100));
+	/// assert_eq!(fields.next(), Some(0b0_0001));
+	/// assert!(fields.next().is_none());
+	/// assert_eq!(fields.remainder(), bits![0]);
+	/// ```
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn iter_uints<I>(&self, width: usize) -> IterUints<T, O, I>
+	where I: Integral {
+		IterUints::new(self, width, Endian::Little)
+	}
+
+	/// Iterates over successive `width`-bit fields, loading each with
+	/// [`BitField::load_be`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34, 0x56];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert!(bits.iter_uints_be::<u16>(12).eq([0x123, 0x456]));
+	/// assert!(bits.iter_uints_be::<i8>(4).eq([1, 2, 3, 4, 5, 6]));
+	/// assert!(bits[4 ..].iter_uints_be::<i8>(3).eq([1, 0, -2, -4, 2, -3]));
+	/// ```
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn iter_uints_be<I>(&self, width: usize) -> IterUints<T, O, I>
+	where I: Integral {
+		IterUints::new(self, width, Endian::Big)
+	}
+
+	/// Rewrites each successive `width`-bit field in place.
+	///
+	/// Each field is loaded with [`BitField::load_le`], passed to `func`, and
+	/// the return value is stored back with [`BitField::store_le`], which keeps
+	/// only its low `width` bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero, or wider than `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0x21u16, 0x43];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// bits.for_each_uint_mut::<u8, _>(4, |nibble| nibble * 2);
+	/// assert_eq!(data, [0x42, 0x86]);
+	/// ```
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn for_each_uint_mut<I, F>(&mut self, width: usize, func: F)
+	where
+		I: Integral,
+		F: FnMut(I) -> I,
+	{
+		self.rewrite_fields(width, Endian::Little, func);
+	}
+
+	/// Rewrites each successive `width`-bit field in place, using
+	/// [`BitField::load_be`] and [`BitField::store_be`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is zero, or wider than `I`.
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn for_each_uint_mut_be<I, F>(&mut self, width: usize, func: F)
+	where
+		I: Integral,
+		F: FnMut(I) -> I,
+	{
+		self.rewrite_fields(width, Endian::Big, func);
+	}
+
+	/// Rewrites each `width`-bit field with `func`.
+	///
+	/// In a bitstream, as many whole fields as fit in a `u64` are loaded,
+	/// rewritten, and stored together. Otherwise, each field is loaded and
+	/// stored on its own.
+	fn rewrite_fields<I, F>(&mut self, width: usize, endian: Endian, mut func: F)
+	where
+		I: Integral,
+		F: FnMut(I) -> I,
+	{
+		check_field_width::<I>(width);
+		let fields = if is_bitstream::<O>(width, endian) {
+			64 / width
+		}
+		else {
+			1
+		};
+		let step = fields * width;
+		let end = self.len() / width * width;
+		let mask = field_mask(width);
+
+		for start in (0 .. end).step_by(step) {
+			let chunk = unsafe {
+				self.get_unchecked_mut(start .. cmp::min(start + step, end))
+			};
+			if fields == 1 {
+				let value = match endian {
+					Endian::Big => chunk.load_be::<I>(),
+					Endian::Little => chunk.load_le::<I>(),
+				};
+				match endian {
+					Endian::Big => chunk.store_be(func(value)),
+					Endian::Little => chunk.store_le(func(value)),
+				}
+				continue;
+			}
+
+			let len = chunk.len();
+			let mut word = match endian {
+				Endian::Big => chunk.load_be::<u64>(),
+				Endian::Little => chunk.load_le::<u64>(),
+			};
+			for field in 0 .. len / width {
+				let shamt = match endian {
+					Endian::Big => len - (field + 1) * width,
+					Endian::Little => field * width,
+				};
+				let value =
+					field::sign(field::resize(word >> shamt & mask), width);
+				let value = field::resize::<I, u64>(func(value)) & mask;
+				word = word & !(mask << shamt) | value << shamt;
+			}
+			match endian {
+				Endian::Big => chunk.store_be(word),
+				Endian::Little => chunk.store_le(word),
+			}
+		}
+	}
+}
+
+/// Buffer manipulation.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Shifts the contents of a bit-slice “left” (towards the zero-index),
+	/// clearing the “right” bits to `0`.
+	///
+	/// This is a strictly-worse analogue to taking `bits = &bits[by ..]`: it
+	/// has to modify the entire memory region that `bits` governs, and destroys
+	/// contained information. Unless the actual memory layout and contents of
+	/// your bit-slice matters to your program, you should *probably* prefer to
+	/// munch your way forward through a bit-slice handle.
+	///
+	/// Note also that the “left” here is semantic only, and **does not**
+	/// necessarily correspond to a left-shift instruction applied to the
+	/// underlying integer storage.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
+	/// bit-slice is entirely cleared to `0`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is not less than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1];
+	/// // these bits are retained ^--------------------------^
+	/// bits.shift_left(2);
+	/// assert_eq!(bits, bits![1, 1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0]);
+	/// // and move here       ^--------------------------^
+	///
+	/// let bits = bits![mut 1; 2];
+	/// bits.shift_left(2);
+	/// assert_eq!(bits, bits![0; 2]);
+	/// ```
+	#[inline]
+	pub fn shift_start(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+
+		let len = self.len();
+		if by == len {
+			return self.fill(false);
+		}
+		assert!(
+			by <= len,
+			"shift must be less than the length of the bit-slice: {} >= {}",
+			by,
+			len,
+		);
+
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			return this.sp_shift_start(by);
+		}
+		if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			return this.sp_shift_start(by);
+		}
+		unsafe {
+			self.copy_within_unchecked(by .., 0);
+			self.get_unchecked_mut(len - by ..).fill(false);
+		}
+	}
+
+	#[inline]
+	#[doc(hidden)]
+	#[allow(deprecated)]
+	#[deprecated(note = "Use `.shift_start()` instead", since = "1.1.0")]
+	pub fn shift_left(&mut self, by: usize) {
+		self.shift_start(by);
+	}
+
+	/// Shifts the contents of a bit-slice “right” (away from the zero-index),
+	/// clearing the “left” bits to `0`.
+	///
+	/// This is a strictly-worse analogue to taking `bits = &bits[.. bits.len()
+	/// - by]`: it must modify the entire memory region that `bits` governs, and
+	/// destroys contained information. Unless the actual memory layout and
+	/// contents of your bit-slice matters to your program, you should
+	/// *probably* prefer to munch your way backward through a bit-slice handle.
+	///
+	/// Note also that the “right” here is semantic only, and **does not**
+	/// necessarily correspond to a right-shift instruction applied to the
+	/// underlying integer storage.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
+	/// bit-slice is entirely cleared to `0`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is not less than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 1];
+	/// // these bits stay   ^--------------------------^
+	/// bits.shift_right(2);
+	/// assert_eq!(bits, bits![0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 1]);
+	/// // and move here             ^--------------------------^
+	///
+	/// let bits = bits![mut 1; 2];
+	/// bits.shift_right(2);
+	/// assert_eq!(bits, bits![0; 2]);
+	/// ```
+	#[inline]
+	pub fn shift_end(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+
+		let len = self.len();
+		if by == len {
+			return self.fill(false);
+		}
+		assert!(
+			by <= len,
+			"shift must be less than the length of the bit-slice: {} >= {}",
+			by,
+			len,
+		);
+
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			return this.sp_shift_end(by);
+		}
+		if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			return this.sp_shift_end(by);
+		}
+		unsafe {
+			self.copy_within_unchecked(.. len - by, by);
+			self.get_unchecked_mut(.. by).fill(false);
+		}
+	}
+
+	#[inline]
+	#[doc(hidden)]
+	#[allow(deprecated)]
+	#[deprecated(note = "Use `.shift_end() instead", since = "1.1.0")]
+	pub fn shift_right(&mut self, by: usize) {
+		self.shift_end(by);
+	}
+
+	/// Arithmetically shifts a little-endian two’s-complement bit-slice
+	/// towards its least significant bit, as `>>=` shifts a signed integer.
+	///
+	/// The zeroth bit is the least significant and the last bit is the sign
+	/// bit, as in [`.cmp_signed_le()`]. This moves bits towards the zero-index,
+	/// as [`.shift_start()`] does, and fills the vacated back bits with the
+	/// original value of the last bit. A shift towards the most significant bit
+	/// does not need a signed variant, as it always fills with `0`.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, every
+	/// bit in the bit-slice is set to the original sign bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut num = BitArray::<u8, Lsb0>::new(-100i8 as u8);
+	/// num.shift_signed_le(3);
+	/// assert_eq!(num.into_inner() as i8, -100 >> 3);
+	///
+	/// let bits = bits![mut 0, 0, 1, 1, 0, 1];
+	/// bits.shift_signed_le(2);
+	/// assert_eq!(bits, bits![1, 1, 0, 1, 1, 1]);
+	/// ```
+	///
+	/// [`.cmp_signed_le()`]: Self::cmp_signed_le
+	/// [`.shift_start()`]: Self::shift_start
+	#[inline]
+	pub fn shift_signed_le(&mut self, by: usize) {
+		let sign = self.last().map_or(false, |bit| *bit);
+		self.shift_start(by);
+		if sign {
+			let len = self.len();
+			unsafe {
+				self.get_unchecked_mut(len - by ..).fill(true);
+			}
+		}
+	}
+
+	/// Arithmetically shifts a big-endian two’s-complement bit-slice towards
+	/// its least significant bit, as `>>=` shifts a signed integer.
+	///
+	/// The zeroth bit is the sign bit and the last bit is the least
+	/// significant, as in [`.cmp_signed_be()`]. This moves bits away from the
+	/// zero-index, as [`.shift_end()`] does, and fills the vacated front bits
+	/// with the original value of the zeroth bit. A shift towards the most
+	/// significant bit does not need a signed variant, as it always fills with
+	/// `0`.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, every
+	/// bit in the bit-slice is set to the original sign bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut num = BitArray::<u8, Msb0>::new(-100i8 as u8);
+	/// num.shift_signed_be(3);
+	/// assert_eq!(num.into_inner() as i8, -100 >> 3);
+	///
+	/// let bits = bits![mut 1, 0, 1, 1, 0, 0];
+	/// bits.shift_signed_be(2);
+	/// assert_eq!(bits, bits![1, 1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`.cmp_signed_be()`]: Self::cmp_signed_be
+	/// [`.shift_end()`]: Self::shift_end
+	#[inline]
+	pub fn shift_signed_be(&mut self, by: usize) {
+		let sign = self.first().map_or(false, |bit| *bit);
+		self.shift_end(by);
+		if sign {
+			unsafe {
+				self.get_unchecked_mut(.. by).fill(true);
+			}
+		}
+	}
+
+	/// Funnel-shifts the contents of another bit-slice into `self`.
+	///
+	/// This shifts `self` “left” (towards the zero-index) by `by` bits, as
+	/// [`.shift_start()`] does, and then fills the vacated “right” bits with
+	/// the first `by` bits of `other`. Viewing `self` and `other` as one
+	/// concatenated sequence, this moves the window that `self` covers
+	/// forward by `by` bits.
+	///
+	/// Like the other shifts, this is accelerated when `self` and `other` have
+	/// the same type parameters.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is greater than either `self.len()` or
+	/// `other.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0, 1, 0];
+	/// let next = bits![u8, Msb0; 0, 1, 1, 1];
+	/// bits.shift_in_from(next, 3);
+	/// assert_eq!(bits, bits![0, 1, 0, 0, 1, 1]);
+	/// ```
+	///
+	/// [`.shift_start()`]: Self::shift_start
+	#[inline]
+	pub fn shift_in_from<T2, O2>(&mut self, other: &BitSlice<T2, O2>, by: usize)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len();
+		assert!(
+			by <= other.len(),
+			"shift must not exceed the length of the source bit-slice: {} > {}",
+			by,
+			other.len(),
+		);
+		self.shift_start(by);
+		unsafe {
+			self.get_unchecked_mut(len - by ..)
+				.clone_from_bitslice(other.get_unchecked(.. by));
+		}
+	}
+}
+
+/// Crate internals.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the structural form of the encoded reference.
+	pub(crate) fn as_bitspan(&self) -> BitSpan<Const, T, O> {
+		BitSpan::from_bitslice_ptr(self)
+	}
+
+	/// Gets the structural form of the encoded reference.
+	pub(crate) fn as_mut_bitspan(&mut self) -> BitSpan<Mut, T, O> {
+		BitSpan::from_bitslice_ptr_mut(self)
+	}
+
+	/// Asserts that `index` is within the given bounds.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to test against the bit-slice.
+	/// - `bounds`: The bounds to check. cannot exceed `0 ..= self.len()`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `bounds` is outside `index`.
+	pub(crate) fn assert_in_bounds<R>(&self, index: usize, bounds: R)
+	where R: RangeExt<usize> {
+		let bounds = bounds.normalize(0, self.len());
+		assert!(
+			bounds.contains(&index),
+			"index {} out of range: {:?}",
+			index,
+			bounds.end_bound()
+		);
+	}
+
+	/// Marks an exclusive bit-slice as covering an aliased memory region.
+	pub(crate) fn alias_mut(&mut self) -> &mut BitSlice<T::Alias, O> {
+		unsafe { self.as_mut_bitspan().cast::<T::Alias>().into_bitslice_mut() }
+	}
+
+	/// Removes an aliasing marker from an exclusive bit-slice handle.
+	///
+	/// ## Safety
+	///
+	/// This may only be used when the bit-slice is either known to be
+	/// unaliased, or this call is combined with an operation that adds an
+	/// aliasing marker and the total number of aliasing markers remains
+	/// unchanged.
+	pub(crate) unsafe fn unalias_mut(
+		this: &mut BitSlice<T::Alias, O>,
+	) -> &mut Self {
+		this.as_mut_bitspan().cast::<T>().into_bitslice_mut()
+	}
+
+	/// Splits a mutable bit-slice at a midpoint, without either doing bounds
+	/// checks or adding an alias marker to the returned sections.
+	///
+	/// This method has the same behavior as [`.split_at_unchecked_mut()`],
+	/// except that it does not apply an aliasing marker to the partitioned
+	/// subslices.
+	///
+	/// ## Safety
+	///
+	/// See `split_at_unchecked_mut`. Additionally, this is only safe when `T`
+	/// is alias-safe.
+	///
+	/// [`.split_at_unchecked_mut()`]: Self::split_at_unchecked_mut
+	pub(crate) unsafe fn split_at_unchecked_mut_noalias(
+		&mut self,
+		mid: usize,
+	) -> (&mut Self, &mut Self) {
+		//  Split the slice at the requested midpoint, adding an alias layer
+		let (head, tail) = self.split_at_unchecked_mut(mid);
+		//  Remove the new alias layer.
+		(Self::unalias_mut(head), Self::unalias_mut(tail))
 	}
 }
 
@@ -1808,6 +2703,216 @@ where
 	}
 }
 
+/// Boolean arithmetic into new allocations.
+///
+/// These leave both operands untouched, and share the word-at-a-time
+/// acceleration of the `&=`, `|=`, and `^=` operators when both operands have
+/// the same type parameters.
+#[cfg(feature = "alloc")]
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Computes `self & !rhs` into a new bit-vector.
+	///
+	/// The result is as long as `self`. If `rhs` is shorter, it is
+	/// zero-extended, so the excess bits of `self` are copied unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.and_not(b), bits![0, 0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn and_not<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = self.to_bitvec();
+		out.bitop_assign(rhs, |a, b| a & !b, |a, b| a & !b);
+		out
+	}
+
+	/// Computes `!(self & rhs)` into a new bit-vector.
+	///
+	/// The result is as long as `self`. If `rhs` is shorter, it is
+	/// zero-extended, so the excess bits of the result are all set to `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.nand(b), bits![1, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn nand<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = self.to_bitvec();
+		out.bitop_assign(rhs, |a, b| !(a & b), |a, b| !(a & b));
+		out
+	}
+
+	/// Computes `!(self | rhs)` into a new bit-vector.
+	///
+	/// The result is as long as `self`. If `rhs` is shorter, it is
+	/// zero-extended, so the excess bits of the result are the inverse of
+	/// `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.nor(b), bits![1, 0, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn nor<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = self.to_bitvec();
+		out.bitop_assign(rhs, |a, b| !(a | b), |a, b| !(a | b));
+		out
+	}
+
+	/// Computes `!(self ^ rhs)` into a new bit-vector.
+	///
+	/// The result is as long as `self`. If `rhs` is shorter, it is
+	/// zero-extended, so the excess bits of the result are the inverse of
+	/// `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.xnor(b), bits![1, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn xnor<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = self.to_bitvec();
+		out.bitop_assign(rhs, |a, b| !(a ^ b), |a, b| !(a ^ b));
+		out
+	}
+}
+
+/// Parallel bit extraction.
+///
+/// This is the inverse of parallel bit deposition, and follows the same
+/// rules for selecting bits by a mask, using a portable emulation of the
+/// `pext` instruction.
+#[cfg(feature = "alloc")]
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gathers the bits of `self` selected by `mask`, in order, into a new
+	/// bit-vector.
+	///
+	/// The result is as long as the number of `1` bits in the overlapping
+	/// portion of `mask`. [`.deposit()`] is the inverse of this method, and
+	/// [`BitVec::retain_mask`] performs it in place.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 1];
+	/// let mask = bits![u16, Msb0; 1, 1, 0, 0, 1];
+	/// assert_eq!(bits.extract(mask), bits![0, 1, 1]);
+	/// ```
+	///
+	/// [`BitVec::retain_mask`]: crate::vec::BitVec::retain_mask
+	/// [`.deposit()`]: Self::deposit
+	#[inline]
+	pub fn extract<T2, O2>(
+		&self,
+		mask: &BitSlice<T2, O2>,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let limit = cmp::min(self.len(), mask.len());
+		let mut out = BitVec::new();
+		for start in (0 .. limit).step_by(WORD_BITS) {
+			let end = cmp::min(start + WORD_BITS, limit);
+			let (bits, count) = self.extract_word(mask, start .. end);
+			let len = out.len();
+			out.resize(len + count, false);
+			unsafe { out.get_unchecked_mut(len ..) }.store_word(bits);
+		}
+		out
+	}
+
+	/// Gathers the bits selected by `mask` into the front of `self`, in
+	/// order, and returns how many there are.
+	///
+	/// The bits of `self` after the returned count are unspecified. Since no
+	/// word is written before it has been read, this needs no scratch space.
+	pub(crate) fn compress<T2, O2>(&mut self, mask: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let limit = cmp::min(self.len(), mask.len());
+		let mut len = 0;
+		for start in (0 .. limit).step_by(WORD_BITS) {
+			let end = cmp::min(start + WORD_BITS, limit);
+			let (bits, count) = self.extract_word(mask, start .. end);
+			unsafe { self.get_unchecked_mut(len .. len + count) }
+				.store_word(bits);
+			len += count;
+		}
+		len
+	}
+
+	/// Extracts the bits in `range` that `mask` selects into the low bits of
+	/// a word, and counts them.
+	///
+	/// `range` must be no wider than a processor word, and lie within both
+	/// `self` and `mask`.
+	fn extract_word<T2, O2>(
+		&self,
+		mask: &BitSlice<T2, O2>,
+		range: core::ops::Range<usize>,
+	) -> (usize, usize)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mask = unsafe { mask.get_unchecked(range.clone()) }.load_word();
+		let bits = unsafe { self.get_unchecked(range) }.load_word();
+		(pext(bits, mask), mask.count_ones() as usize)
+	}
+}
+
 #[inline]
 #[doc = include_str!("../doc/slice/from_raw_parts_unchecked.md")]
 pub unsafe fn from_raw_parts_unchecked<'a, T, O>(
@@ -1833,3 +2938,30 @@ where
 {
 	ptr.span_unchecked(len).into_bitslice_mut()
 }
+
+/// Loads the first word of a search needle, along with a mask of its live
+/// bits.
+fn search_key<T, O>(needle: &BitSlice<T, O>) -> (usize, usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let width = needle.len().min(WORD_BITS);
+	let mask = if width == WORD_BITS {
+		!0
+	}
+	else {
+		(1 << width) - 1
+	};
+	(unsafe { needle.get_unchecked(.. width) }.load_word(), mask)
+}
+
+/// Extracts the word that begins `shamt` bits into the pair `lo`, `hi`.
+fn shifted(lo: usize, hi: usize, shamt: usize) -> usize {
+	if shamt == 0 {
+		lo
+	}
+	else {
+		lo >> shamt | hi << (WORD_BITS - shamt)
+	}
+}
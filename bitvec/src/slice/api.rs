@@ -1809,10 +1809,9 @@ where
 
 	/// Tests if the bit-slice contains the given sequence anywhere within it.
 	///
-	/// This scans over `self.windows(other.len())` until one of the windows
-	/// matches. The search key does not need to share type parameters with the
-	/// bit-slice being tested, as the comparison is bit-wise. However, sharing
-	/// type parameters will accelerate the comparison.
+	/// This is equivalent to `self.find(other).is_some()`. The search key does
+	/// not need to share type parameters with the bit-slice being tested, as
+	/// the comparison is bit-wise. See [`.find()`] for details of the search.
 	///
 	/// ## Original
 	///
@@ -1827,21 +1826,23 @@ where
 	/// assert!( bits.contains(bits![0, 1, 1, 0]));
 	/// assert!(!bits.contains(bits![1, 0, 0, 1]));
 	/// ```
+	///
+	/// [`.find()`]: Self::find
 	#[inline]
 	pub fn contains<T2, O2>(&self, other: &BitSlice<T2, O2>) -> bool
 	where
 		T2: BitStore,
 		O2: BitOrder,
 	{
-		self.len() >= other.len()
-			&& self.windows(other.len()).any(|window| window == other)
+		self.find(other).is_some()
 	}
 
 	/// Tests if the bit-slice begins with the given sequence.
 	///
 	/// The search key does not need to share type parameters with the bit-slice
-	/// being tested, as the comparison is bit-wise. However, sharing type
-	/// parameters will accelerate the comparison.
+	/// being tested, as the comparison is bit-wise. The comparison proceeds one
+	/// processor word at a time, and is accelerated for the `Lsb0` and `Msb0`
+	/// orderings.
 	///
 	/// ## Original
 	///
@@ -1874,15 +1875,16 @@ where
 		O2: BitOrder,
 	{
 		self.get(.. needle.len())
-			.map(|slice| slice == needle)
+			.map(|slice| slice.word_eq(needle))
 			.unwrap_or(false)
 	}
 
 	/// Tests if the bit-slice ends with the given sequence.
 	///
 	/// The search key does not need to share type parameters with the bit-slice
-	/// being tested, as the comparison is bit-wise. However, sharing type
-	/// parameters will accelerate the comparison.
+	/// being tested, as the comparison is bit-wise. The comparison proceeds one
+	/// processor word at a time, and is accelerated for the `Lsb0` and `Msb0`
+	/// orderings.
 	///
 	/// ## Original
 	///
@@ -1914,8 +1916,9 @@ where
 		T2: BitStore,
 		O2: BitOrder,
 	{
-		self.get(self.len() - needle.len() ..)
-			.map(|slice| slice == needle)
+		self.len()
+			.checked_sub(needle.len())
+			.map(|start| unsafe { self.get_unchecked(start ..) }.word_eq(needle))
 			.unwrap_or(false)
 	}
 
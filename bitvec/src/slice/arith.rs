@@ -0,0 +1,977 @@
+#![doc = include_str!("../../doc/slice/arith.md")]
+
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	ops::Range,
+};
+
+use super::{
+	BitSlice,
+	WORD_BITS,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Fixed-width integer arithmetic.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Adds `rhs` into `self`, treating both as little-endian unsigned
+	/// integers, and reports whether the sum carried out of the top bit.
+	///
+	/// The sum is always written into `self`, wrapping around on overflow.
+	/// Because two’s-complement addition is identical to unsigned addition,
+	/// this also adds signed integers; only the meaning of the returned flag
+	/// differs.
+	///
+	/// ## Original
+	///
+	/// [`u64::overflowing_add`](https://doc.rust-lang.org/std/primitive.u64.html#method.overflowing_add)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// type U256 = BitArray<[u64; 4], Lsb0>;
+	///
+	/// let mut reg = U256::from_u128_le(u128::MAX);
+	/// assert!(!reg.overflowing_add_le(&U256::from_u128_le(1)));
+	/// assert_eq!(reg.first_one(), Some(128));
+	/// assert!(reg.overflowing_add_le(&U256::from_i128_le(-1)));
+	/// assert_eq!(reg.to_u128_le(), Some(u128::MAX));
+	/// ```
+	#[inline]
+	pub fn overflowing_add_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_limbs(rhs, false)
+	}
+
+	/// Adds `rhs` into `self`, treating both as big-endian unsigned integers,
+	/// and reports whether the sum carried out of the top bit.
+	///
+	/// See [`.overflowing_add_le()`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 1, 1, 0];
+	/// assert!(!lhs.overflowing_add_be(bits![0, 1, 1, 1]));
+	/// assert_eq!(lhs, bits![1, 1, 0, 1]);
+	/// assert!(lhs.overflowing_add_be(bits![0, 0, 1, 1]));
+	/// assert_eq!(lhs, bits![0, 0, 0, 0]);
+	/// ```
+	///
+	/// [`.overflowing_add_le()`]: Self::overflowing_add_le
+	#[inline]
+	pub fn overflowing_add_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_limbs(rhs, true)
+	}
+
+	/// Adds `rhs` into `self`, treating both as little-endian integers and
+	/// wrapping around on overflow.
+	///
+	/// ## Original
+	///
+	/// [`u64::wrapping_add`](https://doc.rust-lang.org/std/primitive.u64.html#method.wrapping_add)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 1, 1, 0];
+	/// lhs.wrapping_add_le(bits![1, 1, 1]);
+	/// assert_eq!(lhs, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_add_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_limbs(rhs, false);
+	}
+
+	/// Adds `rhs` into `self`, treating both as big-endian integers and
+	/// wrapping around on overflow.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 1, 1];
+	/// lhs.wrapping_add_be(bits![1, 1, 1]);
+	/// assert_eq!(lhs, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_add_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.add_limbs(rhs, true);
+	}
+
+	/// Subtracts `rhs` from `self`, treating both as little-endian unsigned
+	/// integers, and reports whether the difference borrowed past the top bit.
+	///
+	/// The difference is always written into `self`, wrapping around on
+	/// underflow.
+	///
+	/// ## Original
+	///
+	/// [`u64::overflowing_sub`](https://doc.rust-lang.org/std/primitive.u64.html#method.overflowing_sub)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 1, 0, 0];
+	/// assert!(!lhs.overflowing_sub_le(bits![1, 0, 0, 0]));
+	/// assert_eq!(lhs, bits![1, 0, 0, 0]);
+	/// assert!(lhs.overflowing_sub_le(bits![0, 1, 0, 0]));
+	/// assert_eq!(lhs, bits![1, 1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn overflowing_sub_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.sub_limbs(rhs, false)
+	}
+
+	/// Subtracts `rhs` from `self`, treating both as big-endian unsigned
+	/// integers, and reports whether the difference borrowed past the top bit.
+	///
+	/// See [`.overflowing_sub_le()`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 0, 1, 0];
+	/// assert!(lhs.overflowing_sub_be(bits![0, 0, 1, 1]));
+	/// assert_eq!(lhs, bits![1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`.overflowing_sub_le()`]: Self::overflowing_sub_le
+	#[inline]
+	pub fn overflowing_sub_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.sub_limbs(rhs, true)
+	}
+
+	/// Subtracts `rhs` from `self`, treating both as little-endian integers and
+	/// wrapping around on underflow.
+	///
+	/// ## Original
+	///
+	/// [`u64::wrapping_sub`](https://doc.rust-lang.org/std/primitive.u64.html#method.wrapping_sub)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut reg = BitArray::<[u64; 4], Lsb0>::ZERO;
+	/// reg.wrapping_sub_le(&BitArray::<[u64; 4], Lsb0>::from_u128_le(1));
+	/// assert!(reg.all());
+	/// ```
+	#[inline]
+	pub fn wrapping_sub_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.sub_limbs(rhs, false);
+	}
+
+	/// Subtracts `rhs` from `self`, treating both as big-endian integers and
+	/// wrapping around on underflow.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 0, 1];
+	/// lhs.wrapping_sub_be(bits![0, 1, 0]);
+	/// assert_eq!(lhs, bits![1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_sub_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.sub_limbs(rhs, true);
+	}
+
+	/// Multiplies `self` by `rhs`, treating both as little-endian unsigned
+	/// integers, and reports whether the full product was too wide to fit.
+	///
+	/// The low bits of the product are always written into `self`. As with
+	/// addition, these low bits are also the correct two’s-complement product.
+	///
+	/// ## Original
+	///
+	/// [`u64::overflowing_mul`](https://doc.rust-lang.org/std/primitive.u64.html#method.overflowing_mul)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut reg = bitarr![u64, Lsb0; 0; 256];
+	/// reg.store_u128_le(u64::MAX as u128);
+	/// let copy = reg;
+	/// assert!(!reg.overflowing_mul_le(&copy));
+	/// assert_eq!(reg.to_u128_le(), Some((u64::MAX as u128).pow(2)));
+	/// ```
+	#[inline]
+	pub fn overflowing_mul_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_limbs(rhs, false)
+	}
+
+	/// Multiplies `self` by `rhs`, treating both as big-endian unsigned
+	/// integers, and reports whether the full product was too wide to fit.
+	///
+	/// See [`.overflowing_mul_le()`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 1, 1];
+	/// assert!(lhs.overflowing_mul_be(bits![0, 1, 1]));
+	/// assert_eq!(lhs, bits![0, 0, 1]);
+	/// ```
+	///
+	/// [`.overflowing_mul_le()`]: Self::overflowing_mul_le
+	#[inline]
+	pub fn overflowing_mul_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_limbs(rhs, true)
+	}
+
+	/// Multiplies `self` by `rhs`, treating both as little-endian integers and
+	/// keeping only the low bits of the product.
+	///
+	/// ## Original
+	///
+	/// [`u64::wrapping_mul`](https://doc.rust-lang.org/std/primitive.u64.html#method.wrapping_mul)
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 1, 1, 0];
+	/// lhs.wrapping_mul_le(bits![1, 1, 0]);
+	/// assert_eq!(lhs, bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn wrapping_mul_le<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_limbs(rhs, false);
+	}
+
+	/// Multiplies `self` by `rhs`, treating both as big-endian integers and
+	/// keeping only the low bits of the product.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = bits![mut 0, 1, 1];
+	/// lhs.wrapping_mul_be(bits![0, 1, 1]);
+	/// assert_eq!(lhs, bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn wrapping_mul_be<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.mul_limbs(rhs, true);
+	}
+
+	/// Adds one to `self`, treating it as a little-endian integer, and reports
+	/// whether the increment wrapped around to zero.
+	///
+	/// This only touches as many processor words as the carry reaches.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0];
+	/// assert!(!bits.increment_le());
+	/// assert_eq!(bits, bits![0, 0, 1]);
+	///
+	/// let bits = bits![mut 1; 70];
+	/// assert!(bits.increment_le());
+	/// assert!(bits.not_any());
+	/// ```
+	#[inline]
+	pub fn increment_le(&mut self) -> bool {
+		self.increment_limbs(false)
+	}
+
+	/// Adds one to `self`, treating it as a big-endian integer, and reports
+	/// whether the increment wrapped around to zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1];
+	/// assert!(!bits.increment_be());
+	/// assert_eq!(bits, bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn increment_be(&mut self) -> bool {
+		self.increment_limbs(true)
+	}
+
+	/// Replaces `self` with its two’s-complement negation, treating it as a
+	/// little-endian integer.
+	///
+	/// Like [`i64::wrapping_neg`], the most negative value is its own negation.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 0, 0];
+	/// bits.negate_le();
+	/// assert_eq!(bits, bits![1, 0, 1, 1]);
+	/// assert_eq!(bits.to_i128_le(), Some(-3));
+	/// ```
+	///
+	/// [`i64::wrapping_neg`]: https://doc.rust-lang.org/std/primitive.i64.html#method.wrapping_neg
+	#[inline]
+	pub fn negate_le(&mut self) {
+		(!self).increment_limbs(false);
+	}
+
+	/// Replaces `self` with its two’s-complement negation, treating it as a
+	/// big-endian integer.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1, 1];
+	/// bits.negate_be();
+	/// assert_eq!(bits, bits![1, 1, 0, 1]);
+	/// assert_eq!(bits.to_i128_be(), Some(-3));
+	/// ```
+	#[inline]
+	pub fn negate_be(&mut self) {
+		(!self).increment_limbs(true);
+	}
+
+	/// Compares the numeric values of two equal-length bit-slices, treating
+	/// them as little-endian unsigned integers.
+	///
+	/// This differs from the `Ord` implementation on bit-slices, which is
+	/// lexicographic from the zeroth bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![0, 1, 1];
+	/// assert!(a > b);
+	/// assert_eq!(a.cmp_unsigned_le(b), Ordering::Less);
+	/// ```
+	#[inline]
+	pub fn cmp_unsigned_le<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_limbs(rhs, false, false)
+	}
+
+	/// Compares the numeric values of two equal-length bit-slices, treating
+	/// them as big-endian unsigned integers.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 1];
+	/// assert_eq!(a.cmp_unsigned_be(b), Ordering::Greater);
+	/// ```
+	#[inline]
+	pub fn cmp_unsigned_be<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_limbs(rhs, true, false)
+	}
+
+	/// Compares the numeric values of two equal-length bit-slices, treating
+	/// them as little-endian two’s-complement integers.
+	///
+	/// The sign bit is the last bit of the bit-slice.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![0, 1, 0];
+	/// assert_eq!(a.cmp_unsigned_le(b), Ordering::Greater);
+	/// assert_eq!(a.cmp_signed_le(b), Ordering::Less);
+	/// ```
+	#[inline]
+	pub fn cmp_signed_le<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_limbs(rhs, false, true)
+	}
+
+	/// Compares the numeric values of two equal-length bit-slices, treating
+	/// them as big-endian two’s-complement integers.
+	///
+	/// The sign bit is the zeroth bit of the bit-slice.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self` and `rhs` have different lengths.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let a = bits![1, 0, 1];
+	/// let b = bits![0, 1, 0];
+	/// assert_eq!(a.cmp_signed_be(b), Ordering::Less);
+	/// ```
+	#[inline]
+	pub fn cmp_signed_be<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.cmp_limbs(rhs, true, true)
+	}
+
+	/// Reads `self` as a little-endian unsigned integer.
+	///
+	/// This returns `None` if the value is too large for `u128`. Unlike
+	/// [`BitField::load_le`], the bit-slice may be any length, as long as its
+	/// set bits all lie in the low 128.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut reg = bitarr![u64, Lsb0; 0; 256];
+	/// assert_eq!(reg.to_u128_le(), Some(0));
+	/// reg.set(127, true);
+	/// assert_eq!(reg.to_u128_le(), Some(1 << 127));
+	/// reg.set(128, true);
+	/// assert!(reg.to_u128_le().is_none());
+	/// ```
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn to_u128_le(&self) -> Option<u128> {
+		self.to_u128_limbs(false)
+	}
+
+	/// Reads `self` as a big-endian unsigned integer.
+	///
+	/// This returns `None` if the value is too large for `u128`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 0];
+	/// assert_eq!(bits.to_u128_be(), Some(6));
+	/// ```
+	#[inline]
+	pub fn to_u128_be(&self) -> Option<u128> {
+		self.to_u128_limbs(true)
+	}
+
+	/// Reads `self` as a little-endian two’s-complement integer.
+	///
+	/// The last bit of the bit-slice is its sign. This returns `None` if the
+	/// value is out of range for `i128`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1, 1].to_i128_le(), Some(-2));
+	/// assert_eq!(bits![0, 1, 0].to_i128_le(), Some(2));
+	/// assert_eq!(bits![1; 300].to_i128_le(), Some(-1));
+	///
+	/// let bits = bits![mut 0; 200];
+	/// bits.set(150, true);
+	/// assert!(bits.to_i128_le().is_none());
+	/// ```
+	#[inline]
+	pub fn to_i128_le(&self) -> Option<i128> {
+		self.to_i128_limbs(false)
+	}
+
+	/// Reads `self` as a big-endian two’s-complement integer.
+	///
+	/// The zeroth bit of the bit-slice is its sign. This returns `None` if the
+	/// value is out of range for `i128`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![1, 1, 0].to_i128_be(), Some(-2));
+	/// ```
+	#[inline]
+	pub fn to_i128_be(&self) -> Option<i128> {
+		self.to_i128_limbs(true)
+	}
+
+	/// Writes `value` into `self` as a little-endian unsigned integer.
+	///
+	/// Bits of `value` beyond the bit-slice length are discarded, and bits of
+	/// the bit-slice beyond the width of `u128` are cleared.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1; 200];
+	/// bits.store_u128_le(5);
+	/// assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [0, 2]);
+	/// ```
+	#[inline]
+	pub fn store_u128_le(&mut self, value: u128) {
+		self.store_i128_limbs(value as i128, false, false);
+	}
+
+	/// Writes `value` into `self` as a big-endian unsigned integer.
+	///
+	/// Bits of `value` beyond the bit-slice length are discarded, and bits of
+	/// the bit-slice beyond the width of `u128` are cleared.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 5];
+	/// bits.store_u128_be(6);
+	/// assert_eq!(bits, bits![0, 0, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn store_u128_be(&mut self, value: u128) {
+		self.store_i128_limbs(value as i128, true, false);
+	}
+
+	/// Writes `value` into `self` as a little-endian two’s-complement integer.
+	///
+	/// Bits of `value` beyond the bit-slice length are discarded, and bits of
+	/// the bit-slice beyond the width of `i128` are filled with its sign.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 200];
+	/// bits.store_i128_le(-2);
+	/// assert!(!bits[0]);
+	/// assert!(bits[1 ..].all());
+	/// ```
+	#[inline]
+	pub fn store_i128_le(&mut self, value: i128) {
+		self.store_i128_limbs(value, false, true);
+	}
+
+	/// Writes `value` into `self` as a big-endian two’s-complement integer.
+	///
+	/// Bits of `value` beyond the bit-slice length are discarded, and bits of
+	/// the bit-slice beyond the width of `i128` are filled with its sign.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 4];
+	/// bits.store_i128_be(-3);
+	/// assert_eq!(bits, bits![1, 1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn store_i128_be(&mut self, value: i128) {
+		self.store_i128_limbs(value, true, true);
+	}
+}
+
+/// Limb-wise implementations.
+///
+/// These view the bit-slice as a sequence of processor words, counted from the
+/// least significant end. In little-endian significance the limbs run forward
+/// from the zeroth bit; in big-endian significance they run backward from the
+/// last bit.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the processor words needed to hold the bit-slice.
+	fn limb_count(&self) -> usize {
+		(self.len() + WORD_BITS - 1) / WORD_BITS
+	}
+
+	/// Counts the live bits in a limb. Only the most significant limb can be
+	/// narrower than a processor word.
+	fn limb_width(&self, idx: usize) -> usize {
+		cmp::min(self.len() - idx * WORD_BITS, WORD_BITS)
+	}
+
+	/// Loads a limb, placing its least significant bit at `2^0`.
+	fn load_limb(&self, idx: usize, be: bool) -> usize {
+		let bits =
+			unsafe { self.get_unchecked(limb_range(self.len(), idx, be)) };
+		if be {
+			bits.load_word_rev()
+		}
+		else {
+			bits.load_word()
+		}
+	}
+
+	/// Stores a limb, discarding any bits of `value` above its width.
+	fn store_limb(&mut self, idx: usize, be: bool, value: usize) {
+		let range = limb_range(self.len(), idx, be);
+		let bits = unsafe { self.get_unchecked_mut(range) };
+		if be {
+			bits.store_word_rev(value);
+		}
+		else {
+			bits.store_word(value);
+		}
+	}
+
+	/// Reads the most significant bit.
+	fn sign_bit(&self, be: bool) -> bool {
+		let bit = if be { self.first() } else { self.last() };
+		bit.map_or(false, |bit| *bit)
+	}
+
+	/// Asserts that an arithmetic operand has the same width as `self`.
+	fn assert_same_width<T2, O2>(&self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			self.len(),
+			rhs.len(),
+			"integer arithmetic requires bit-slices of equal length",
+		);
+	}
+
+	/// Adds `rhs` into `self`, returning the carry out of the top limb.
+	fn add_limbs<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>, be: bool) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_same_width(rhs);
+		let mut carry = false;
+		for idx in 0 .. self.limb_count() {
+			let width = self.limb_width(idx);
+			let (sum, c1) = self
+				.load_limb(idx, be)
+				.overflowing_add(rhs.load_limb(idx, be));
+			let (sum, c2) = sum.overflowing_add(carry as usize);
+			carry = c1 | c2 | (width < WORD_BITS && sum >> width != 0);
+			self.store_limb(idx, be, sum);
+		}
+		carry
+	}
+
+	/// Subtracts `rhs` from `self`, returning the borrow out of the top limb.
+	fn sub_limbs<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>, be: bool) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_same_width(rhs);
+		let mut borrow = false;
+		for idx in 0 .. self.limb_count() {
+			let (diff, b1) = self
+				.load_limb(idx, be)
+				.overflowing_sub(rhs.load_limb(idx, be));
+			let (diff, b2) = diff.overflowing_sub(borrow as usize);
+			borrow = b1 | b2;
+			self.store_limb(idx, be, diff);
+		}
+		borrow
+	}
+
+	/// Multiplies `self` by `rhs`, returning whether any bits of the product
+	/// were lost.
+	///
+	/// This works in place by consuming the limbs of `self` from the most
+	/// significant downward. Adding `self[i] * rhs << i` only disturbs limbs at
+	/// or above `i`, which have already been consumed, so the remaining limbs
+	/// of the multiplicand are always intact.
+	fn mul_limbs<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>, be: bool) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_same_width(rhs);
+		let count = self.limb_count();
+		let mut overflow = false;
+		for idx in (0 .. count).rev() {
+			let digit = self.load_limb(idx, be) as u128;
+			self.store_limb(idx, be, 0);
+			if digit == 0 {
+				continue;
+			}
+			let mut carry = 0u128;
+			for (dst, src) in (idx .. count).zip(0 ..) {
+				//  `(2^w - 1) + (2^w - 1)^2 + (2^w - 1)` is exactly `2^2w - 1`.
+				let acc = self.load_limb(dst, be) as u128
+					+ digit * rhs.load_limb(src, be) as u128
+					+ carry;
+				let width = self.limb_width(dst);
+				overflow |= width < WORD_BITS && (acc as usize) >> width != 0;
+				self.store_limb(dst, be, acc as usize);
+				carry = acc >> WORD_BITS;
+			}
+			overflow |= carry != 0
+				|| (count - idx .. count).any(|src| rhs.load_limb(src, be) != 0);
+		}
+		overflow
+	}
+
+	/// Adds one to `self`, returning whether it wrapped to zero.
+	fn increment_limbs(&mut self, be: bool) -> bool {
+		for idx in 0 .. self.limb_count() {
+			let width = self.limb_width(idx);
+			let sum = self.load_limb(idx, be).wrapping_add(1);
+			self.store_limb(idx, be, sum);
+			let carry = if width < WORD_BITS {
+				sum >> width != 0
+			}
+			else {
+				sum == 0
+			};
+			if !carry {
+				return false;
+			}
+		}
+		true
+	}
+
+	/// Compares two bit-slices as integers, from the most significant limb
+	/// downward.
+	fn cmp_limbs<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+		be: bool,
+		signed: bool,
+	) -> Ordering
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_same_width(rhs);
+		if signed {
+			match (self.sign_bit(be), rhs.sign_bit(be)) {
+				(true, false) => return Ordering::Less,
+				(false, true) => return Ordering::Greater,
+				_ => {},
+			}
+		}
+		(0 .. self.limb_count())
+			.rev()
+			.map(|idx| self.load_limb(idx, be).cmp(&rhs.load_limb(idx, be)))
+			.find(|ord| ord.is_ne())
+			.unwrap_or(Ordering::Equal)
+	}
+
+	/// Collects the low 128 bits of `self`, and tests whether every limb
+	/// above them is equal to `ext`.
+	fn low_u128(&self, be: bool, ext: usize) -> (u128, bool) {
+		let mut value = 0u128;
+		let mut fits = true;
+		for idx in 0 .. self.limb_count() {
+			let limb = self.load_limb(idx, be);
+			if idx * WORD_BITS < 128 {
+				value |= (limb as u128) << (idx * WORD_BITS);
+			}
+			else {
+				let mask = !0 >> (WORD_BITS - self.limb_width(idx));
+				fits &= limb == ext & mask;
+			}
+		}
+		(value, fits)
+	}
+
+	/// Reads `self` as an unsigned integer, if it fits in `u128`.
+	fn to_u128_limbs(&self, be: bool) -> Option<u128> {
+		let (value, fits) = self.low_u128(be, 0);
+		fits.then_some(value)
+	}
+
+	/// Reads `self` as a signed integer, if it fits in `i128`.
+	fn to_i128_limbs(&self, be: bool) -> Option<i128> {
+		let len = self.len();
+		let sign = self.sign_bit(be);
+		let (mut value, fits) = self.low_u128(be, if sign { !0 } else { 0 });
+		if len < 128 && sign {
+			value |= !0 << len;
+		}
+		//  When `self` is wider than `i128`, the sign of the low bits must
+		//  agree with the extension above them.
+		let agrees = len <= 128 || (value >> 127 != 0) == sign;
+		(fits && agrees).then_some(value as i128)
+	}
+
+	/// Writes an integer into `self`, extending it with its sign if `signed`
+	/// is set and with zeros otherwise.
+	fn store_i128_limbs(&mut self, value: i128, be: bool, signed: bool) {
+		let ext = if signed && value < 0 { !0 } else { 0 };
+		for idx in 0 .. self.limb_count() {
+			let shamt = idx * WORD_BITS;
+			let limb = if shamt < 128 {
+				(value as u128 >> shamt) as usize
+			}
+			else {
+				ext
+			};
+			self.store_limb(idx, be, limb);
+		}
+	}
+}
+
+/// Selects the bits of a limb in a bit-slice of `len` bits.
+fn limb_range(len: usize, idx: usize, be: bool) -> Range<usize> {
+	let start = idx * WORD_BITS;
+	let end = cmp::min(start + WORD_BITS, len);
+	if be {
+		len - end .. len - start
+	}
+	else {
+		start .. end
+	}
+}
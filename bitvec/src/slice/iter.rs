@@ -13,8 +13,10 @@ use core::{
 	},
 	marker::PhantomData,
 	mem,
+	ops::Range,
 };
 
+use funty::Integral;
 use wyz::comu::{
 	Const,
 	Mut,
@@ -25,8 +27,14 @@ use super::{
 	BitSliceIndex,
 };
 use crate::{
+	field::{
+		self,
+		BitField,
+	},
+	mem::bits_of,
 	order::{
 		BitOrder,
+		Contiguity,
 		Lsb0,
 		Msb0,
 	},
@@ -35,6 +43,7 @@ use crate::{
 		BitRef,
 	},
 	store::BitStore,
+	stream::Endian,
 };
 
 /// [Original](https://doc.rust-lang.org/core/iter/trait.IntoIterator.html#impl-IntoIterator-1)
@@ -1507,6 +1516,22 @@ macro_rules! new_group {
 	)+ };
 }
 
+impl<'a, T, O> ChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Resumes chunking a bit-slice that has already been marked as aliased,
+	/// such as one half of a parallel split.
+	#[cfg(feature = "rayon")]
+	pub(super) fn from_aliased(
+		slice: &'a mut BitSlice<T::Alias, O>,
+		width: usize,
+	) -> Self {
+		Self { slice, width }
+	}
+}
+
 new_group! {
 	Windows,
 	Chunks,
@@ -2362,6 +2387,476 @@ where
 {
 }
 
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/iter/IterRuns.md")]
+pub struct IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The remaining bit-slice whose runs are to be found.
+	inner: &'a BitSlice<T, O>,
+	/// The offset from the front of the original bit-slice to the current
+	/// `.inner`.
+	front: usize,
+}
+
+impl<'a, T, O> IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(slice: &'a BitSlice<T, O>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<T, O> Default for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<T, O> Iterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = (bool, Range<usize>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.first()?;
+		//  The run ends at the first bit of the opposite value. The seeking
+		//  methods are specialized to scan whole elements at a time.
+		let len = if bit {
+			self.inner.first_zero()
+		}
+		else {
+			self.inner.first_one()
+		}
+		.unwrap_or_else(|| self.inner.len());
+
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(len) };
+		self.inner = rest;
+		let start = self.front;
+		self.front += len;
+		Some((bit, start .. self.front))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		((len != 0) as usize, Some(len))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T, O> DoubleEndedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let bit = *self.inner.last()?;
+		let start = if bit {
+			self.inner.last_zero()
+		}
+		else {
+			self.inner.last_one()
+		}
+		.map_or(0, |idx| idx + 1);
+
+		let end = self.front + self.inner.len();
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(start) };
+		self.inner = rest;
+		Some((bit, self.front + start .. end))
+	}
+}
+
+impl<T, O> FusedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc = include_str!("../../doc/slice/iter/IterUints.md")]
+pub struct IterUints<'a, T, O, I>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	/// The whole fields that have not yet been loaded into `buffer`.
+	rest:      &'a BitSlice<T, O>,
+	/// The bits after the last whole field.
+	remainder: &'a BitSlice<T, O>,
+	/// Bits taken from the front of the fields that have not been yielded.
+	buffer:    u128,
+	/// The number of live bits in `buffer`.
+	buffered:  usize,
+	/// The number of bits in each field.
+	width:     usize,
+	/// Selects the `BitField` method used to load fields.
+	endian:    Endian,
+	/// Marker for the type of the yielded fields.
+	_int:      PhantomData<I>,
+}
+
+impl<'a, T, O, I> IterUints<'a, T, O, I>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	I: Integral,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(
+		slice: &'a BitSlice<T, O>,
+		width: usize,
+		endian: Endian,
+	) -> Self {
+		check_field_width::<I>(width);
+		let (rest, remainder) =
+			unsafe { slice.split_at_unchecked(slice.len() / width * width) };
+		Self {
+			rest,
+			remainder,
+			buffer: 0,
+			buffered: 0,
+			width,
+			endian,
+			_int: PhantomData,
+		}
+	}
+
+	/// Gets the bits after the last whole field, which the iterator does not
+	/// yield.
+	///
+	/// ## Original
+	///
+	/// [`ChunksExact::remainder`](core::slice::ChunksExact::remainder)
+	#[inline]
+	pub fn remainder(&self) -> &'a BitSlice<T, O> {
+		self.remainder
+	}
+}
+
+impl<T, O, I> Iterator for IterUints<'_, T, O, I>
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+	BitSlice<T, O>: BitField,
+{
+	type Item = I;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let width = self.width;
+		if !is_bitstream::<O>(width, self.endian) {
+			if self.rest.is_empty() {
+				return None;
+			}
+			let (field, rest) = unsafe { self.rest.split_at_unchecked(width) };
+			self.rest = rest;
+			return Some(match self.endian {
+				Endian::Big => field.load_be(),
+				Endian::Little => field.load_le(),
+			});
+		}
+
+		if self.buffered < width {
+			if self.rest.is_empty() {
+				return None;
+			}
+			//  `buffered` is less than `width`, which is at most 64, so the
+			//  buffer has room for another word.
+			let take = cmp::min(self.rest.len(), 64);
+			let (word, rest) = unsafe { self.rest.split_at_unchecked(take) };
+			self.rest = rest;
+			match self.endian {
+				Endian::Big => {
+					self.buffer =
+						self.buffer << take | word.load_be::<u64>() as u128;
+				},
+				Endian::Little => {
+					self.buffer |=
+						(word.load_le::<u64>() as u128) << self.buffered;
+				},
+			}
+			self.buffered += take;
+		}
+
+		let raw = match self.endian {
+			Endian::Big => self.buffer >> (self.buffered - width),
+			Endian::Little => {
+				let raw = self.buffer;
+				self.buffer >>= width;
+				raw
+			},
+		} as u64 & field_mask(width);
+		self.buffered -= width;
+		Some(field::sign(field::resize(raw), width))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = (self.buffered + self.rest.len()) / self.width;
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+}
+
+impl<T, O, I> ExactSizeIterator for IterUints<'_, T, O, I>
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+	BitSlice<T, O>: BitField,
+{
+}
+
+impl<T, O, I> FusedIterator for IterUints<'_, T, O, I>
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Integral,
+	BitSlice<T, O>: BitField,
+{
+}
+
+/// Panics if `width` is not a usable width for a field loaded into `I`.
+pub(super) fn check_field_width<I>(width: usize)
+where I: Integral {
+	assert!(
+		(1 ..= bits_of::<I>()).contains(&width),
+		"cannot iterate over {}-bit fields of a {}-bit integer",
+		width,
+		bits_of::<I>(),
+	);
+}
+
+/// Tests if `BitField` treats a bit-slice of ordering `O` as a bitstream when
+/// loading `width`-bit fields in `endian` order.
+///
+/// In a bitstream, the significance of each bit of a field depends only on its
+/// distance from the ends of the field, and not on where the field falls
+/// relative to memory elements. Fields can then be cut out of a wider load
+/// that spans several of them. This holds for little-endian loads from
+/// orderings laid out as `Lsb0` is and big-endian loads from orderings laid
+/// out as `Msb0` is, as long as the field fits in a `u64`.
+pub(super) fn is_bitstream<O>(width: usize, endian: Endian) -> bool
+where O: BitOrder {
+	let layout = match endian {
+		Endian::Big => Contiguity::Descending,
+		Endian::Little => Contiguity::Ascending,
+	};
+	width <= 64 && O::CONTIGUITY == layout
+}
+
+/// A mask of the low `width` bits of a `u64`.
+pub(super) fn field_mask(width: usize) -> u64 {
+	!0 >> (64 - width)
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc = include_str!("../../doc/slice/iter/FindIter.md")]
+pub struct FindIter<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The remaining bit-slice to search.
+	inner:  &'a BitSlice<T, O>,
+	/// The bit-sequence being sought.
+	needle: &'a BitSlice<T2, O2>,
+	/// The offset from the front of the original bit-slice to the current
+	/// `.inner`.
+	front:  usize,
+	/// Marks that the search has been exhausted.
+	done:   bool,
+}
+
+impl<'a, T, O, T2, O2> FindIter<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(
+		slice: &'a BitSlice<T, O>,
+		needle: &'a BitSlice<T2, O2>,
+	) -> Self {
+		Self {
+			inner: slice,
+			needle,
+			front: 0,
+			done: false,
+		}
+	}
+}
+
+impl<T, O, T2, O2> Iterator for FindIter<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.inner.find(self.needle) {
+			Some(n) => {
+				let out = self.front + n;
+				//  Resume one bit past the start of this match, unless it was
+				//  an empty match at the very end of the bit-slice.
+				match self.inner.get(n + 1 ..) {
+					Some(rest) => self.inner = rest,
+					None => self.done = true,
+				}
+				self.front = out + 1;
+				Some(out)
+			},
+			None => {
+				self.done = true;
+				None
+			},
+		}
+	}
+}
+
+impl<T, O, T2, O2> FusedIterator for FindIter<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc = include_str!("../../doc/slice/iter/MatchIndices.md")]
+pub struct MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The remaining bit-slice to search.
+	inner:  &'a BitSlice<T, O>,
+	/// The bit-sequence being sought.
+	needle: &'a BitSlice<T2, O2>,
+	/// The offset from the front of the original bit-slice to the current
+	/// `.inner`.
+	front:  usize,
+	/// Marks that the search has been exhausted.
+	done:   bool,
+}
+
+impl<'a, T, O, T2, O2> MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(
+		slice: &'a BitSlice<T, O>,
+		needle: &'a BitSlice<T2, O2>,
+	) -> Self {
+		Self {
+			inner: slice,
+			needle,
+			front: 0,
+			done: false,
+		}
+	}
+}
+
+impl<'a, T, O, T2, O2> Iterator for MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	type Item = (usize, &'a BitSlice<T, O>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let width = self.needle.len();
+		match self.inner.find(self.needle) {
+			Some(n) => {
+				let (_, rest) = unsafe { self.inner.split_at_unchecked(n) };
+				let (found, rest) = unsafe { rest.split_at_unchecked(width) };
+				let out = self.front + n;
+				//  Empty needles must still advance by one bit.
+				let step = if width == 0 { 1 } else { 0 };
+				match rest.get(step ..) {
+					Some(rest) => self.inner = rest,
+					None => self.done = true,
+				}
+				self.front = out + width + step;
+				Some((out, found))
+			},
+			None => {
+				self.done = true;
+				None
+			},
+		}
+	}
+}
+
+impl<T, O, T2, O2> FusedIterator for MatchIndices<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -0,0 +1,213 @@
+#![doc = include_str!("../../doc/slice/morton.md")]
+
+use core::cmp;
+
+use super::{
+	BitSlice,
+	WORD_BITS,
+};
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Interleaves the bits of several bit-slices into one.
+///
+/// Bit `i` of `sources[k]` is written into `dest[i * sources.len() + k]`, so
+/// the first bit of each source comes first, then the second bit of each
+/// source, and so on. With two coordinates, this produces their Morton code.
+///
+/// ## Panics
+///
+/// This panics if `sources` is empty, if the sources do not all have the same
+/// length, or if `dest` is not exactly as long as all of the sources together.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::slice::interleave;
+///
+/// let x = bits![0, 1, 1];
+/// let y = bits![1, 1, 0];
+/// let key = bits![mut 0; 6];
+/// interleave(&[x, y], key);
+/// assert_eq!(key, bits![0, 1, 1, 1, 1, 0]);
+/// ```
+#[inline]
+pub fn interleave<T, O, T2, O2>(
+	sources: &[&BitSlice<T2, O2>],
+	dest: &mut BitSlice<T, O>,
+) where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	let len = interleaved_len(sources.iter().map(|src| src.len()), dest.len());
+	let ways = sources.len();
+	let spread = match ways {
+		2 => spread_2,
+		3 => spread_3,
+		4 => spread_4,
+		_ => {
+			for (idx, mut bit) in dest.iter_mut().enumerate() {
+				*bit = unsafe { *sources[idx % ways].get_unchecked(idx / ways) };
+			}
+			return;
+		},
+	};
+	let width = WORD_BITS / ways;
+	for start in (0 .. len).step_by(width) {
+		let end = cmp::min(start + width, len);
+		let word = sources.iter().enumerate().fold(0, |word, (k, src)| {
+			let bits = unsafe { src.get_unchecked(start .. end) }.load_word();
+			word | spread(bits as u64) << k
+		});
+		unsafe { dest.get_unchecked_mut(start * ways .. end * ways) }
+			.store_word(word as usize);
+	}
+}
+
+/// Splits the bits of one bit-slice among several, reversing [`interleave`].
+///
+/// `dests[k][i]` receives `source[i * dests.len() + k]`.
+///
+/// ## Panics
+///
+/// This panics if `dests` is empty, if the destinations do not all have the
+/// same length, or if `source` is not exactly as long as all of the
+/// destinations together.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::slice::deinterleave;
+///
+/// let key = bits![0, 1, 1, 1, 1, 0];
+/// let (x, y) = (bits![mut 0; 3], bits![mut 0; 3]);
+/// deinterleave(key, &mut [x, y]);
+/// assert_eq!(x, bits![0, 1, 1]);
+/// assert_eq!(y, bits![1, 1, 0]);
+/// ```
+#[inline]
+pub fn deinterleave<T, O, T2, O2>(
+	source: &BitSlice<T, O>,
+	dests: &mut [&mut BitSlice<T2, O2>],
+) where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	let len = interleaved_len(dests.iter().map(|dst| dst.len()), source.len());
+	let ways = dests.len();
+	let compact = match ways {
+		2 => compact_2,
+		3 => compact_3,
+		4 => compact_4,
+		_ => {
+			for (idx, bit) in source.iter().by_vals().enumerate() {
+				unsafe {
+					dests[idx % ways].set_unchecked(idx / ways, bit);
+				}
+			}
+			return;
+		},
+	};
+	let width = WORD_BITS / ways;
+	for start in (0 .. len).step_by(width) {
+		let end = cmp::min(start + width, len);
+		let word = unsafe { source.get_unchecked(start * ways .. end * ways) }
+			.load_word() as u64;
+		for (k, dst) in dests.iter_mut().enumerate() {
+			unsafe { dst.get_unchecked_mut(start .. end) }
+				.store_word(compact(word >> k) as usize);
+		}
+	}
+}
+
+/// Checks that a set of separated bit-slices all have the same length, and
+/// that an interleaved bit-slice has room for exactly all of their bits.
+///
+/// Returns the length of each separated bit-slice.
+fn interleaved_len<I>(mut lens: I, total: usize) -> usize
+where I: ExactSizeIterator<Item = usize> {
+	let ways = lens.len();
+	let len = lens.next().expect("cannot interleave zero bit-slices");
+	assert!(
+		lens.all(|other| other == len),
+		"interleaved bit-slices must have the same length",
+	);
+	assert_eq!(
+		len.checked_mul(ways),
+		Some(total),
+		"interleaved bit-slice length mismatch",
+	);
+	len
+}
+
+/// Spreads the low 32 bits of `x` into the even bits of the result.
+fn spread_2(mut x: u64) -> u64 {
+	x &= 0x0000_0000_FFFF_FFFF;
+	x = (x | x << 16) & 0x0000_FFFF_0000_FFFF;
+	x = (x | x << 8) & 0x00FF_00FF_00FF_00FF;
+	x = (x | x << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+	x = (x | x << 2) & 0x3333_3333_3333_3333;
+	(x | x << 1) & 0x5555_5555_5555_5555
+}
+
+/// Spreads the low 21 bits of `x` into every third bit of the result.
+fn spread_3(mut x: u64) -> u64 {
+	x &= 0x0000_0000_001F_FFFF;
+	x = (x | x << 32) & 0x001F_0000_0000_FFFF;
+	x = (x | x << 16) & 0x001F_0000_FF00_00FF;
+	x = (x | x << 8) & 0x100F_00F0_0F00_F00F;
+	x = (x | x << 4) & 0x10C3_0C30_C30C_30C3;
+	(x | x << 2) & 0x1249_2492_4924_9249
+}
+
+/// Spreads the low 16 bits of `x` into every fourth bit of the result.
+fn spread_4(mut x: u64) -> u64 {
+	x &= 0x0000_0000_0000_FFFF;
+	x = (x | x << 24) & 0x0000_00FF_0000_00FF;
+	x = (x | x << 12) & 0x000F_000F_000F_000F;
+	x = (x | x << 6) & 0x0303_0303_0303_0303;
+	(x | x << 3) & 0x1111_1111_1111_1111
+}
+
+/// Gathers the even bits of `x` into the low 32 bits of the result.
+///
+/// This is the inverse of [`spread_2`].
+fn compact_2(mut x: u64) -> u64 {
+	x &= 0x5555_5555_5555_5555;
+	x = (x | x >> 1) & 0x3333_3333_3333_3333;
+	x = (x | x >> 2) & 0x0F0F_0F0F_0F0F_0F0F;
+	x = (x | x >> 4) & 0x00FF_00FF_00FF_00FF;
+	x = (x | x >> 8) & 0x0000_FFFF_0000_FFFF;
+	(x | x >> 16) & 0x0000_0000_FFFF_FFFF
+}
+
+/// Gathers every third bit of `x` into the low 21 bits of the result.
+///
+/// This is the inverse of [`spread_3`].
+fn compact_3(mut x: u64) -> u64 {
+	x &= 0x1249_2492_4924_9249;
+	x = (x | x >> 2) & 0x10C3_0C30_C30C_30C3;
+	x = (x | x >> 4) & 0x100F_00F0_0F00_F00F;
+	x = (x | x >> 8) & 0x001F_0000_FF00_00FF;
+	x = (x | x >> 16) & 0x001F_0000_0000_FFFF;
+	(x | x >> 32) & 0x0000_0000_001F_FFFF
+}
+
+/// Gathers every fourth bit of `x` into the low 16 bits of the result.
+///
+/// This is the inverse of [`spread_4`].
+fn compact_4(mut x: u64) -> u64 {
+	x &= 0x1111_1111_1111_1111;
+	x = (x | x >> 3) & 0x0303_0303_0303_0303;
+	x = (x | x >> 6) & 0x000F_000F_000F_000F;
+	x = (x | x >> 12) & 0x0000_00FF_0000_00FF;
+	(x | x >> 24) & 0x0000_0000_0000_FFFF
+}
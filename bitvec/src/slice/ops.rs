@@ -16,6 +16,8 @@ use core::ops::{
 	RangeInclusive,
 	RangeTo,
 	RangeToInclusive,
+	ShlAssign,
+	ShrAssign,
 };
 
 use super::{
@@ -42,25 +44,7 @@ where
 	#[inline]
 	#[doc = include_str!("../../doc/slice/bitop_assign.md")]
 	fn bitand_assign(&mut self, rhs: &BitSlice<T2, O2>) {
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Lsb0>(), rhs.coerce::<T1, Lsb0>())
-		{
-			return this.sp_bitop_assign(that, BitAnd::bitand, BitAnd::bitand);
-		}
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Msb0>(), rhs.coerce::<T1, Msb0>())
-		{
-			return this.sp_bitop_assign(that, BitAnd::bitand, BitAnd::bitand);
-		}
-		for (this, that) in self.as_mut_bitptr_range().zip(rhs.as_bitptr_range())
-		{
-			unsafe {
-				this.write(this.read() & that.read());
-			}
-		}
-		if let Some(rem) = self.get_mut(rhs.len() ..) {
-			rem.fill(false);
-		}
+		self.bitop_assign(rhs, BitAnd::bitand, BitAnd::bitand);
 	}
 }
 
@@ -74,22 +58,7 @@ where
 	#[inline]
 	#[doc = include_str!("../../doc/slice/bitop_assign.md")]
 	fn bitor_assign(&mut self, rhs: &BitSlice<T2, O2>) {
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Lsb0>(), rhs.coerce::<T1, Lsb0>())
-		{
-			return this.sp_bitop_assign(that, BitOr::bitor, BitOr::bitor);
-		}
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Msb0>(), rhs.coerce::<T1, Msb0>())
-		{
-			return this.sp_bitop_assign(that, BitOr::bitor, BitOr::bitor);
-		}
-		for (this, that) in self.as_mut_bitptr_range().zip(rhs.as_bitptr_range())
-		{
-			unsafe {
-				this.write(this.read() | that.read());
-			}
-		}
+		self.bitop_assign(rhs, BitOr::bitor, BitOr::bitor);
 	}
 }
 
@@ -103,22 +72,7 @@ where
 	#[inline]
 	#[doc = include_str!("../../doc/slice/bitop_assign.md")]
 	fn bitxor_assign(&mut self, rhs: &BitSlice<T2, O2>) {
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Lsb0>(), rhs.coerce::<T1, Lsb0>())
-		{
-			return this.sp_bitop_assign(that, BitXor::bitxor, BitXor::bitxor);
-		}
-		if let (Some(this), Some(that)) =
-			(self.coerce_mut::<T1, Msb0>(), rhs.coerce::<T1, Msb0>())
-		{
-			return this.sp_bitop_assign(that, BitXor::bitxor, BitXor::bitxor);
-		}
-		for (this, that) in self.as_mut_bitptr_range().zip(rhs.as_bitptr_range())
-		{
-			unsafe {
-				this.write(this.read() ^ that.read());
-			}
-		}
+		self.bitop_assign(rhs, BitXor::bitxor, BitXor::bitxor);
 	}
 }
 
@@ -237,3 +191,129 @@ where
 		self
 	}
 }
+
+/** Shifts the bit-slice contents as `<<=` shifts an integer.
+
+`Lsb0` bit-slices are read as little-endian numbers, with the zeroth bit the
+least significant (as [`BitField::load_le`] reads them). This moves bits away
+from the zero-index, as [`BitSlice::shift_end`], and fills the front with `0`.
+
+## Panics
+
+This panics if the shift amount is greater than the bit-slice length.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits = BitArray::<u16, Lsb0>::new(0x0123);
+bits <<= 4;
+assert_eq!(bits.into_inner(), 0x1230);
+```
+
+[`BitField::load_le`]: crate::field::BitField::load_le
+**/
+impl<T> ShlAssign<usize> for BitSlice<T, Lsb0>
+where T: BitStore
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: usize) {
+		self.shift_end(rhs);
+	}
+}
+
+/** Shifts the bit-slice contents as `<<=` shifts an integer.
+
+`Msb0` bit-slices are read as big-endian numbers, with the zeroth bit the most
+significant (as [`BitField::load_be`] reads them). This moves bits towards the
+zero-index, as [`BitSlice::shift_start`], and fills the back with `0`.
+
+## Panics
+
+This panics if the shift amount is greater than the bit-slice length.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits = BitArray::<u16, Msb0>::new(0x0123);
+bits <<= 4;
+assert_eq!(bits.into_inner(), 0x1230);
+```
+
+[`BitField::load_be`]: crate::field::BitField::load_be
+**/
+impl<T> ShlAssign<usize> for BitSlice<T, Msb0>
+where T: BitStore
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: usize) {
+		self.shift_start(rhs);
+	}
+}
+
+/** Shifts the bit-slice contents as `>>=` shifts an unsigned integer.
+
+`Lsb0` bit-slices are read as little-endian numbers, with the zeroth bit the
+least significant (as [`BitField::load_le`] reads them). This moves bits towards
+the zero-index, as [`BitSlice::shift_start`], and fills the back with `0`. Use
+[`BitSlice::shift_signed_le`] to fill with the sign bit instead.
+
+## Panics
+
+This panics if the shift amount is greater than the bit-slice length.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits = BitArray::<u16, Lsb0>::new(0x8123);
+bits >>= 4;
+assert_eq!(bits.into_inner(), 0x0812);
+```
+
+[`BitField::load_le`]: crate::field::BitField::load_le
+**/
+impl<T> ShrAssign<usize> for BitSlice<T, Lsb0>
+where T: BitStore
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: usize) {
+		self.shift_start(rhs);
+	}
+}
+
+/** Shifts the bit-slice contents as `>>=` shifts an unsigned integer.
+
+`Msb0` bit-slices are read as big-endian numbers, with the zeroth bit the most
+significant (as [`BitField::load_be`] reads them). This moves bits away from the
+zero-index, as [`BitSlice::shift_end`], and fills the front with `0`. Use
+[`BitSlice::shift_signed_be`] to fill with the sign bit instead.
+
+## Panics
+
+This panics if the shift amount is greater than the bit-slice length.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bits = BitArray::<u16, Msb0>::new(0x8123);
+bits >>= 4;
+assert_eq!(bits.into_inner(), 0x0812);
+```
+
+[`BitField::load_be`]: crate::field::BitField::load_be
+**/
+impl<T> ShrAssign<usize> for BitSlice<T, Msb0>
+where T: BitStore
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: usize) {
+		self.shift_end(rhs);
+	}
+}
@@ -0,0 +1,583 @@
+#![doc = include_str!("../../doc/slice/par.md")]
+#![cfg(feature = "rayon")]
+
+use core::{
+	cmp,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+	},
+};
+
+use rayon::{
+	iter::{
+		plumbing::{
+			bridge,
+			bridge_unindexed,
+			Consumer,
+			Folder,
+			Producer,
+			ProducerCallback,
+			UnindexedConsumer,
+			UnindexedProducer,
+		},
+		IndexedParallelIterator,
+		ParallelIterator,
+	},
+	join,
+};
+
+use super::{
+	BitSlice,
+	Chunks,
+	ChunksMut,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Bit-slices shorter than this are processed on a single thread.
+const PAR_MIN_BITS: usize = 1 << 15;
+
+/// Parallel iteration.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Iterates over non-overlapping subdivisions of a bit-slice in parallel.
+	///
+	/// This is the parallel analogue of [`.chunks()`], and yields the same
+	/// bit-slices in the same order.
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1, 0];
+	/// let counts = bits
+	///   .par_chunks(3)
+	///   .map(|chunk| chunk.count_ones())
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(counts, [2, 1, 0]);
+	/// ```
+	///
+	/// [`.chunks()`]: Self::chunks
+	#[inline]
+	pub fn par_chunks(&self, chunk_size: usize) -> ParChunks<T, O>
+	where T: Sync {
+		assert_ne!(chunk_size, 0, "view width cannot be 0");
+		ParChunks {
+			slice: self,
+			width: chunk_size,
+		}
+	}
+
+	/// Iterates over non-overlapping mutable subdivisions of a bit-slice in
+	/// parallel.
+	///
+	/// This is the parallel analogue of [`.chunks_mut()`]. Like it, the
+	/// yielded bit-slices are marked as aliased, since chunk boundaries may
+	/// fall within a memory element. Choosing a `chunk_size` that is a
+	/// multiple of the element width does not change the yielded type, but
+	/// does keep the workers from contending on shared elements.
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![mut 0; 7];
+	/// bits.par_chunks_mut(3)
+	///   .enumerate()
+	///   .for_each(|(idx, chunk)| chunk.set(idx % chunk.len(), true));
+	/// assert_eq!(bits, bits![1, 0, 0, 0, 1, 0, 1]);
+	/// ```
+	///
+	/// [`.chunks_mut()`]: Self::chunks_mut
+	#[inline]
+	pub fn par_chunks_mut(&mut self, chunk_size: usize) -> ParChunksMut<T, O>
+	where T::Alias: Sync {
+		assert_ne!(chunk_size, 0, "view width cannot be 0");
+		ParChunksMut {
+			slice: self.alias_mut(),
+			width: chunk_size,
+		}
+	}
+
+	/// Enumerates the indices of all bits set to `1` in parallel.
+	///
+	/// This is the parallel analogue of [`.iter_ones()`]. Collecting the
+	/// indices preserves their ascending order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let ones = bits.par_iter_ones().collect::<Vec<_>>();
+	/// assert_eq!(ones, [1, 4]);
+	/// ```
+	///
+	/// [`.iter_ones()`]: Self::iter_ones
+	#[inline]
+	pub fn par_iter_ones(&self) -> ParIterOnes<T, O>
+	where T: Sync {
+		ParIterOnes { slice: self }
+	}
+}
+
+/// Parallel bulk operations.
+///
+/// These divide the bit-slice only at memory-element boundaries, so each
+/// worker operates on un-aliased memory and uses the same batched
+/// implementation as the single-threaded method.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Sync,
+	O: BitOrder,
+{
+	/// Counts the number of bits set to `1` in parallel.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bitvec![1; 100_000];
+	/// assert_eq!(bits.par_count_ones(), 100_000);
+	/// ```
+	#[inline]
+	pub fn par_count_ones(&self) -> usize {
+		if self.len() <= PAR_MIN_BITS {
+			return self.count_ones();
+		}
+		let (left, right) = unsafe { self.split_at_unchecked(self.par_mid()) };
+		let (l, r) = join(|| left.par_count_ones(), || right.par_count_ones());
+		l + r
+	}
+
+	/// Counts the number of bits cleared to `0` in parallel.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bitvec![0; 100_000];
+	/// assert_eq!(bits.par_count_zeros(), 100_000);
+	/// ```
+	#[inline]
+	pub fn par_count_zeros(&self) -> usize {
+		self.len() - self.par_count_ones()
+	}
+
+	/// Fills the bit-slice with a given bit in parallel.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitvec![0; 100_000];
+	/// bits[3 ..].par_fill(true);
+	/// assert_eq!(bits.count_zeros(), 3);
+	/// ```
+	#[inline]
+	pub fn par_fill(&mut self, value: bool) {
+		self.par_for_each_split(&|bits| bits.fill(value));
+	}
+
+	/// Applies `&=` in parallel.
+	///
+	/// As with the `&=` operator, `rhs` is zero-extended if it is shorter
+	/// than `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitvec![1; 100_000];
+	/// bits.par_bitand_assign(&bitvec![u8, Msb0; 1; 40_000]);
+	/// assert_eq!(bits.count_ones(), 40_000);
+	/// ```
+	#[inline]
+	pub fn par_bitand_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_bitop_assign(rhs, BitAnd::bitand, BitAnd::bitand);
+	}
+
+	/// Applies `|=` in parallel.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitvec![0; 100_000];
+	/// bits.par_bitor_assign(&bitvec![1; 40_000]);
+	/// assert_eq!(bits.count_ones(), 40_000);
+	/// ```
+	#[inline]
+	pub fn par_bitor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_bitop_assign(rhs, BitOr::bitor, BitOr::bitor);
+	}
+
+	/// Applies `^=` in parallel.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitvec![1; 100_000];
+	/// bits.par_bitxor_assign(&bitvec![1; 40_000]);
+	/// assert_eq!(bits.count_ones(), 60_000);
+	/// ```
+	#[inline]
+	pub fn par_bitxor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_bitop_assign(rhs, BitXor::bitxor, BitXor::bitxor);
+	}
+
+	/// Splits `self` in two at an element boundary near its midpoint, and
+	/// runs `func` on each half in parallel.
+	fn par_for_each_split<F>(&mut self, func: &F)
+	where F: Fn(&mut Self) + Sync {
+		if self.len() <= PAR_MIN_BITS {
+			return func(self);
+		}
+		let mid = self.par_mid();
+		let (left, right) = unsafe { self.split_at_unchecked_mut_noalias(mid) };
+		join(
+			|| left.par_for_each_split(func),
+			|| right.par_for_each_split(func),
+		);
+	}
+
+	/// Recursively divides a pair of bit-slices, applying the batched Boolean
+	/// arithmetic to each part.
+	fn par_bitop_assign<T2, O2>(
+		&mut self,
+		rhs: &BitSlice<T2, O2>,
+		word_op: fn(usize, usize) -> usize,
+		bool_op: fn(bool, bool) -> bool,
+	) where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		if self.len() <= PAR_MIN_BITS {
+			return self.bitop_assign(rhs, word_op, bool_op);
+		}
+		let mid = self.par_mid();
+		let (left, right) = unsafe { self.split_at_unchecked_mut_noalias(mid) };
+		let (rhs_left, rhs_right) =
+			unsafe { rhs.split_at_unchecked(cmp::min(mid, rhs.len())) };
+		join(
+			|| left.par_bitop_assign(rhs_left, word_op, bool_op),
+			|| right.par_bitop_assign(rhs_right, word_op, bool_op),
+		);
+	}
+}
+
+/// Crate internals.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Finds the memory-element boundary nearest below the midpoint of the
+	/// bit-slice.
+	///
+	/// The bit-slice must be at least two elements long, so that the result is
+	/// never `0`.
+	fn par_mid(&self) -> usize {
+		let width = bits_of::<T::Mem>();
+		let head = self.as_bitspan().head().into_inner() as usize;
+		(head + self.len() / 2) / width * width - head
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/slice/par/ParChunks.md")]
+pub struct ParChunks<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a BitSlice<T, O>,
+	/// The width of the produced chunks.
+	width: usize,
+}
+
+impl<'a, T, O> ParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type IntoIter = Chunks<'a, T, O>;
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.chunks(self.width)
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = cmp::min(index * self.width, self.slice.len());
+		let (left, right) = unsafe { self.slice.split_at_unchecked(mid) };
+		(
+			Self {
+				slice: left,
+				width: self.width,
+			},
+			Self {
+				slice: right,
+				width: self.width,
+			},
+		)
+	}
+}
+
+#[derive(Debug)]
+#[doc = include_str!("../../doc/slice/par/ParChunksMut.md")]
+pub struct ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The source bit-slice, marked with the alias tainting.
+	slice: &'a mut BitSlice<T::Alias, O>,
+	/// The width of the produced chunks.
+	width: usize,
+}
+
+impl<'a, T, O> ParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	type Item = &'a mut BitSlice<T::Alias, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	type IntoIter = ChunksMut<'a, T, O>;
+	type Item = &'a mut BitSlice<T::Alias, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		ChunksMut::from_aliased(self.slice, self.width)
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = cmp::min(index * self.width, self.slice.len());
+		let (left, right) =
+			unsafe { self.slice.split_at_unchecked_mut_noalias(mid) };
+		(
+			Self {
+				slice: left,
+				width: self.width,
+			},
+			Self {
+				slice: right,
+				width: self.width,
+			},
+		)
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/slice/par/ParIterOnes.md")]
+pub struct ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a BitSlice<T, O>,
+}
+
+impl<'a, T, O> ParallelIterator for ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge_unindexed(
+			OnesProducer {
+				slice:  self.slice,
+				offset: 0,
+			},
+			consumer,
+		)
+	}
+}
+
+/// Splits a bit-slice at element boundaries for [`ParIterOnes`], remembering
+/// where each part begins in the original bit-slice.
+struct OnesProducer<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The part of the source bit-slice to search.
+	slice:  &'a BitSlice<T, O>,
+	/// The index of `slice` within the source bit-slice.
+	offset: usize,
+}
+
+impl<'a, T, O> UnindexedProducer for OnesProducer<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn split(self) -> (Self, Option<Self>) {
+		if self.slice.len() <= PAR_MIN_BITS {
+			return (self, None);
+		}
+		let mid = self.slice.par_mid();
+		let (left, right) = unsafe { self.slice.split_at_unchecked(mid) };
+		(
+			Self {
+				slice:  left,
+				offset: self.offset,
+			},
+			Some(Self {
+				slice:  right,
+				offset: self.offset + mid,
+			}),
+		)
+	}
+
+	#[inline]
+	fn fold_with<F>(self, folder: F) -> F
+	where F: Folder<Self::Item> {
+		let offset = self.offset;
+		folder.consume_iter(self.slice.iter_ones().map(move |idx| idx + offset))
+	}
+}
+
+/// Counts the chunks of `width` bits needed to cover `len` bits.
+fn chunk_count(len: usize, width: usize) -> usize {
+	len / width + (len % width != 0) as usize
+}
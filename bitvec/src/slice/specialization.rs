@@ -1,12 +1,18 @@
 #![doc = include_str!("../../doc/slice/specialization.md")]
 
+use core::iter;
+
 use funty::Integral;
 
 use super::BitSlice;
 use crate::{
 	devel as dvl,
 	mem,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	store::BitStore,
 };
 
@@ -14,7 +20,7 @@ mod lsb0;
 mod msb0;
 
 /// Processor width, used for chunking.
-const WORD_BITS: usize = mem::bits_of::<usize>();
+pub(crate) const WORD_BITS: usize = mem::bits_of::<usize>();
 
 /// Tests whether the masked portion of an integer has a `0` bit in it.
 fn has_zero<T>(val: T, mask: T) -> bool
@@ -28,6 +34,41 @@ where T: Integral {
 	val & mask != T::ZERO
 }
 
+/// Gathers the bits of `value` selected by `mask` into the low bits of the
+/// result, preserving their relative order.
+///
+/// This is a portable emulation of the x86 BMI2 `pext` instruction. It runs
+/// once per set bit in `mask`.
+#[cfg(feature = "alloc")]
+pub(crate) fn pext(value: usize, mask: usize) -> usize {
+	let (mut out, mut mask, mut dest) = (0, mask, 1usize);
+	while mask != 0 {
+		if value & mask & mask.wrapping_neg() != 0 {
+			out |= dest;
+		}
+		mask &= mask - 1;
+		dest <<= 1;
+	}
+	out
+}
+
+/// Scatters the low bits of `value` into the positions selected by `mask`,
+/// preserving their relative order. Unselected positions are zero.
+///
+/// This is a portable emulation of the x86 BMI2 `pdep` instruction, and the
+/// inverse of [`pext`]. It runs once per set bit in `mask`.
+pub(crate) fn pdep(value: usize, mask: usize) -> usize {
+	let (mut out, mut mask, mut src) = (0, mask, 1usize);
+	while mask != 0 {
+		if value & src != 0 {
+			out |= mask & mask.wrapping_neg();
+		}
+		mask &= mask - 1;
+		src <<= 1;
+	}
+	out
+}
+
 impl<T, O> BitSlice<T, O>
 where
 	T: BitStore,
@@ -78,4 +119,164 @@ where
 			None
 		}
 	}
+
+	/// Loads up to one processor word out of a bit-slice, placing the bit at
+	/// index `n` at numerical significance `2^n` in the returned word.
+	///
+	/// This is specialized to a single `BitField` load for the `Lsb0` and
+	/// `Msb0` orderings, and gathers each bit individually for all others.
+	/// Empty bit-slices produce `0`.
+	///
+	/// The bit-slice must not be wider than a processor word.
+	pub(crate) fn load_word(&self) -> usize {
+		debug_assert!(
+			self.len() <= WORD_BITS,
+			"cannot load {} bits",
+			self.len()
+		);
+		if self.is_empty() {
+			return 0;
+		}
+		if let Some(bits) = self.coerce::<T, Lsb0>() {
+			return bits.sp_load_word();
+		}
+		if let Some(bits) = self.coerce::<T, Msb0>() {
+			return bits.sp_load_word();
+		}
+		self.iter()
+			.by_vals()
+			.enumerate()
+			.fold(0, |word, (idx, bit)| word | (bit as usize) << idx)
+	}
+
+	/// Stores up to one processor word into a bit-slice, writing the bit at
+	/// numerical significance `2^n` in `value` into index `n`. Bits of `value`
+	/// beyond the bit-slice length are discarded.
+	///
+	/// This is the inverse of [`.load_word()`].
+	///
+	/// [`.load_word()`]: Self::load_word
+	pub(crate) fn store_word(&mut self, value: usize) {
+		debug_assert!(
+			self.len() <= WORD_BITS,
+			"cannot store {} bits",
+			self.len()
+		);
+		if self.is_empty() {
+			return;
+		}
+		if let Some(bits) = self.coerce_mut::<T, Lsb0>() {
+			return bits.sp_store_word(value);
+		}
+		if let Some(bits) = self.coerce_mut::<T, Msb0>() {
+			return bits.sp_store_word(value);
+		}
+		for (idx, bit) in self.as_mut_bitptr_range().enumerate() {
+			unsafe {
+				bit.write(value & (1 << idx) != 0);
+			}
+		}
+	}
+
+	/// Loads up to one processor word out of a bit-slice in the reverse
+	/// direction of [`.load_word()`]: the *last* bit of the bit-slice is placed
+	/// at numerical significance `2^0`.
+	///
+	/// [`.load_word()`]: Self::load_word
+	pub(crate) fn load_word_rev(&self) -> usize {
+		debug_assert!(
+			self.len() <= WORD_BITS,
+			"cannot load {} bits",
+			self.len()
+		);
+		if self.is_empty() {
+			return 0;
+		}
+		if let Some(bits) = self.coerce::<T, Lsb0>() {
+			return bits.sp_load_word_rev();
+		}
+		if let Some(bits) = self.coerce::<T, Msb0>() {
+			return bits.sp_load_word_rev();
+		}
+		self.iter()
+			.by_vals()
+			.fold(0, |word, bit| word << 1 | bit as usize)
+	}
+
+	/// Stores up to one processor word into a bit-slice in the reverse
+	/// direction of [`.store_word()`]: the bit at numerical significance `2^0`
+	/// is written into the *last* bit of the bit-slice.
+	///
+	/// [`.store_word()`]: Self::store_word
+	pub(crate) fn store_word_rev(&mut self, value: usize) {
+		debug_assert!(
+			self.len() <= WORD_BITS,
+			"cannot store {} bits",
+			self.len()
+		);
+		if self.is_empty() {
+			return;
+		}
+		if let Some(bits) = self.coerce_mut::<T, Lsb0>() {
+			return bits.sp_store_word_rev(value);
+		}
+		if let Some(bits) = self.coerce_mut::<T, Msb0>() {
+			return bits.sp_store_word_rev(value);
+		}
+		for (idx, bit) in self.as_mut_bitptr_range().rev().enumerate() {
+			unsafe {
+				bit.write(value & (1 << idx) != 0);
+			}
+		}
+	}
+
+	/// Tests if two equal-length bit-slices have the same contents, comparing
+	/// them one processor word at a time.
+	pub(crate) fn word_eq<T2, O2>(&self, other: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.len() == other.len()
+			&& self
+				.chunks(WORD_BITS)
+				.zip(other.chunks(WORD_BITS))
+				.all(|(a, b)| a.load_word() == b.load_word())
+	}
+
+	/// Applies a Boolean-arithmetic function across a pair of bit-slices,
+	/// writing the result into `self`. The secondary bit-slice is
+	/// zero-extended if it expires before `self` does.
+	///
+	/// This dispatches to the batched `sp_bitop_assign` when both bit-slices
+	/// share a storage type and use either `Lsb0` or `Msb0`, and applies
+	/// `bool_op` to each bit individually otherwise.
+	pub(crate) fn bitop_assign<T2, O2>(
+		&mut self,
+		rhs: &BitSlice<T2, O2>,
+		word_op: fn(usize, usize) -> usize,
+		bool_op: fn(bool, bool) -> bool,
+	) where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		if let (Some(this), Some(that)) =
+			(self.coerce_mut::<T, Lsb0>(), rhs.coerce::<T, Lsb0>())
+		{
+			return this.sp_bitop_assign(that, word_op, bool_op);
+		}
+		if let (Some(this), Some(that)) =
+			(self.coerce_mut::<T, Msb0>(), rhs.coerce::<T, Msb0>())
+		{
+			return this.sp_bitop_assign(that, word_op, bool_op);
+		}
+		for (this, that) in self
+			.as_mut_bitptr_range()
+			.zip(rhs.iter().by_vals().chain(iter::repeat(false)))
+		{
+			unsafe {
+				this.write(bool_op(this.read(), that));
+			}
+		}
+	}
 }
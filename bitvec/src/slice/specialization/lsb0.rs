@@ -295,6 +295,120 @@ where T: BitStore
 		}
 	}
 
+	/// Loads up to one processor word, placing the bit at index `n` in the
+	/// bit-slice at numerical significance `2^n` in the returned word.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_load_word(&self) -> usize {
+		self.load_le::<usize>()
+	}
+
+	/// Stores up to one processor word, writing the bit at numerical
+	/// significance `2^n` in `value` into index `n` of the bit-slice.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_store_word(&mut self, value: usize) {
+		self.store_le(value);
+	}
+
+	/// Loads up to one processor word, placing the last bit of the bit-slice
+	/// at numerical significance `2^0` in the returned word.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_load_word_rev(&self) -> usize {
+		self.load_le::<usize>().reverse_bits() >> (WORD_BITS - self.len())
+	}
+
+	/// Stores up to one processor word, writing the bit at numerical
+	/// significance `2^0` in `value` into the last bit of the bit-slice.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_store_word_rev(&mut self, value: usize) {
+		self.store_le(value.reverse_bits() >> (WORD_BITS - self.len()));
+	}
+
+	/// Accelerates shifting the contents toward the front of the bit-slice.
+	///
+	/// When the bit-slice exactly covers its memory elements, this shifts the
+	/// elements in place rather than copying bits. In `Lsb0` order, moving
+	/// toward the front of the bit-slice is a numerical right-shift of each
+	/// element, carrying in from its successor. Partially-occupied regions use
+	/// the batched copy.
+	///
+	/// `by` must be in `1 .. self.len()`.
+	pub(crate) fn sp_shift_start(&mut self, by: usize) {
+		let len = self.len();
+		if let Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.domain_mut()
+		{
+			let width = bits_of::<T::Mem>();
+			let zero = <T::Mem as Integral>::ZERO;
+			let (skip, shift) = (by / width, by % width);
+			for idx in 0 .. body.len() {
+				let this =
+					body.get(idx + skip).map_or(zero, BitStore::load_value);
+				let next =
+					body.get(idx + skip + 1).map_or(zero, BitStore::load_value);
+				body[idx].store_value(if shift == 0 {
+					this
+				}
+				else {
+					(this >> shift) | (next << (width - shift))
+				});
+			}
+			return;
+		}
+		unsafe {
+			self.sp_copy_within_unchecked(by .., 0);
+			self.get_unchecked_mut(len - by ..).fill(false);
+		}
+	}
+
+	/// Accelerates shifting the contents toward the back of the bit-slice.
+	///
+	/// This is the mirror of [`.sp_shift_start()`]. In `Lsb0` order, moving
+	/// toward the back of the bit-slice is a numerical left-shift of each
+	/// element, carrying in from its predecessor.
+	///
+	/// `by` must be in `1 .. self.len()`.
+	///
+	/// [`.sp_shift_start()`]: Self::sp_shift_start
+	pub(crate) fn sp_shift_end(&mut self, by: usize) {
+		let len = self.len();
+		if let Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.domain_mut()
+		{
+			let width = bits_of::<T::Mem>();
+			let zero = <T::Mem as Integral>::ZERO;
+			let (skip, shift) = (by / width, by % width);
+			for idx in (0 .. body.len()).rev() {
+				let this = idx
+					.checked_sub(skip)
+					.map_or(zero, |src| body[src].load_value());
+				let prev = idx
+					.checked_sub(skip + 1)
+					.map_or(zero, |src| body[src].load_value());
+				body[idx].store_value(if shift == 0 {
+					this
+				}
+				else {
+					(this << shift) | (prev >> (width - shift))
+				});
+			}
+			return;
+		}
+		unsafe {
+			self.sp_copy_within_unchecked(.. len - by, by);
+			self.get_unchecked_mut(.. by).fill(false);
+		}
+	}
+
 	/// Accelerates swapping memory.
 	pub(crate) fn sp_swap_with_bitslice(&mut self, other: &mut Self) {
 		for (this, that) in unsafe {
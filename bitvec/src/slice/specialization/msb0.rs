@@ -158,7 +158,7 @@ where T: BitStore
 
 	/// Seeks the index of the last `1` bit in the bit-slice.
 	pub(crate) fn sp_last_one(&self) -> Option<usize> {
-		let mut out = self.len().checked_sub(1)?;
+		let mut out = self.len();
 		match self.domain() {
 			Domain::Enclave(elem) => {
 				let val = elem.load_value();
@@ -166,7 +166,7 @@ where T: BitStore
 					bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 				if has_one(val, elem.mask().into_inner()) {
 					out -= val.trailing_zeros() as usize - dead_bits;
-					return Some(out);
+					return Some(out - 1);
 				}
 				None
 			},
@@ -177,14 +177,14 @@ where T: BitStore
 						bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 					out -= val.trailing_zeros() as usize - dead_bits;
 					if has_one(val, elem.mask().into_inner()) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
 				for val in body.iter().map(BitStore::load_value).rev() {
 					out -= val.trailing_zeros() as usize;
 					if has_one(val, !<T::Mem as Integral>::ZERO) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -192,7 +192,7 @@ where T: BitStore
 					let val = elem.load_value();
 					if has_one(val, elem.mask().into_inner()) {
 						out -= val.trailing_zeros() as usize;
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -247,7 +247,7 @@ where T: BitStore
 
 	/// Seeks the index of the last `0` bit in the bit-slice.
 	pub(crate) fn sp_last_zero(&self) -> Option<usize> {
-		let mut out = self.len().checked_sub(1)?;
+		let mut out = self.len();
 		match self.domain() {
 			Domain::Enclave(elem) => {
 				let val = elem.load_value() | !elem.mask().into_inner();
@@ -255,7 +255,7 @@ where T: BitStore
 					bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 				if has_zero(val, elem.mask().into_inner()) {
 					out -= val.trailing_ones() as usize - dead_bits;
-					return Some(out);
+					return Some(out - 1);
 				}
 				None
 			},
@@ -266,14 +266,14 @@ where T: BitStore
 						bits_of::<T::Mem>() - elem.tail().into_inner() as usize;
 					out -= val.trailing_ones() as usize - dead_bits;
 					if has_zero(val, elem.mask().into_inner()) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
 				for val in body.iter().map(BitStore::load_value).rev() {
 					out -= val.trailing_ones() as usize;
 					if has_zero(val, !<T::Mem as Integral>::ZERO) {
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -281,7 +281,7 @@ where T: BitStore
 					let val = elem.load_value() | !elem.mask().into_inner();
 					if has_zero(val, elem.mask().into_inner()) {
 						out -= val.trailing_ones() as usize;
-						return Some(out);
+						return Some(out - 1);
 					}
 				}
 
@@ -290,6 +290,122 @@ where T: BitStore
 		}
 	}
 
+	/// Loads up to one processor word, placing the bit at index `n` in the
+	/// bit-slice at numerical significance `2^n` in the returned word.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_load_word(&self) -> usize {
+		//  `load_be` places the zeroth bit in the most significant live
+		// position.
+		self.load_be::<usize>().reverse_bits() >> (WORD_BITS - self.len())
+	}
+
+	/// Stores up to one processor word, writing the bit at numerical
+	/// significance `2^n` in `value` into index `n` of the bit-slice.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_store_word(&mut self, value: usize) {
+		self.store_be(value.reverse_bits() >> (WORD_BITS - self.len()));
+	}
+
+	/// Loads up to one processor word, placing the last bit of the bit-slice
+	/// at numerical significance `2^0` in the returned word.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_load_word_rev(&self) -> usize {
+		self.load_be::<usize>()
+	}
+
+	/// Stores up to one processor word, writing the bit at numerical
+	/// significance `2^0` in `value` into the last bit of the bit-slice.
+	///
+	/// The bit-slice must not be empty or wider than a processor word.
+	pub(crate) fn sp_store_word_rev(&mut self, value: usize) {
+		self.store_be(value);
+	}
+
+	/// Accelerates shifting the contents toward the front of the bit-slice.
+	///
+	/// When the bit-slice exactly covers its memory elements, this shifts the
+	/// elements in place rather than copying bits. In `Msb0` order, moving
+	/// toward the front of the bit-slice is a numerical left-shift of each
+	/// element, carrying in from its successor. Partially-occupied regions use
+	/// the batched copy.
+	///
+	/// `by` must be in `1 .. self.len()`.
+	pub(crate) fn sp_shift_start(&mut self, by: usize) {
+		let len = self.len();
+		if let Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.domain_mut()
+		{
+			let width = bits_of::<T::Mem>();
+			let zero = <T::Mem as Integral>::ZERO;
+			let (skip, shift) = (by / width, by % width);
+			for idx in 0 .. body.len() {
+				let this =
+					body.get(idx + skip).map_or(zero, BitStore::load_value);
+				let next =
+					body.get(idx + skip + 1).map_or(zero, BitStore::load_value);
+				body[idx].store_value(if shift == 0 {
+					this
+				}
+				else {
+					(this << shift) | (next >> (width - shift))
+				});
+			}
+			return;
+		}
+		unsafe {
+			self.sp_copy_within_unchecked(by .., 0);
+			self.get_unchecked_mut(len - by ..).fill(false);
+		}
+	}
+
+	/// Accelerates shifting the contents toward the back of the bit-slice.
+	///
+	/// This is the mirror of [`.sp_shift_start()`]. In `Msb0` order, moving
+	/// toward the back of the bit-slice is a numerical right-shift of each
+	/// element, carrying in from its predecessor.
+	///
+	/// `by` must be in `1 .. self.len()`.
+	///
+	/// [`.sp_shift_start()`]: Self::sp_shift_start
+	pub(crate) fn sp_shift_end(&mut self, by: usize) {
+		let len = self.len();
+		if let Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} = self.domain_mut()
+		{
+			let width = bits_of::<T::Mem>();
+			let zero = <T::Mem as Integral>::ZERO;
+			let (skip, shift) = (by / width, by % width);
+			for idx in (0 .. body.len()).rev() {
+				let this = idx
+					.checked_sub(skip)
+					.map_or(zero, |src| body[src].load_value());
+				let prev = idx
+					.checked_sub(skip + 1)
+					.map_or(zero, |src| body[src].load_value());
+				body[idx].store_value(if shift == 0 {
+					this
+				}
+				else {
+					(this >> shift) | (prev << (width - shift))
+				});
+			}
+			return;
+		}
+		unsafe {
+			self.sp_copy_within_unchecked(.. len - by, by);
+			self.get_unchecked_mut(.. by).fill(false);
+		}
+	}
+
 	/// Accelerates swapping memory.
 	pub(crate) fn sp_swap_with_bitslice(&mut self, other: &mut Self) {
 		for (this, that) in unsafe {
@@ -7,13 +7,22 @@ use core::cell::Cell;
 use rand::random;
 
 use crate::{
-	order::HiLo,
+	order::{
+		BitOrder,
+		HiLo,
+	},
 	prelude::*,
+	slice::{
+		deinterleave,
+		interleave,
+	},
 };
 
 mod api;
+mod arith;
 mod iter;
 mod ops;
+mod par;
 mod traits;
 
 #[test]
@@ -213,6 +222,175 @@ fn bit_counting() {
 	assert_eq!(15u8.view_bits::<Msb0>().trailing_zeros(), 0);
 }
 
+#[test]
+fn msb0_last_search() {
+	assert!(bits![u8, Msb0; 0; 3].last_one().is_none());
+	assert!(bits![u8, Msb0; 0; 16].last_one().is_none());
+	assert!(bits![u8, Msb0; 0; 30].last_one().is_none());
+	assert!(bits![u8, Msb0; 0; 30][3 ..].last_one().is_none());
+	assert!(bits![u8, Msb0; 1; 3].last_zero().is_none());
+	assert!(bits![u8, Msb0; 1; 16].last_zero().is_none());
+	assert!(bits![u8, Msb0; 1; 30].last_zero().is_none());
+	assert!(bits![u8, Msb0; 1; 30][3 ..].last_zero().is_none());
+
+	let bits = bits![mut u16, Msb0; 0; 40];
+	for idx in [0, 5, 15, 16, 31, 32, 39] {
+		bits.set(idx, true);
+		assert_eq!(bits.last_one(), Some(idx));
+		assert_eq!(bits[1 ..].last_one(), idx.checked_sub(1));
+		bits.set(idx, false);
+	}
+
+	bits.fill(true);
+	for idx in [0, 5, 15, 16, 31, 32, 39] {
+		bits.set(idx, false);
+		assert_eq!(bits.last_zero(), Some(idx));
+		assert_eq!(bits[1 ..].last_zero(), idx.checked_sub(1));
+		bits.set(idx, true);
+	}
+}
+
+#[test]
+fn similarity() {
+	fn check(x: &BitSlice<u32, Lsb0>, y: &BitSlice<u8, Msb0>) {
+		let pairs = || {
+			(0 .. x.len().max(y.len())).map(|idx| {
+				(
+					x.get(idx).map_or(false, |b| *b),
+					y.get(idx).map_or(false, |b| *b),
+				)
+			})
+		};
+		let and = pairs().filter(|&(l, r)| l & r).count();
+		let or = pairs().filter(|&(l, r)| l | r).count();
+		assert_eq!(x.intersection_count(y), and);
+		assert_eq!(x.union_count(y), or);
+		assert_eq!(x.hamming_distance(y), or - and);
+		assert_eq!(y.hamming_distance(x), or - and);
+		if or != 0 {
+			assert_eq!(x.jaccard(y), and as f64 / or as f64);
+		}
+	}
+
+	let a = random::<[u32; 5]>();
+	let b = random::<[u32; 5]>();
+	let c = random::<[u8; 20]>();
+	let x = a.view_bits::<Lsb0>();
+	let y = b.view_bits::<Lsb0>();
+	let z = c.view_bits::<Msb0>();
+	for (start, end) in [(0, 160), (3, 150), (7, 9), (32, 128), (5, 5)] {
+		//  Matching domains pair up their elements.
+		let (l, r) = (&x[start .. end], &y[start .. end]);
+		let and = l.iter().zip(r).filter(|(l, r)| **l & **r).count();
+		let or = l.iter().zip(r).filter(|(l, r)| **l | **r).count();
+		assert_eq!(l.intersection_count(r), and);
+		assert_eq!(l.union_count(r), or);
+		assert_eq!(l.hamming_distance(r), or - and);
+
+		//  Misaligned starts use shifted word loads.
+		let r = &y[start / 2 ..][.. end - start];
+		let diff = l.iter().zip(r).filter(|(l, r)| l != r).count();
+		assert_eq!(l.hamming_distance(r), diff);
+
+		//  Differing types and lengths.
+		check(l, &z[start ..][.. end - start]);
+		check(l, &z[1 ..]);
+	}
+
+	assert_eq!(bits![].jaccard(bits![]), 1.0);
+	assert_eq!(bits![1, 1].tanimoto(bits![1, 0, 0, 1]), 1.0 / 3.0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn extract_deposit() {
+	fn check<O>(bits: &BitSlice<u16, O>, mask: &BitSlice<u8, Msb0>)
+	where O: BitOrder {
+		let selected = bits
+			.iter()
+			.by_vals()
+			.zip(mask.iter().by_vals())
+			.filter_map(|(bit, sel)| sel.then_some(bit))
+			.collect::<Vec<_>>();
+		let out = bits.extract(mask);
+		assert!(out.iter().by_vals().eq(selected.iter().copied()));
+
+		let mut dest = BitVec::<u32, Lsb0>::repeat(true, bits.len());
+		dest.deposit(mask, &out);
+		for (idx, bit) in dest.iter().by_vals().enumerate() {
+			let sel = mask.get(idx).map_or(false, |b| *b);
+			assert_eq!(bit, if sel { bits[idx] } else { true });
+		}
+
+		let mut bv = bits.to_bitvec();
+		bv.retain_mask(mask);
+		assert_eq!(bv, out);
+	}
+
+	let a = random::<[u16; 12]>();
+	let m = random::<[u8; 30]>();
+	let mask = m.view_bits::<Msb0>();
+	for (start, end) in [(0, 192), (3, 150), (7, 9), (64, 128), (5, 5)] {
+		check(&a.view_bits::<Lsb0>()[start .. end], &mask[start ..]);
+		check(
+			&a.view_bits::<Msb0>()[start .. end],
+			&mask[1 ..][.. end / 2],
+		);
+		check(&a.view_bits::<HiLo>()[start .. end], &mask[.. 3]);
+	}
+
+	let bits = bits![mut 1; 6];
+	bits.deposit(bits![0, 1, 1, 0, 1, 1], bits![1, 0]);
+	assert_eq!(bits, bits![1, 1, 0, 1, 0, 0]);
+	assert!(bits.extract(bits![]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn morton() {
+	fn check<O, O2>(data: &mut [u64; 5], ways: usize, len: usize)
+	where
+		O: BitOrder,
+		O2: BitOrder,
+	{
+		let coords = random::<[u16; 30]>();
+		let coords = coords.view_bits::<O2>();
+		let sources = (0 .. ways)
+			.map(|k| &coords[k * 80 + 3 ..][.. len])
+			.collect::<Vec<_>>();
+		let dest = &mut data.view_bits_mut::<O>()[1 ..][.. ways * len];
+		interleave(&sources, dest);
+		for (idx, bit) in dest.iter().by_vals().enumerate() {
+			assert_eq!(bit, sources[idx % ways][idx / ways]);
+		}
+
+		let mut out = random::<[u32; 30]>();
+		let mut parts = out
+			.view_bits_mut::<Lsb0>()
+			.chunks_exact_mut(len)
+			.take(ways)
+			.collect::<Vec<_>>();
+		deinterleave(dest, &mut parts);
+		for (part, src) in parts.iter().zip(&sources) {
+			assert_eq!(part, src);
+		}
+	}
+
+	let mut data = [0u64; 5];
+	for ways in 1 ..= 6 {
+		for len in [1, 7, 21, 32, 50] {
+			check::<Lsb0, Lsb0>(&mut data, ways, len);
+			check::<Msb0, Lsb0>(&mut data, ways, len);
+			check::<Lsb0, Msb0>(&mut data, ways, len);
+			check::<HiLo, Msb0>(&mut data, ways, len);
+		}
+	}
+
+	let empty = BitSlice::<u8, Lsb0>::empty_mut();
+	interleave(&[bits![], bits![]], empty);
+	deinterleave(bits![], &mut [empty]);
+}
+
 #[test]
 fn shunting() {
 	let bits = bits![mut 0, 1, 0, 0, 1];
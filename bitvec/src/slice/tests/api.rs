@@ -1,6 +1,11 @@
 #![cfg(test)]
 
-use crate::prelude::*;
+use rand::random;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
 
 #[test]
 fn properties() {
@@ -136,4 +141,70 @@ fn inspectors() {
 
 	assert!(bits.ends_with(bits![1, 0, 1]));
 	assert!(!bits.ends_with(bits![0, 0, 1]));
+	assert!(!bits![0].ends_with(bits![0, 0]));
+}
+
+#[test]
+fn searchers() {
+	let bits = bits![0, 1, 0, 0, 1, 0, 1, 1, 0, 1];
+	assert_eq!(bits.find(bits![]), Some(0));
+	assert_eq!(bits.rfind(bits![]), Some(10));
+	assert_eq!(bits.find(bits![1, 0, 1]), Some(4));
+	assert_eq!(bits.rfind(bits![1, 0, 1]), Some(7));
+	assert!(bits.find(bits![1; 3]).is_none());
+	assert!(bits.rfind(bits![0; 11]).is_none());
+
+	assert_eq!(bits.find_iter(bits![1, 0, 1]).collect::<Vec<_>>(), [4, 7]);
+	assert_eq!(bits![0; 3].find_iter(bits![]).count(), 4);
+	assert_eq!(
+		bits.match_indices(bits![0, 1])
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>(),
+		[0, 3, 5, 8],
+	);
+	assert_eq!(bits![0; 5].match_indices(bits![0, 0]).count(), 2);
+
+	let data = random::<[u64; 6]>();
+	let needle_data = random::<[u32; 4]>();
+	let hay = &data.view_bits::<Lsb0>()[3 .. 380];
+	for (start, width) in [(0, 1), (5, 7), (17, 64), (33, 77)] {
+		let needle = &hay[start .. start + width];
+		let naive = hay.windows(width).position(|w| w == needle);
+		let rnaive = hay.windows(width).rposition(|w| w == needle);
+		assert_eq!(hay.find(needle), naive);
+		assert_eq!(hay.rfind(needle), rnaive);
+
+		let mut other = bitvec![u8, Msb0; 0; width];
+		other.clone_from_bitslice(needle);
+		assert_eq!(hay.find(&other), naive);
+		assert_eq!(hay.rfind(&other), rnaive);
+
+		let mut other = bitvec![u16, HiLo; 0; width];
+		other.clone_from_bitslice(needle);
+		assert_eq!(hay.find(&other), naive);
+		assert_eq!(hay.rfind(&other), rnaive);
+
+		let needle = &needle_data.view_bits::<Msb0>()[.. width];
+		assert_eq!(
+			hay.find_iter(needle).collect::<Vec<_>>(),
+			hay.windows(width)
+				.enumerate()
+				.filter(|(_, w)| *w == needle)
+				.map(|(idx, _)| idx)
+				.collect::<Vec<_>>(),
+		);
+	}
+
+	let hay = &data.view_bits::<Msb0>()[9 .. 301];
+	for (start, width) in [(2, 3), (40, 64), (70, 100)] {
+		let needle = hay[start .. start + width].to_bitvec();
+		assert_eq!(
+			hay.find(&needle),
+			hay.windows(width).position(|w| w == needle),
+		);
+		assert_eq!(
+			hay.rfind(&needle),
+			hay.windows(width).rposition(|w| w == needle),
+		);
+	}
 }
@@ -0,0 +1,183 @@
+#![cfg(test)]
+
+use core::cmp::Ordering;
+
+use rand::random;
+
+use crate::{
+	order::{
+		BitOrder,
+		HiLo,
+	},
+	prelude::*,
+};
+
+/// Runs the arithmetic suite against `u128` arithmetic, for one storage
+/// layout and significance order.
+fn check<O>(be: bool)
+where O: BitOrder {
+	for width in [1, 7, 31, 63, 64, 65, 100, 127, 128] {
+		let mask = !0u128 >> (128 - width);
+		let (a, b) = (random::<u128>() & mask, random::<u128>() & mask);
+		let mut lhs = bitvec![u16, O; 0; width + 5];
+		let mut rhs = bitvec![u8, Lsb0; 0; width + 3];
+		let (l, r) = (&mut lhs[5 ..], &mut rhs[3 ..]);
+		let load = |bits: &BitSlice<u16, O>| {
+			if be {
+				bits.to_u128_be()
+			}
+			else {
+				bits.to_u128_le()
+			}
+			.unwrap()
+		};
+		let reset = |bits: &mut BitSlice<u16, O>| {
+			if be {
+				bits.store_u128_be(a)
+			}
+			else {
+				bits.store_u128_le(a)
+			}
+		};
+		if be {
+			r.store_u128_be(b);
+		}
+		else {
+			r.store_u128_le(b);
+		}
+		reset(l);
+		assert_eq!(load(l), a);
+
+		let sum = a.wrapping_add(b);
+		let carry = if be {
+			l.overflowing_add_be(r)
+		}
+		else {
+			l.overflowing_add_le(r)
+		};
+		assert_eq!(load(l), sum & mask);
+		assert_eq!(carry, sum & mask != sum || sum < a);
+
+		reset(l);
+		let borrow = if be {
+			l.overflowing_sub_be(r)
+		}
+		else {
+			l.overflowing_sub_le(r)
+		};
+		assert_eq!(load(l), a.wrapping_sub(b) & mask);
+		assert_eq!(borrow, b > a);
+
+		reset(l);
+		let overflow = if be {
+			l.overflowing_mul_be(r)
+		}
+		else {
+			l.overflowing_mul_le(r)
+		};
+		let (prod, wide) = a.overflowing_mul(b);
+		assert_eq!(load(l), prod & mask);
+		assert_eq!(overflow, wide || prod & mask != prod);
+
+		reset(l);
+		let ord = if be {
+			l.cmp_unsigned_be(r)
+		}
+		else {
+			l.cmp_unsigned_le(r)
+		};
+		assert_eq!(ord, a.cmp(&b));
+
+		let wrapped = if be {
+			l.increment_be()
+		}
+		else {
+			l.increment_le()
+		};
+		assert_eq!(wrapped, a == mask);
+		assert_eq!(load(l), a.wrapping_add(1) & mask);
+
+		reset(l);
+		if be {
+			l.negate_be()
+		}
+		else {
+			l.negate_le()
+		};
+		assert_eq!(load(l), a.wrapping_neg() & mask);
+	}
+}
+
+#[test]
+fn unsigned() {
+	check::<Lsb0>(false);
+	check::<Lsb0>(true);
+	check::<Msb0>(false);
+	check::<Msb0>(true);
+	check::<HiLo>(false);
+	check::<HiLo>(true);
+}
+
+#[test]
+fn signed() {
+	let bits = bits![mut u32, Lsb0; 0; 200];
+	let other = bits![mut u8, Msb0; 0; 200];
+	for (a, b) in [(-5i128, 3i128), (7, -7), (i128::MIN, i128::MAX), (-1, -2)] {
+		bits.store_i128_le(a);
+		other.store_i128_le(b);
+		assert_eq!(bits.to_i128_le(), Some(a));
+		assert_eq!(other.to_i128_le(), Some(b));
+		assert_eq!(bits.cmp_signed_le(other), a.cmp(&b));
+		assert_eq!(bits[.. 128].to_i128_le(), Some(a));
+
+		bits.wrapping_add_le(other);
+		assert_eq!(bits.to_i128_le(), a.checked_add(b));
+		bits.store_i128_le(a);
+		bits.wrapping_mul_le(other);
+		assert_eq!(bits.to_i128_le(), a.checked_mul(b));
+	}
+
+	bits.store_i128_be(i128::MIN);
+	assert!(bits[.. 72].all());
+	assert!(bits[73 ..].not_any());
+	assert_eq!(bits.to_i128_be(), Some(i128::MIN));
+	bits.negate_be();
+	assert!(bits.to_i128_be().is_none());
+	assert_eq!(bits.to_u128_be(), Some(1 << 127));
+
+	let empty = bits![mut];
+	assert!(empty.increment_le());
+	assert_eq!(empty.to_i128_be(), Some(0));
+	assert_eq!(empty.cmp_signed_le(bits![]), Ordering::Equal);
+}
+
+#[test]
+fn wide() {
+	type U256 = BitArray<[u64; 4], Lsb0>;
+
+	let a = U256::from_u128_le(random::<u64>() as u128 + 1);
+	let b = U256::from_u128_le(random::<u128>() | 1 << 127);
+	let prod = a.checked_mul_le(&b).unwrap();
+	let mut quot = U256::ZERO;
+	let mut rest = prod;
+	//  Repeated subtraction of `b << k` recovers `a` as the quotient.
+	for shift in (0 .. 128).rev() {
+		let mut step = b;
+		step.shift_end(shift);
+		if let Some(next) = rest.checked_sub_le(&step) {
+			rest = next;
+			quot.set(shift, true);
+		}
+	}
+	assert!(rest.not_any());
+	assert_eq!(quot, a);
+
+	assert!(prod.checked_sub_le(&U256::from_u128_le(1)).is_some());
+	assert!(U256::from_i128_le(-1).checked_add_le(&a).is_none());
+	assert!(a.checked_sub_le(&b).is_none());
+	assert_eq!(a.cmp_unsigned_le(&b), Ordering::Less);
+
+	let reg = BitArray::<[u8; 3], Msb0>::from_u128_be(0x12_3456);
+	assert_eq!(reg.into_inner(), [0x12, 0x34, 0x56]);
+	assert_eq!(reg.load_be::<u32>(), 0x12_3456);
+}
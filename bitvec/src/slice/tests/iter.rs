@@ -1,6 +1,9 @@
 #![cfg(test)]
 
-use crate::prelude::*;
+use crate::{
+	field::BitField,
+	prelude::*,
+};
 
 #[test]
 fn iter() {
@@ -677,6 +680,134 @@ fn iter_zeros() {
 	assert!(zeros.next().is_none());
 }
 
+#[test]
+fn iter_runs() {
+	use crate::order::HiLo;
+
+	let bits = 0b0011_0110u8.view_bits::<HiLo>();
+	// ordering: 3210 7654
+	let mut runs = bits.iter_runs();
+	assert_eq!(runs.next(), Some((true, 0 .. 2)));
+	assert_eq!(runs.next_back(), Some((false, 7 .. 8)));
+	assert_eq!(runs.next_back(), Some((true, 5 .. 7)));
+	assert_eq!(runs.next(), Some((false, 2 .. 5)));
+	assert!(runs.next().is_none());
+	assert!(bits![].iter_runs().next().is_none());
+
+	//  Runs that cross element boundaries, in both specialized orderings.
+	let mut bv = bitvec![u16, Msb0; 0; 200];
+	bv[10 .. 150].fill(true);
+	bv.set(160, true);
+	let runs = [
+		(false, 0 .. 10),
+		(true, 10 .. 150),
+		(false, 150 .. 160),
+		(true, 160 .. 161),
+		(false, 161 .. 200),
+	];
+	assert!(bv.iter_runs().eq(runs.iter().cloned()));
+	assert!(bv.iter_runs().rev().eq(runs.iter().rev().cloned()));
+	let lsb = bv.iter().by_vals().collect::<BitVec<u32, Lsb0>>();
+	assert!(lsb[3 ..].iter_runs().eq(runs.iter().map(|(bit, range)| {
+		(*bit, range.start.saturating_sub(3) .. range.end - 3)
+	})));
+
+	assert_eq!(BitVec::<u16, Msb0>::from_runs(bv.iter_runs()), bv);
+	assert_eq!(
+		BitVec::<u8, Lsb0>::from_ranges([10 .. 150, 160 .. 161]),
+		bv[.. 161]
+	);
+}
+
+#[test]
+fn iter_uints() {
+	fn check<T, O>(bits: &mut BitSlice<T, O>, expected: &mut BitSlice<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		for width in [1, 3, 7, 8, 13, 32, 63, 64] {
+			assert!(bits
+				.iter_uints::<u64>(width)
+				.eq(bits.chunks_exact(width).map(BitField::load_le::<u64>)));
+			assert!(bits
+				.iter_uints_be::<u64>(width)
+				.eq(bits.chunks_exact(width).map(BitField::load_be::<u64>)));
+			assert_eq!(
+				bits.iter_uints::<u64>(width).remainder(),
+				bits.chunks_exact(width).remainder(),
+			);
+			assert_eq!(bits.iter_uints::<u64>(width).len(), bits.len() / width);
+		}
+		assert!(bits
+			.iter_uints::<i16>(5)
+			.eq(bits.chunks_exact(5).map(BitField::load_le::<i16>)));
+		assert!(bits
+			.iter_uints_be::<i16>(11)
+			.eq(bits.chunks_exact(11).map(BitField::load_be::<i16>)));
+		assert!(bits
+			.iter_uints::<u128>(100)
+			.eq(bits.chunks_exact(100).map(BitField::load_le::<u128>)));
+
+		//  Rewriting matches rewriting each chunk on its own.
+		let len = expected.len();
+		let chunks = |width| {
+			(0 .. len / width).map(move |n| n * width .. (n + 1) * width)
+		};
+		for range in chunks(7) {
+			let chunk = &mut expected[range];
+			chunk.store_le(chunk.load_le::<u8>().wrapping_mul(3));
+		}
+		bits.for_each_uint_mut::<u8, _>(7, |value| value.wrapping_mul(3));
+		assert_eq!(bits, expected);
+		for range in chunks(13) {
+			let chunk = &mut expected[range];
+			chunk.store_be(!chunk.load_be::<i16>());
+		}
+		bits.for_each_uint_mut_be::<i16, _>(13, |value| !value);
+		assert_eq!(bits, expected);
+		for range in chunks(64) {
+			let chunk = &mut expected[range];
+			chunk.store_le(chunk.load_le::<u64>().rotate_left(5));
+		}
+		bits.for_each_uint_mut::<u64, _>(64, |value| value.rotate_left(5));
+		assert_eq!(bits, expected);
+	}
+
+	let mut data = [0u32; 12];
+	let mut state = 0x9E37_79B9u32;
+	for elem in data.iter_mut() {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		*elem = state;
+	}
+	let bytes = data.map(|elem| elem as u8);
+	for start in [0, 3, 8] {
+		let (mut a, mut b) = (data, data);
+		check(
+			&mut a.view_bits_mut::<Lsb0>()[start .. 350],
+			&mut b.view_bits_mut::<Lsb0>()[start .. 350],
+		);
+		let (mut a, mut b) = (data, data);
+		check(
+			&mut a.view_bits_mut::<Msb0>()[start .. 350],
+			&mut b.view_bits_mut::<Msb0>()[start .. 350],
+		);
+		let (mut a, mut b) = (bytes, bytes);
+		check(
+			&mut a.view_bits_mut::<Lsb0>()[start ..],
+			&mut b.view_bits_mut::<Lsb0>()[start ..],
+		);
+		let (mut a, mut b) = (bytes, bytes);
+		check(
+			&mut a.view_bits_mut::<Msb0>()[start ..],
+			&mut b.view_bits_mut::<Msb0>()[start ..],
+		);
+	}
+}
+
 #[test]
 fn trait_impls() {
 	use core::iter::FusedIterator;
@@ -1,6 +1,7 @@
 use rand::random;
 
 use crate::{
+	order::BitOrder,
 	prelude::*,
 	slice::BitSliceIndex,
 };
@@ -108,6 +109,168 @@ fn bit_not() {
 	assert_eq!(c, [0xFF_FF_00_00, 0xFF_00_00_FF, 0x00_00_FF_FF]);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn borrowed_ops() {
+	let a = random::<[u32; 3]>();
+	let b = random::<[u32; 3]>();
+	let (l, r) = (a.view_bits::<Lsb0>(), b.view_bits::<Lsb0>());
+	let expect =
+		|f: fn(u32, u32) -> u32| [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2])];
+
+	assert_eq!((l & r).into_vec(), expect(|x, y| x & y));
+	assert_eq!((l | r).into_vec(), expect(|x, y| x | y));
+	assert_eq!((l ^ r).into_vec(), expect(|x, y| x ^ y));
+	assert_eq!((!l).into_vec(), expect(|x, _| !x));
+	assert_eq!(l.and_not(r).into_vec(), expect(|x, y| x & !y));
+	assert_eq!(l.nand(r).into_vec(), expect(|x, y| !(x & y)));
+	assert_eq!(l.nor(r).into_vec(), expect(|x, y| !(x | y)));
+	assert_eq!(l.xnor(r).into_vec(), expect(|x, y| !(x ^ y)));
+
+	//  Mismatched types use the bit-wise path, and zero-extend `rhs`.
+	let m = &b.view_bits::<Msb0>()[.. 40];
+	let out = l.nand(m);
+	assert_eq!(out.len(), 96);
+	for (idx, bit) in out.iter().by_vals().enumerate() {
+		let rhs = m.get(idx).map(|b| *b).unwrap_or(false);
+		assert_eq!(bit, !(l[idx] & rhs));
+	}
+	assert_eq!(l & m, l.to_bitvec() & m);
+
+	let c = random::<[u32; 3]>();
+	let all = BitVec::<u32, Lsb0>::and_all([l, r, c.view_bits::<Lsb0>()]);
+	assert_eq!(all.into_vec(), [
+		a[0] & b[0] & c[0],
+		a[1] & b[1] & c[1],
+		a[2] & b[2] & c[2],
+	]);
+	let any = BitVec::<u32, Lsb0>::or_all([l, r, c.view_bits::<Lsb0>()]);
+	assert_eq!(any.into_vec(), [
+		a[0] | b[0] | c[0],
+		a[1] | b[1] | c[1],
+		a[2] | b[2] | c[2],
+	]);
+	assert!(
+		BitVec::<u8, Msb0>::or_all(core::iter::empty::<&BitSlice>()).is_empty()
+	);
+}
+
+#[test]
+fn shifts() {
+	fn check<T, O>(bits: &mut BitSlice<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let orig = bits.iter().by_vals().collect::<Vec<_>>();
+		let len = orig.len();
+		for by in [0, 1, 3, 8, 13, 31, 64, 65, len - 1, len] {
+			bits.iter_mut().zip(&orig).for_each(|(mut b, o)| *b = *o);
+			bits.shift_start(by);
+			for (idx, bit) in bits.iter().by_vals().enumerate() {
+				assert_eq!(bit, orig.get(idx + by).copied().unwrap_or(false));
+			}
+
+			bits.iter_mut().zip(&orig).for_each(|(mut b, o)| *b = *o);
+			bits.shift_end(by);
+			for (idx, bit) in bits.iter().by_vals().enumerate() {
+				let expect = idx.checked_sub(by).map_or(false, |i| orig[i]);
+				assert_eq!(bit, expect);
+			}
+		}
+	}
+
+	let mut data = random::<[u8; 17]>();
+	check(data.view_bits_mut::<Lsb0>());
+	check(data.view_bits_mut::<Msb0>());
+	check(&mut data.view_bits_mut::<Lsb0>()[3 .. 130]);
+	check(&mut data.view_bits_mut::<Msb0>()[5 ..]);
+	let mut data = random::<[u16; 9]>();
+	check(data.view_bits_mut::<Lsb0>());
+	check(data.view_bits_mut::<Msb0>());
+	let mut data = random::<[u64; 3]>();
+	check(data.view_bits_mut::<Lsb0>());
+	check(data.view_bits_mut::<Msb0>());
+	check(&mut data.view_bits_mut::<LocalBits>()[.. 150]);
+
+	let a = bitarr![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	assert_eq!((a << 3)[.. 9], bits![1, 0, 0, 1, 0, 1, 0, 0, 0]);
+	assert_eq!((a >> 3)[.. 9], bits![0, 0, 0, 1, 0, 1, 1, 0, 0]);
+	let mut a = BitArray::<u32, Msb0>::new(0x8000_1234);
+	a >>= 4;
+	assert_eq!(a.into_inner(), 0x0800_0123);
+	a.shift_signed_be(4);
+	assert_eq!(a.into_inner(), 0x0080_0012);
+	a <<= 8;
+	a.shift_signed_be(8);
+	assert_eq!(a.into_inner(), 0xFF80_0012);
+
+	let num = 0x8123_4567u32;
+	let mut le = BitArray::<[u8; 4], Lsb0>::ZERO;
+	le.store_le(num);
+	let mut be = BitArray::<[u8; 4], Msb0>::ZERO;
+	be.store_be(num);
+	for by in [0, 1, 7, 8, 13, 31, 32] {
+		let shl = num.checked_shl(by as u32).unwrap_or(0);
+		let shr = num.checked_shr(by as u32).unwrap_or(0);
+		let lsb0 = BitArray::<u32, Lsb0>::new(num);
+		let msb0 = BitArray::<u32, Msb0>::new(num);
+		assert_eq!((lsb0 << by).into_inner(), shl);
+		assert_eq!((lsb0 >> by).into_inner(), shr);
+		assert_eq!((msb0 << by).into_inner(), shl);
+		assert_eq!((msb0 >> by).into_inner(), shr);
+		assert_eq!((le << by).load_le::<u32>(), shl);
+		assert_eq!((le >> by).load_le::<u32>(), shr);
+		assert_eq!((be << by).load_be::<u32>(), shl);
+		assert_eq!((be >> by).load_be::<u32>(), shr);
+	}
+
+	let mut v = bitvec![0, 0, 1, 1];
+	v <<= 1;
+	assert_eq!(v, bits![0, 0, 0, 1]);
+	let v = v >> 2;
+	assert_eq!(v, bits![0, 1, 0, 0]);
+	let b = v.into_boxed_bitslice() << 1;
+	assert_eq!(b, bits![0, 0, 1, 0]);
+	let mut b = b >> 4;
+	assert_eq!(b, bits![0; 4]);
+	b.shift_signed_le(4);
+	assert_eq!(b, bits![0; 4]);
+
+	for val in i8::MIN ..= i8::MAX {
+		for by in 0 ..= 8 {
+			let expect = val >> by.min(7);
+			let mut lsb0 = BitArray::<u8, Lsb0>::new(val as u8);
+			lsb0.shift_signed_le(by);
+			assert_eq!(lsb0.into_inner() as i8, expect);
+			let mut msb0 = BitArray::<u8, Msb0>::new(val as u8);
+			msb0.shift_signed_be(by);
+			assert_eq!(msb0.into_inner() as i8, expect);
+		}
+	}
+	for val in [i32::MIN, -100_000, -1, 0, 1, 100_000, i32::MAX] {
+		for by in [0, 1, 7, 8, 13, 31, 32] {
+			let expect = val >> by.min(31);
+			let mut le = BitArray::<[u8; 4], Lsb0>::ZERO;
+			le.store_le(val);
+			le.shift_signed_le(by);
+			assert_eq!(le.load_le::<i32>(), expect);
+			let mut be = BitArray::<[u8; 4], Msb0>::ZERO;
+			be.store_be(val);
+			be.shift_signed_be(by);
+			assert_eq!(be.load_be::<i32>(), expect);
+		}
+	}
+
+	let bits = bits![mut 1, 1, 0, 1, 0];
+	bits.shift_in_from(bits![u16, Msb0; 1, 0, 1], 2);
+	assert_eq!(bits, bits![0, 1, 0, 1, 0]);
+	bits.shift_in_from(bits![1; 5], 5);
+	assert_eq!(bits, bits![1; 5]);
+	bits.shift_in_from(bits![], 0);
+	assert_eq!(bits, bits![1; 5]);
+}
+
 #[test]
 fn indexing() {
 	let bits = bits![mut 0, 1, 0, 0, 1];
@@ -0,0 +1,61 @@
+#![cfg(all(test, feature = "rayon"))]
+
+use rand::random;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[test]
+fn par_iters() {
+	let data = (0 .. 2048).map(|_| random::<u64>()).collect::<Vec<_>>();
+	let bits = &data.view_bits::<Msb0>()[5 .. 130_000];
+
+	for width in [1, 7, 64, 1000] {
+		assert_eq!(
+			bits.par_chunks(width).collect::<Vec<_>>(),
+			bits.chunks(width).collect::<Vec<_>>(),
+		);
+	}
+	assert_eq!(bits.par_chunks(3).len(), bits.chunks(3).len());
+	assert_eq!(
+		bits.par_iter_ones().collect::<Vec<_>>(),
+		bits.iter_ones().collect::<Vec<_>>(),
+	);
+
+	let mut copy = data.clone();
+	let copy_bits = &mut copy.view_bits_mut::<Msb0>()[5 .. 130_000];
+	copy_bits
+		.par_chunks_mut(333)
+		.for_each(|chunk| chunk.reverse());
+	for (a, b) in copy_bits.chunks(333).zip(bits.chunks(333)) {
+		assert!(a.iter().by_vals().eq(b.iter().by_vals().rev()));
+	}
+}
+
+#[test]
+fn par_bulk() {
+	let mut a = BitVec::<u32, Lsb0>::from_vec(
+		(0 .. 4096).map(|_| random::<u32>()).collect(),
+	);
+	let b = BitVec::<u16, Msb0>::from_vec(
+		(0 .. 3000).map(|_| random::<u16>()).collect(),
+	);
+	let b = b.as_bitslice();
+	let a_part = &a[3 ..];
+	assert_eq!(a_part.par_count_ones(), a_part.count_ones());
+	assert_eq!(a_part.par_count_zeros(), a_part.count_zeros());
+
+	let orig = a.clone();
+	a[3 ..].par_bitxor_assign(b);
+	assert_eq!(a[3 ..], orig[3 ..].to_bitvec() ^ b);
+	a[3 ..].par_bitor_assign(b);
+	assert_eq!(a[3 ..], (orig[3 ..].to_bitvec() ^ b) | b);
+	a.par_bitand_assign(b);
+	assert!(a[b.len() ..].not_any());
+
+	a[9 .. 100_000].par_fill(true);
+	assert!(a[9 .. 100_000].all());
+	assert!(a[100_000 ..].not_any());
+	a.par_fill(false);
+	assert!(a.not_any());
+}
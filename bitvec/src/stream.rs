@@ -0,0 +1,161 @@
+#![doc = include_str!("../doc/stream.md")]
+
+use core::fmt::{
+	self,
+	Debug,
+	Display,
+	Formatter,
+};
+
+mod io;
+mod reader;
+mod tests;
+mod writer;
+
+#[cfg(feature = "std")]
+pub use self::io::{
+	IoBitReader,
+	IoBitWriter,
+};
+pub use self::reader::BitReader;
+#[cfg(feature = "alloc")]
+pub use self::writer::BitWriter;
+
+/// The order in which the bits of a field in a stream are given significance.
+///
+/// This selects between the [`BitField`] methods that the stream uses to move
+/// integers in and out of its fields. Bitstream formats that transmit the most
+/// significant bit of each field first, such as H.264 or MPEG-TS, should use
+/// `Big` over a `BitSlice<u8, Msb0>`.
+///
+/// [`BitField`]: crate::field::BitField
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Endian {
+	/// Fields are moved with [`BitField::load_be`] and
+	/// [`BitField::store_be`].
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	Big,
+	/// Fields are moved with [`BitField::load_le`] and
+	/// [`BitField::store_le`].
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	Little,
+}
+
+#[doc = include_str!("../doc/stream/StreamError.md")]
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StreamError {
+	/// The stream has fewer bits remaining than an operation requires.
+	Underrun {
+		/// The number of bits the operation requires.
+		requested: usize,
+		/// The number of bits remaining in the stream.
+		remaining: usize,
+	},
+	/// A field is wider than the integer it is read from or written into.
+	TooWide {
+		/// The width of the field.
+		width: usize,
+		/// The width of the integer.
+		max:   usize,
+	},
+	/// A position is beyond the end of the stream.
+	OutOfBounds {
+		/// The requested position.
+		position: usize,
+		/// The length of the stream.
+		len:      usize,
+	},
+	/// A value does not fit in the field it is written into.
+	Overflow {
+		/// The width of the field.
+		width: usize,
+	},
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for StreamError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("StreamError::")?;
+		match *self {
+			Self::Underrun {
+				requested,
+				remaining,
+			} => fmt
+				.debug_struct("Underrun")
+				.field("requested", &requested)
+				.field("remaining", &remaining)
+				.finish(),
+			Self::TooWide { width, max } => fmt
+				.debug_struct("TooWide")
+				.field("width", &width)
+				.field("max", &max)
+				.finish(),
+			Self::OutOfBounds { position, len } => fmt
+				.debug_struct("OutOfBounds")
+				.field("position", &position)
+				.field("len", &len)
+				.finish(),
+			Self::Overflow { width } => {
+				fmt.debug_struct("Overflow").field("width", &width).finish()
+			},
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for StreamError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Underrun {
+				requested,
+				remaining,
+			} => write!(
+				fmt,
+				"cannot take {} bits from a stream with {} bits remaining",
+				requested, remaining,
+			),
+			Self::TooWide { width, max } => write!(
+				fmt,
+				"a {}-bit field does not fit in a {}-bit integer",
+				width, max,
+			),
+			Self::OutOfBounds { position, len } => write!(
+				fmt,
+				"position {} is beyond the end of a {}-bit stream",
+				position, len,
+			),
+			Self::Overflow { width } => {
+				write!(fmt, "the value does not fit in a {}-bit field", width)
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
+
+/// Streams over `std::io` report their own failures as `io::Error`s, so that
+/// they can be combined with the errors of the byte source or sink.
+///
+/// Running out of bits is [`UnexpectedEof`]; every other failure is
+/// [`InvalidInput`].
+///
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+/// [`UnexpectedEof`]: std::io::ErrorKind::UnexpectedEof
+#[cfg(feature = "std")]
+impl From<StreamError> for std::io::Error {
+	#[inline]
+	fn from(err: StreamError) -> Self {
+		let kind = match err {
+			StreamError::Underrun { .. } => std::io::ErrorKind::UnexpectedEof,
+			_ => std::io::ErrorKind::InvalidInput,
+		};
+		Self::new(kind, err)
+	}
+}
@@ -0,0 +1,495 @@
+#![cfg(feature = "std")]
+#![doc = include_str!("../../doc/stream/io.md")]
+
+use core::{
+	marker::PhantomData,
+	mem::ManuallyDrop,
+	ptr,
+};
+use std::io::{
+	self,
+	ErrorKind,
+	Read,
+	Write,
+};
+
+use funty::{
+	Integral,
+	Signed,
+};
+
+use super::{
+	BitReader,
+	BitWriter,
+	Endian,
+	StreamError,
+};
+use crate::{
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+	view::BitView,
+};
+
+/// The number of bytes that the reader requests from its source at a time, and
+/// that the writer collects before passing them to its sink.
+const BUF_BYTES: usize = 8 * 1024;
+
+/// The widest field that can be read or written, in bytes. This is the width
+/// of `u128`, plus one byte for a field that does not begin on a byte
+/// boundary.
+const MAX_FIELD_BYTES: usize = 17;
+
+#[doc = include_str!("../../doc/stream/IoBitReader.md")]
+#[derive(Debug)]
+pub struct IoBitReader<R, O = Lsb0>
+where
+	R: Read,
+	O: BitOrder,
+{
+	/// The source of the stream.
+	inner:  R,
+	/// Bytes taken from the source that have not been entirely read.
+	buf:    Vec<u8>,
+	/// The index in `buf`, as a bit-slice, of the next bit to read.
+	pos:    usize,
+	/// The number of bits that were read before the first byte of `buf`.
+	base:   u64,
+	/// Set once the source reports that it has no more bytes.
+	eof:    bool,
+	/// Selects the `BitField` method used to load fields.
+	endian: Endian,
+	/// Marker for the ordering of bits within each byte.
+	_ord:   PhantomData<O>,
+}
+
+/// Construction and inspection.
+impl<R, O> IoBitReader<R, O>
+where
+	R: Read,
+	O: BitOrder,
+{
+	/// Begins reading from a byte source, loading fields in big-endian order.
+	#[inline]
+	pub fn new(inner: R) -> Self {
+		Self::with_endian(inner, Endian::Big)
+	}
+
+	/// Begins reading from a byte source, loading fields in the given order.
+	#[inline]
+	pub fn with_endian(inner: R, endian: Endian) -> Self {
+		Self {
+			inner,
+			buf: Vec::new(),
+			pos: 0,
+			base: 0,
+			eof: false,
+			endian,
+			_ord: PhantomData,
+		}
+	}
+
+	/// Gets the order in which fields are loaded.
+	#[inline]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the order in which subsequent fields are loaded.
+	#[inline]
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Gets the number of bits that have been read or skipped since the reader
+	/// was created.
+	#[inline]
+	pub fn position(&self) -> u64 {
+		self.base + self.pos as u64
+	}
+
+	/// Borrows the byte source.
+	#[inline]
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	/// Mutably borrows the byte source.
+	///
+	/// Reading from the source directly skips over the bytes that it produces,
+	/// without the reader’s knowledge.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.inner
+	}
+
+	/// Ends the stream, and returns the byte source.
+	///
+	/// Any bytes that the reader has taken from the source, but not yet
+	/// entirely read, are lost.
+	#[inline]
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	/// Moves the reader forward by `count` bits without reading them.
+	///
+	/// Skipped bits are discarded as they are read from the source, so `count`
+	/// may be far larger than the reader’s buffer.
+	///
+	/// ## Errors
+	///
+	/// If the stream ends first, this fails with [`ErrorKind::UnexpectedEof`]
+	/// and leaves the reader at the end of the stream.
+	#[inline]
+	pub fn skip(&mut self, mut count: u64) -> io::Result<()> {
+		loop {
+			let buffered = self.buffered();
+			if count <= buffered as u64 {
+				self.pos += count as usize;
+				return Ok(());
+			}
+			count -= buffered as u64;
+			self.pos += buffered;
+			if !self.fill()? {
+				return Err(StreamError::Underrun {
+					requested: count.try_into().unwrap_or(usize::MAX),
+					remaining: 0,
+				}
+				.into());
+			}
+		}
+	}
+
+	/// Moves the reader forward to the next multiple of eight bits from the
+	/// start of the stream. This does nothing if the reader is already there.
+	#[inline]
+	pub fn align_to_byte(&mut self) -> io::Result<()> {
+		let bits = bits_of::<u8>();
+		self.skip(((bits - self.pos % bits) % bits) as u64)
+	}
+
+	/// Reads a single bit.
+	#[inline]
+	pub fn read_bool(&mut self) -> io::Result<bool> {
+		self.take(1, |reader| reader.read_bool())
+	}
+
+	/// Reads the next `width` bits as an unsigned integer.
+	///
+	/// This behaves as [`BitReader::read`], and additionally fails if the
+	/// source does.
+	#[inline]
+	pub fn read<I>(&mut self, width: usize) -> io::Result<I>
+	where I: Integral {
+		self.take(width, |reader| reader.read::<I>(width))
+	}
+
+	/// Reads the next `width` bits as a two’s-complement signed integer,
+	/// sign-extending it from its highest bit.
+	///
+	/// This behaves as [`BitReader::read_signed`], and additionally fails if
+	/// the source does.
+	#[inline]
+	pub fn read_signed<I>(&mut self, width: usize) -> io::Result<I>
+	where I: Signed {
+		self.take(width, |reader| reader.read_signed::<I>(width))
+	}
+
+	/// Runs a read of `width` bits against the buffer, after making sure that
+	/// it holds them, and advances past the bits that the read consumed.
+	///
+	/// A failed read leaves the reader where it was.
+	fn take<F, V>(&mut self, width: usize, read: F) -> io::Result<V>
+	where F: FnOnce(&mut BitReader<u8, O>) -> Result<V, StreamError> {
+		//  Widths that no integer can hold are rejected by `read` without
+		//  waiting on the source for bits that will never be used.
+		let want = width.min(MAX_FIELD_BYTES * bits_of::<u8>());
+		while self.buffered() < want && self.fill()? {}
+
+		let bits = &self.buf.view_bits::<O>()[self.pos ..];
+		let mut reader = BitReader::with_endian(bits, self.endian);
+		let out = read(&mut reader)?;
+		self.pos += reader.position();
+		Ok(out)
+	}
+
+	/// Counts the bits in the buffer that have not been read.
+	fn buffered(&self) -> usize {
+		self.buf.len() * bits_of::<u8>() - self.pos
+	}
+
+	/// Discards the bytes that have been entirely read, and appends more bytes
+	/// from the source to the buffer.
+	///
+	/// Returns `false` if the source has ended.
+	fn fill(&mut self) -> io::Result<bool> {
+		if self.eof {
+			return Ok(false);
+		}
+
+		let done = self.pos / bits_of::<u8>();
+		self.buf.drain(.. done);
+		self.pos -= done * bits_of::<u8>();
+		self.base += (done * bits_of::<u8>()) as u64;
+
+		let len = self.buf.len();
+		self.buf.resize(len + BUF_BYTES, 0);
+		let count = loop {
+			match self.inner.read(&mut self.buf[len ..]) {
+				Ok(count) => break count,
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				Err(err) => {
+					self.buf.truncate(len);
+					return Err(err);
+				},
+			}
+		};
+		self.buf.truncate(len + count);
+		self.eof = count == 0;
+		Ok(!self.eof)
+	}
+}
+
+#[doc = include_str!("../../doc/stream/IoBitWriter.md")]
+#[derive(Debug)]
+pub struct IoBitWriter<W, O = Lsb0>
+where
+	W: Write,
+	O: BitOrder,
+{
+	/// The sink of the stream.
+	inner:   W,
+	/// Bits that have not yet been passed to the sink. This always begins on
+	/// a byte boundary of the stream.
+	bits:    BitVec<u8, O>,
+	/// The number of bits that have been passed to the sink.
+	base:    u64,
+	/// The value of the bits that fill out a final partial byte.
+	padding: bool,
+	/// Selects the `BitField` method used to store fields.
+	endian:  Endian,
+}
+
+/// Construction and inspection.
+impl<W, O> IoBitWriter<W, O>
+where
+	W: Write,
+	O: BitOrder,
+{
+	/// Begins writing into a byte sink, storing fields in big-endian order.
+	#[inline]
+	pub fn new(inner: W) -> Self {
+		Self::with_endian(inner, Endian::Big)
+	}
+
+	/// Begins writing into a byte sink, storing fields in the given order.
+	#[inline]
+	pub fn with_endian(inner: W, endian: Endian) -> Self {
+		Self {
+			inner,
+			bits: BitVec::with_capacity(BUF_BYTES * bits_of::<u8>()),
+			base: 0,
+			padding: false,
+			endian,
+		}
+	}
+
+	/// Gets the order in which fields are stored.
+	#[inline]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the order in which subsequent fields are stored.
+	#[inline]
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Gets the bit that fills out a final partial byte when the stream ends.
+	#[inline]
+	pub fn padding(&self) -> bool {
+		self.padding
+	}
+
+	/// Changes the bit that fills out a final partial byte when the stream
+	/// ends. This is `0` unless it is changed.
+	#[inline]
+	pub fn set_padding(&mut self, fill: bool) {
+		self.padding = fill;
+	}
+
+	/// Gets the number of bits that have been written since the writer was
+	/// created, including those that have not yet reached the sink.
+	#[inline]
+	pub fn position(&self) -> u64 {
+		self.base + self.bits.len() as u64
+	}
+
+	/// Borrows the byte sink.
+	#[inline]
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Mutably borrows the byte sink.
+	///
+	/// Writing to the sink directly places bytes ahead of any that the writer
+	/// has not yet passed to it.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut W {
+		&mut self.inner
+	}
+
+	/// Appends a single bit.
+	#[inline]
+	pub fn write_bool(&mut self, bit: bool) -> io::Result<()> {
+		self.bits.push(bit);
+		self.drain(false)
+	}
+
+	/// Appends the contents of a bit-slice.
+	#[inline]
+	pub fn write_bits<T2, O2>(
+		&mut self,
+		bits: &BitSlice<T2, O2>,
+	) -> io::Result<()>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bits.extend_from_bitslice(bits);
+		self.drain(false)
+	}
+
+	/// Appends the low `width` bits of `value`, which must be zero in all
+	/// higher bits.
+	///
+	/// This behaves as [`BitWriter::write`], and additionally fails if the
+	/// sink does.
+	#[inline]
+	pub fn write<I>(&mut self, value: I, width: usize) -> io::Result<()>
+	where I: Integral {
+		BitWriter::with_endian(&mut self.bits, self.endian)
+			.write(value, width)?;
+		self.drain(false)
+	}
+
+	/// Appends `value` as a `width`-bit two’s-complement integer, which must
+	/// be able to represent it.
+	///
+	/// This behaves as [`BitWriter::write_signed`], and additionally fails if
+	/// the sink does.
+	#[inline]
+	pub fn write_signed<I>(&mut self, value: I, width: usize) -> io::Result<()>
+	where I: Signed {
+		BitWriter::with_endian(&mut self.bits, self.endian)
+			.write_signed(value, width)?;
+		self.drain(false)
+	}
+
+	/// Appends `fill` bits until the stream is a whole number of bytes long.
+	/// This does nothing if it already is.
+	#[inline]
+	pub fn pad_to_byte(&mut self, fill: bool) -> io::Result<()> {
+		BitWriter::from_bitvec(&mut self.bits).pad_to_byte(fill);
+		self.drain(false)
+	}
+
+	/// Passes every whole byte of the stream to the sink, and flushes it.
+	///
+	/// A final partial byte is kept, as later writes may still complete it.
+	///
+	/// If the sink fails, the bytes that it had already accepted are not passed
+	/// to it again, so the flush can be retried once the sink recovers.
+	#[inline]
+	pub fn flush(&mut self) -> io::Result<()> {
+		self.drain(true)?;
+		self.inner.flush()
+	}
+
+	/// Ends the stream, and returns the byte sink.
+	///
+	/// A final partial byte is filled out with the [padding] bit, and every
+	/// byte is passed to the sink before it is flushed.
+	///
+	/// Dropping the writer does the same, but ignores any errors.
+	///
+	/// [padding]: Self::set_padding
+	#[inline]
+	pub fn finish(self) -> io::Result<W> {
+		let mut this = ManuallyDrop::new(self);
+		let result = this.finish_stream();
+		//  `this` is not used again, and is never dropped, so each field is
+		//  moved out exactly once.
+		let (inner, bits) =
+			unsafe { (ptr::read(&this.inner), ptr::read(&this.bits)) };
+		drop(bits);
+		result.map(|()| inner)
+	}
+
+	/// Pads the stream to a byte boundary, passes all of it to the sink, and
+	/// flushes the sink.
+	fn finish_stream(&mut self) -> io::Result<()> {
+		let fill = self.padding;
+		BitWriter::from_bitvec(&mut self.bits).pad_to_byte(fill);
+		self.drain(true)?;
+		self.inner.flush()
+	}
+
+	/// Passes the whole bytes at the front of the buffer to the sink, once
+	/// there are enough of them or if `force` is set.
+	///
+	/// Bytes that the sink accepts are removed from the buffer even if it later
+	/// fails, so that retrying the write does not pass them to it again.
+	fn drain(&mut self, force: bool) -> io::Result<()> {
+		let bytes = self.bits.len() / bits_of::<u8>();
+		if bytes == 0 || (bytes < BUF_BYTES && !force) {
+			return Ok(());
+		}
+		let mut sent = 0;
+		let mut result = Ok(());
+		while sent < bytes {
+			match self.inner.write(&self.bits.as_raw_slice()[sent .. bytes]) {
+				Ok(0) => {
+					result = Err(io::Error::new(
+						ErrorKind::WriteZero,
+						"failed to write whole buffer",
+					));
+					break;
+				},
+				Ok(count) => sent += count,
+				Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+				Err(err) => {
+					result = Err(err);
+					break;
+				},
+			}
+		}
+
+		//  The buffer begins on a byte boundary, so moving its unsent bytes to
+		//  the front keeps every bit in its place within its byte.
+		let len = self.bits.len();
+		self.bits.as_raw_mut_slice().copy_within(sent .., 0);
+		self.bits.truncate(len - sent * bits_of::<u8>());
+		self.base += (sent * bits_of::<u8>()) as u64;
+		result
+	}
+}
+
+impl<W, O> Drop for IoBitWriter<W, O>
+where
+	W: Write,
+	O: BitOrder,
+{
+	#[inline]
+	fn drop(&mut self) {
+		let _ = self.finish_stream();
+	}
+}
@@ -0,0 +1,264 @@
+#![doc = include_str!("../../doc/stream/reader.md")]
+
+use funty::{
+	Integral,
+	Signed,
+};
+
+use super::{
+	Endian,
+	StreamError,
+};
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../../doc/stream/BitReader.md")]
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<'a, T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The whole stream, including bits that have already been read.
+	bits:   &'a BitSlice<T, O>,
+	/// The index in `bits` of the next bit to read.
+	pos:    usize,
+	/// Selects the `BitField` method used to load fields.
+	endian: Endian,
+}
+
+/// Construction and positioning.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins reading a bit-slice from its first bit, loading fields in
+	/// big-endian order.
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>) -> Self {
+		Self::with_endian(bits, Endian::Big)
+	}
+
+	/// Begins reading a bit-slice from its first bit, loading fields in the
+	/// given order.
+	#[inline]
+	pub fn with_endian(bits: &'a BitSlice<T, O>, endian: Endian) -> Self {
+		Self {
+			bits,
+			pos: 0,
+			endian,
+		}
+	}
+
+	/// Gets the order in which fields are loaded.
+	#[inline]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the order in which subsequent fields are loaded.
+	#[inline]
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Gets the number of bits that have been read or skipped.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits that have not yet been read.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Views the bits that have not yet been read.
+	#[inline]
+	pub fn as_bitslice(&self) -> &'a BitSlice<T, O> {
+		unsafe { self.bits.get_unchecked(self.pos ..) }
+	}
+
+	/// Moves the reader to an absolute position in the stream.
+	///
+	/// `position` may be anywhere from `0` to the length of the stream,
+	/// inclusive, and may be behind the current position.
+	#[inline]
+	pub fn seek(&mut self, position: usize) -> Result<(), StreamError> {
+		if position > self.bits.len() {
+			return Err(StreamError::OutOfBounds {
+				position,
+				len: self.bits.len(),
+			});
+		}
+		self.pos = position;
+		Ok(())
+	}
+
+	/// Moves the reader forward by `count` bits without reading them.
+	#[inline]
+	pub fn skip(&mut self, count: usize) -> Result<(), StreamError> {
+		self.read_bits(count).map(drop)
+	}
+
+	/// Moves the reader forward to the next multiple of eight bits from the
+	/// start of the stream. This does nothing if the reader is already there.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![u8, Msb0; 0; 24];
+	/// let mut reader = BitReader::new(bits);
+	/// reader.skip(3).unwrap();
+	/// reader.align_to_byte().unwrap();
+	/// assert_eq!(reader.position(), 8);
+	/// reader.align_to_byte().unwrap();
+	/// assert_eq!(reader.position(), 8);
+	/// ```
+	#[inline]
+	pub fn align_to_byte(&mut self) -> Result<(), StreamError> {
+		let bits = bits_of::<u8>();
+		self.skip((bits - self.pos % bits) % bits)
+	}
+
+	/// Reads a single bit.
+	#[inline]
+	pub fn read_bool(&mut self) -> Result<bool, StreamError> {
+		self.read_bits(1).map(|bit| bit[0])
+	}
+
+	/// Reads `count` bits as a bit-slice.
+	///
+	/// The bit-slice borrows from the stream, so this does not copy.
+	#[inline]
+	pub fn read_bits(
+		&mut self,
+		count: usize,
+	) -> Result<&'a BitSlice<T, O>, StreamError> {
+		let out = self.peek_bits(count)?;
+		self.pos += count;
+		Ok(out)
+	}
+
+	/// Views the next `count` bits without advancing the reader.
+	#[inline]
+	pub fn peek_bits(
+		&self,
+		count: usize,
+	) -> Result<&'a BitSlice<T, O>, StreamError> {
+		let remaining = self.remaining();
+		if count > remaining {
+			return Err(StreamError::Underrun {
+				requested: count,
+				remaining,
+			});
+		}
+		Ok(unsafe { self.bits.get_unchecked(self.pos .. self.pos + count) })
+	}
+}
+
+/// Field loading.
+///
+/// These methods fail, without advancing the reader, if the stream has fewer
+/// than `width` bits remaining or if `width` is wider than `I`. A `width` of
+/// zero reads no bits and produces zero.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Loads the next `width` bits as an unsigned integer, without advancing
+	/// the reader.
+	#[inline]
+	pub fn peek<I>(&self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		let value = self.load::<I>(width)?;
+		//  `BitField` sign-extends signed integers, which is undone here.
+		if width < bits_of::<I>() {
+			return Ok(value & !(!I::ZERO << width));
+		}
+		Ok(value)
+	}
+
+	/// Reads the next `width` bits as an unsigned integer.
+	///
+	/// Signed integer types are permitted, and are zero-extended; use
+	/// [`.read_signed()`] to sign-extend them instead.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let data = [0b1011_0010u8, 0b1100_0000];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	///
+	/// assert_eq!(reader.read::<u8>(3), Ok(0b101));
+	/// assert_eq!(reader.read::<u16>(7), Ok(0b100_1011));
+	/// assert!(reader.read::<u8>(9).is_err());
+	/// assert_eq!(reader.remaining(), 6);
+	/// ```
+	///
+	/// [`.read_signed()`]: Self::read_signed
+	#[inline]
+	pub fn read<I>(&mut self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		let out = self.peek(width)?;
+		self.pos += width;
+		Ok(out)
+	}
+
+	/// Reads the next `width` bits as a two’s-complement signed integer,
+	/// sign-extending it from its highest bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitReader;
+	///
+	/// let bits = bits![u8, Msb0; 1, 1, 0, 0, 1, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_signed::<i8>(3), Ok(-2));
+	/// assert_eq!(reader.read_signed::<i8>(3), Ok(3));
+	/// ```
+	#[inline]
+	pub fn read_signed<I>(&mut self, width: usize) -> Result<I, StreamError>
+	where I: Signed {
+		let out = self.load(width)?;
+		self.pos += width;
+		Ok(out)
+	}
+
+	/// Loads the next `width` bits, sign-extending signed integers.
+	fn load<I>(&self, width: usize) -> Result<I, StreamError>
+	where I: Integral {
+		let max = bits_of::<I>();
+		if width > max {
+			return Err(StreamError::TooWide { width, max });
+		}
+		let field = self.peek_bits(width)?;
+		if width == 0 {
+			return Ok(I::ZERO);
+		}
+		Ok(match self.endian {
+			Endian::Big => field.load_be(),
+			Endian::Little => field.load_le(),
+		})
+	}
+}
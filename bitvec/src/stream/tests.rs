@@ -0,0 +1,264 @@
+//! Unit tests for bitstreams.
+
+#![cfg(test)]
+
+use super::*;
+use crate::prelude::*;
+
+#[test]
+fn reader() {
+	let data = [0xA5u8, 0x0F, 0xF0, 0x81];
+	let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	assert_eq!(reader.endian(), Endian::Big);
+	assert_eq!(reader.remaining(), 32);
+
+	assert_eq!(reader.peek::<u8>(4), Ok(0xA));
+	assert_eq!(reader.read::<u8>(4), Ok(0xA));
+	assert_eq!(reader.read::<u16>(0), Ok(0));
+	assert_eq!(reader.read::<u16>(8), Ok(0x50));
+	assert_eq!(reader.read::<i8>(4), Ok(0xF));
+	assert_eq!(reader.read_signed::<i8>(4), Ok(-1));
+	assert_eq!(reader.position(), 20);
+
+	assert_eq!(
+		reader.read::<u8>(9),
+		Err(StreamError::TooWide { width: 9, max: 8 })
+	);
+	assert_eq!(
+		reader.read::<u16>(13),
+		Err(StreamError::Underrun {
+			requested: 13,
+			remaining: 12,
+		})
+	);
+	assert_eq!(reader.position(), 20);
+
+	reader.align_to_byte().unwrap();
+	assert_eq!(reader.as_bitslice(), data[3].view_bits::<Msb0>());
+	assert!(reader.read_bool().unwrap());
+	reader.skip(6).unwrap();
+	assert!(reader.read_bool().unwrap());
+	assert!(reader.read_bool().is_err());
+	assert!(reader.skip(1).is_err());
+	assert!(reader.align_to_byte().is_ok());
+
+	assert_eq!(
+		reader.seek(33),
+		Err(StreamError::OutOfBounds {
+			position: 33,
+			len:      32,
+		})
+	);
+	reader.seek(4).unwrap();
+	reader.set_endian(Endian::Little);
+	//  Little-endian loads put the later elements of the field in the high
+	//  bits.
+	assert_eq!(reader.read::<u16>(16), Ok(0xF0F5));
+	assert_eq!(reader.read_bits(4).unwrap(), bits![0; 4]);
+
+	//  Lsb0 streams read each byte from its least significant bit.
+	let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	assert_eq!(reader.read::<u8>(4), Ok(0x5));
+	assert_eq!(reader.read::<u8>(8), Ok(0xAF));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn writer() {
+	let mut writer = BitWriter::<u8, Msb0>::new();
+	assert!(writer.is_empty());
+	writer.write::<u8>(0xA, 4).unwrap();
+	writer.write::<u16>(0, 0).unwrap();
+	writer.write::<u16>(0x50, 8).unwrap();
+	writer.write_signed::<i8>(-1, 4).unwrap();
+	writer.write::<u32>(u32::MAX, 32).unwrap();
+	assert_eq!(writer.len(), 48);
+	assert_eq!(writer.byte_len(), 6);
+
+	assert_eq!(
+		writer.write::<u8>(1, 9),
+		Err(StreamError::TooWide { width: 9, max: 8 })
+	);
+	assert_eq!(
+		writer.write::<u8>(0x10, 4),
+		Err(StreamError::Overflow { width: 4 })
+	);
+	assert_eq!(
+		writer.write::<u8>(1, 0),
+		Err(StreamError::Overflow { width: 0 })
+	);
+	assert_eq!(
+		writer.write_signed::<i8>(-5, 3),
+		Err(StreamError::Overflow { width: 3 })
+	);
+	assert_eq!(
+		writer.write_signed::<i8>(4, 3),
+		Err(StreamError::Overflow { width: 3 })
+	);
+	writer.write_signed::<i8>(-4, 3).unwrap();
+	assert_eq!(writer.len(), 51);
+
+	writer.pad_to_byte(true);
+	assert_eq!(writer.byte_len(), 7);
+	writer.pad_to_byte(false);
+	writer.write_bits(bits![u16, Lsb0; 1, 0, 1]);
+	writer.set_endian(Endian::Little);
+	writer.write::<u16>(0xF0F5, 16).unwrap();
+	assert_eq!(writer.byte_len(), 10);
+
+	let bv = writer.finish();
+	let mut reader = BitReader::new(bv.as_bitslice());
+	assert_eq!(reader.read::<u8>(4), Ok(0xA));
+	assert_eq!(reader.read::<u16>(8), Ok(0x50));
+	assert_eq!(reader.read_signed::<i8>(4), Ok(-1));
+	assert_eq!(reader.read::<u32>(32), Ok(u32::MAX));
+	assert_eq!(reader.read_signed::<i8>(3), Ok(-4));
+	assert_eq!(reader.read::<u8>(5), Ok(0b1_1111));
+	assert_eq!(reader.read_bits(3).unwrap(), bits![1, 0, 1]);
+	reader.set_endian(Endian::Little);
+	assert_eq!(reader.read::<u16>(16), Ok(0xF0F5));
+	assert_eq!(reader.remaining(), 0);
+
+	//  Borrowed vectors are appended to, and the final byte is padded with
+	//  zeros.
+	let mut bv = bitvec![u8, Msb0; 1];
+	let mut writer = BitWriter::from_bitvec(&mut bv);
+	writer.write::<u8>(0b11, 2).unwrap();
+	assert_eq!(writer.finish_bytes(), [0b1110_0000]);
+	assert_eq!(bv, bits![1, 1, 1, 0, 0, 0, 0, 0]);
+
+	let mut writer =
+		BitWriter::<u8, Lsb0>::with_endian(BitVec::new(), Endian::Little);
+	writer.write::<u16>(0xABC, 12).unwrap();
+	assert_eq!(writer.finish_bytes(), [0xBC, 0x0A]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_round_trip() {
+	use std::io::ErrorKind;
+
+	let mut writer = IoBitWriter::<_, Msb0>::new(Vec::new());
+	//  Enough fields to cross the buffer boundary of both cursors, none of
+	//  which fall on a byte boundary.
+	for n in 0 .. 10_000u32 {
+		writer.write::<u32>(n, 14).unwrap();
+		writer.write_signed::<i8>(-3, 3).unwrap();
+	}
+	writer.write_bits(bits![1, 0, 1]).unwrap();
+	assert_eq!(writer.position(), 170_003);
+	assert_eq!(
+		writer.write::<u8>(4, 2).unwrap_err().kind(),
+		ErrorKind::InvalidInput,
+	);
+	assert_eq!(writer.position(), 170_003);
+	writer.set_padding(true);
+	let bytes = writer.finish().unwrap();
+	assert_eq!(bytes.len(), 21_251);
+	assert_eq!(*bytes.last().unwrap(), 0b1011_1111);
+
+	let mut reader = IoBitReader::<_, Msb0>::new(bytes.as_slice());
+	for n in 0 .. 10_000u32 {
+		assert_eq!(reader.read::<u32>(14).unwrap(), n);
+		assert_eq!(reader.read_signed::<i8>(3).unwrap(), -3);
+	}
+	assert_eq!(
+		reader.read::<u8>(9).unwrap_err().kind(),
+		ErrorKind::InvalidInput,
+	);
+	assert!(reader.read_bool().unwrap());
+	assert_eq!(reader.read::<u8>(2).unwrap(), 1);
+	assert_eq!(
+		reader.read::<u8>(8).unwrap_err().kind(),
+		ErrorKind::UnexpectedEof,
+	);
+	assert_eq!(reader.position(), 170_003);
+	reader.align_to_byte().unwrap();
+	assert_eq!(reader.position(), 170_008);
+	assert!(reader.read_bool().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_alignment() {
+	let mut writer = IoBitWriter::<_, Lsb0>::with_endian(vec![], Endian::Little);
+	writer.write::<u16>(0x1FF, 9).unwrap();
+	writer.pad_to_byte(false).unwrap();
+	writer.pad_to_byte(true).unwrap();
+	assert_eq!(writer.position(), 16);
+	writer.write_bool(true).unwrap();
+	writer.flush().unwrap();
+	assert_eq!(writer.get_ref(), &[0xFF, 0x01]);
+	drop(writer);
+
+	let mut sink = Vec::new();
+	IoBitWriter::<_, Lsb0>::new(&mut sink)
+		.write_bool(true)
+		.unwrap();
+	assert_eq!(sink, [0x01]);
+
+	let data = [0xFFu8, 0x01, 0xAA, 0xBB, 0xCC];
+	let mut reader =
+		IoBitReader::<_, Lsb0>::with_endian(&data[..], Endian::Little);
+	assert_eq!(reader.read::<u16>(9).unwrap(), 0x1FF);
+	reader.align_to_byte().unwrap();
+	assert_eq!(reader.position(), 16);
+	reader.align_to_byte().unwrap();
+	assert_eq!(reader.position(), 16);
+	reader.skip(8).unwrap();
+	assert_eq!(reader.read::<u16>(16).unwrap(), 0xCCBB);
+	assert!(reader.skip(1).is_err());
+	assert_eq!(reader.position(), 40);
+	assert_eq!(reader.into_inner(), &[] as &[u8]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_partial_write() {
+	use std::io::{
+		self,
+		ErrorKind,
+		Write,
+	};
+
+	/// Accepts at most two bytes per write, and fails once `budget` is spent.
+	struct Sink {
+		out:    Vec<u8>,
+		budget: usize,
+	}
+
+	impl Write for Sink {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			if self.budget == 0 {
+				return Err(ErrorKind::BrokenPipe.into());
+			}
+			let count = buf.len().min(2).min(self.budget);
+			self.out.extend_from_slice(&buf[.. count]);
+			self.budget -= count;
+			Ok(count)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	let sink = Sink {
+		out:    vec![],
+		budget: 3,
+	};
+	let mut writer = IoBitWriter::<_, Msb0>::new(sink);
+	for byte in 0 .. 5u8 {
+		writer.write::<u8>(byte, 8).unwrap();
+	}
+	writer.write_bool(true).unwrap();
+	assert_eq!(writer.flush().unwrap_err().kind(), ErrorKind::BrokenPipe);
+	assert_eq!(writer.get_ref().out, [0, 1, 2]);
+	assert_eq!(writer.position(), 41);
+
+	writer.get_mut().budget = usize::MAX;
+	writer.flush().unwrap();
+	assert_eq!(writer.get_ref().out, [0, 1, 2, 3, 4]);
+	let sink = writer.finish().unwrap();
+	assert_eq!(sink.out, [0, 1, 2, 3, 4, 0x80]);
+}
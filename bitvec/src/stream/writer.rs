@@ -0,0 +1,338 @@
+#![cfg(feature = "alloc")]
+#![doc = include_str!("../../doc/stream/writer.md")]
+
+use alloc::vec::Vec;
+use core::{
+	borrow::BorrowMut,
+	marker::PhantomData,
+};
+
+use funty::{
+	Integral,
+	Signed,
+};
+
+use super::{
+	Endian,
+	StreamError,
+};
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[doc = include_str!("../../doc/stream/BitWriter.md")]
+#[derive(Clone, Debug)]
+pub struct BitWriter<T = usize, O = Lsb0, V = BitVec<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+	V: BorrowMut<BitVec<T, O>>,
+{
+	/// The bit-vector that receives written fields.
+	bits:   V,
+	/// Selects the `BitField` method used to store fields.
+	endian: Endian,
+	/// Marker for the bit-vector’s type parameters.
+	_vec:   PhantomData<BitVec<T, O>>,
+}
+
+impl<T, O> BitWriter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Begins writing into a new, empty, bit-vector, storing fields in
+	/// big-endian order.
+	#[inline]
+	pub fn new() -> Self {
+		Self::from_bitvec(BitVec::new())
+	}
+}
+
+/// Construction and inspection.
+impl<T, O, V> BitWriter<T, O, V>
+where
+	T: BitStore,
+	O: BitOrder,
+	V: BorrowMut<BitVec<T, O>>,
+{
+	/// Begins appending to an existing bit-vector, storing fields in
+	/// big-endian order.
+	///
+	/// `bits` may be a `BitVec`, which the writer owns, or a `&mut BitVec`,
+	/// which the writer borrows.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::BitWriter;
+	///
+	/// let mut bv = bitvec![u8, Msb0; 1];
+	/// let mut writer = BitWriter::from_bitvec(&mut bv);
+	/// writer.write::<u8>(0, 3).unwrap();
+	/// assert_eq!(bv, bits![1, 0, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: V) -> Self {
+		Self::with_endian(bits, Endian::Big)
+	}
+
+	/// Begins appending to an existing bit-vector, storing fields in the given
+	/// order.
+	#[inline]
+	pub fn with_endian(bits: V, endian: Endian) -> Self {
+		Self {
+			bits,
+			endian,
+			_vec: PhantomData,
+		}
+	}
+
+	/// Gets the order in which fields are stored.
+	#[inline]
+	pub fn endian(&self) -> Endian {
+		self.endian
+	}
+
+	/// Changes the order in which subsequent fields are stored.
+	#[inline]
+	pub fn set_endian(&mut self, endian: Endian) {
+		self.endian = endian;
+	}
+
+	/// Views the bits that have been written.
+	///
+	/// When the writer appends to an existing bit-vector, this includes its
+	/// original contents.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.borrow().as_bitslice()
+	}
+
+	/// Gets the number of bits in the stream.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	/// Tests if the stream is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.as_bitslice().is_empty()
+	}
+
+	/// Gets the number of bytes needed to hold the stream, counting a partial
+	/// final byte.
+	#[inline]
+	pub fn byte_len(&self) -> usize {
+		let bits = bits_of::<u8>();
+		(self.len() + bits - 1) / bits
+	}
+
+	/// Ends the stream, and returns the bit-vector that received it.
+	#[inline]
+	pub fn finish(self) -> V {
+		self.bits
+	}
+
+	/// Appends a single bit.
+	#[inline]
+	pub fn write_bool(&mut self, bit: bool) {
+		self.bits.borrow_mut().push(bit);
+	}
+
+	/// Appends the contents of a bit-slice.
+	#[inline]
+	pub fn write_bits<T2, O2>(&mut self, bits: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bits.borrow_mut().extend_from_bitslice(bits);
+	}
+
+	/// Appends `fill` bits until the stream is a whole number of bytes long.
+	/// This does nothing if it already is.
+	#[inline]
+	pub fn pad_to_byte(&mut self, fill: bool) {
+		let bits = bits_of::<u8>();
+		let len = self.len();
+		let pad = (bits - len % bits) % bits;
+		self.bits.borrow_mut().resize(len + pad, fill);
+	}
+
+	/// Extends the stream by `width` bits, and returns them for writing.
+	///
+	/// The bit-vector’s own allocation strategy amortizes its growth, and the
+	/// new bits are not initialized beyond what the bit-vector guarantees,
+	/// since the caller overwrites all of them.
+	fn extend(&mut self, width: usize) -> &mut BitSlice<T, O> {
+		let bits = self.bits.borrow_mut();
+		let len = bits.len();
+		bits.reserve(width);
+		unsafe {
+			bits.set_len(len + width);
+			bits.get_unchecked_mut(len ..)
+		}
+	}
+}
+
+/// Field storing.
+///
+/// These methods fail, without writing anything, if `width` is wider than `I`
+/// or if `value` does not fit in `width` bits. A `width` of zero writes
+/// nothing, and only accepts a zero `value`.
+impl<T, O, V> BitWriter<T, O, V>
+where
+	T: BitStore,
+	O: BitOrder,
+	V: BorrowMut<BitVec<T, O>>,
+	BitSlice<T, O>: BitField,
+{
+	/// Appends the low `width` bits of `value`, which must be zero in all
+	/// higher bits.
+	///
+	/// Signed integer types are permitted, and are treated as unsigned; use
+	/// [`.write_signed()`] to write them in two’s-complement.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::{BitWriter, StreamError};
+	///
+	/// let mut writer = BitWriter::<u8, Msb0>::new();
+	/// writer.write::<u8>(0b101, 3).unwrap();
+	/// writer.write::<u16>(0x1FF, 9).unwrap();
+	/// assert_eq!(
+	///   writer.write::<u8>(4, 2),
+	///   Err(StreamError::Overflow { width: 2 }),
+	/// );
+	/// writer.pad_to_byte(false);
+	/// assert_eq!(writer.finish_bytes(), [0b1011_1111, 0b1111_0000]);
+	/// ```
+	///
+	/// [`.write_signed()`]: Self::write_signed
+	#[inline]
+	pub fn write<I>(
+		&mut self,
+		value: I,
+		width: usize,
+	) -> Result<(), StreamError>
+	where
+		I: Integral,
+	{
+		check::<I>(width)?;
+		if width < bits_of::<I>() && value >> width != I::ZERO {
+			return Err(StreamError::Overflow { width });
+		}
+		self.store(value, width);
+		Ok(())
+	}
+
+	/// Appends `value` as a `width`-bit two’s-complement integer, which must
+	/// be able to represent it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::stream::{BitWriter, StreamError};
+	///
+	/// let mut writer = BitWriter::<u8, Msb0>::new();
+	/// writer.write_signed::<i8>(-2, 3).unwrap();
+	/// writer.write_signed::<i8>(3, 3).unwrap();
+	/// assert!(writer.write_signed::<i8>(4, 3).is_err());
+	/// assert_eq!(writer.as_bitslice(), bits![1, 1, 0, 0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn write_signed<I>(
+		&mut self,
+		value: I,
+		width: usize,
+	) -> Result<(), StreamError>
+	where
+		I: Signed,
+	{
+		check::<I>(width)?;
+		let fits = match width {
+			0 => value == I::ZERO,
+			//  Shifting out all but the sign bit leaves `0` or `-1` for values
+			//  that fit.
+			w if w < bits_of::<I>() => {
+				let rest = value >> (w - 1);
+				rest == I::ZERO || rest == !I::ZERO
+			},
+			_ => true,
+		};
+		if !fits {
+			return Err(StreamError::Overflow { width });
+		}
+		self.store(value, width);
+		Ok(())
+	}
+
+	/// Ends the stream, and packs it into bytes.
+	///
+	/// Each byte is loaded from eight bits of the stream, in the writer’s
+	/// endianness, and a partial final byte is padded with `0` bits. When the
+	/// bit-vector is a `BitVec<u8, Msb0>` written in big-endian order, this is
+	/// its raw memory.
+	#[inline]
+	pub fn finish_bytes(mut self) -> Vec<u8> {
+		self.pad_to_byte(false);
+		let endian = self.endian;
+		self.as_bitslice()
+			.chunks_exact(bits_of::<u8>())
+			.map(|byte| match endian {
+				Endian::Big => byte.load_be::<u8>(),
+				Endian::Little => byte.load_le::<u8>(),
+			})
+			.collect()
+	}
+
+	/// Appends the low `width` bits of `value`.
+	fn store<I>(&mut self, value: I, width: usize)
+	where I: Integral {
+		if width == 0 {
+			return;
+		}
+		let endian = self.endian;
+		let field = self.extend(width);
+		match endian {
+			Endian::Big => field.store_be(value),
+			Endian::Little => field.store_le(value),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Default for BitWriter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Checks that a `width`-bit field can hold an `I`.
+fn check<I>(width: usize) -> Result<(), StreamError>
+where I: Integral {
+	let max = bits_of::<I>();
+	if width > max {
+		return Err(StreamError::TooWide { width, max });
+	}
+	Ok(())
+}
@@ -9,6 +9,7 @@ use core::{
 		self,
 		ManuallyDrop,
 	},
+	ops::Range,
 	ptr,
 	slice,
 };
@@ -199,6 +200,70 @@ where
 		BitSlice::<T, O>::try_from_slice(slice).map(Self::from_bitslice)
 	}
 
+	/// Constructs a new bit-vector from runs of bits.
+	///
+	/// Each run is a bit-value and the range of indices to which it is
+	/// written. The bit-vector is as long as the furthest end of any range,
+	/// and indices covered by no range are `0`. Later runs overwrite earlier
+	/// ones where they overlap.
+	///
+	/// This is the inverse of [`BitSlice::iter_runs`].
+	///
+	/// ## Panics
+	///
+	/// This panics if any range has its start after its end.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 0, 1];
+	/// let bv = BitVec::<usize, Lsb0>::from_runs(bits.iter_runs());
+	/// assert_eq!(bv, bits);
+	///
+	/// let bv = BitVec::<u8, Msb0>::from_runs([(true, 2 .. 4), (false, 3 .. 5)]);
+	/// assert_eq!(bv, bits![0, 0, 1, 0, 0]);
+	/// ```
+	///
+	/// [`BitSlice::iter_runs`]: crate::slice::BitSlice::iter_runs
+	#[inline]
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, Range<usize>)> {
+		let mut out = Self::new();
+		for (bit, range) in runs {
+			if range.end > out.len() {
+				out.resize(range.end, false);
+			}
+			out[range].fill(bit);
+		}
+		out
+	}
+
+	/// Constructs a new bit-vector with `1` bits at each index in a sequence
+	/// of ranges.
+	///
+	/// The bit-vector is as long as the furthest end of any range, and all
+	/// other indices are `0`.
+	///
+	/// ## Panics
+	///
+	/// This panics if any range has its start after its end.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Lsb0>::from_ranges([1 .. 3, 5 .. 6]);
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn from_ranges<I>(ranges: I) -> Self
+	where I: IntoIterator<Item = Range<usize>> {
+		Self::from_runs(ranges.into_iter().map(|range| (true, range)))
+	}
+
 	/// Converts a regular vector in-place into a bit-vector.
 	///
 	/// The produced bit-vector spans every bit in the original vector. No
@@ -302,6 +367,86 @@ where
 	pub fn extend_from_raw_slice(&mut self, slice: &[T]) {
 		self.extend_from_bitslice(slice.view_bits::<O>());
 	}
+
+	/// Computes the Boolean intersection of any number of bit-slices.
+	///
+	/// The first bit-slice is copied into a new bit-vector, and each following
+	/// bit-slice is merged into it with `&=`. As with that operator, the result
+	/// is as long as the first bit-slice, and shorter operands are
+	/// zero-extended. An empty sequence of operands produces an empty
+	/// bit-vector.
+	///
+	/// Operands that share the type parameters of the produced bit-vector are
+	/// merged a processor word at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 1, 0];
+	/// let b = bits![1, 1, 0, 0];
+	/// let c = bits![0, 1, 1, 0];
+	/// let all = BitVec::<usize, Lsb0>::and_all([a, b, c]);
+	/// assert_eq!(all, bits![0, 1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn and_all<'a, T2, O2, I>(operands: I) -> Self
+	where
+		T2: 'a + BitStore,
+		O2: BitOrder,
+		I: IntoIterator<Item = &'a BitSlice<T2, O2>>,
+	{
+		let mut iter = operands.into_iter();
+		let mut out = Self::new();
+		if let Some(first) = iter.next() {
+			out.extend_from_bitslice(first);
+		}
+		for bits in iter {
+			*out.as_mut_bitslice() &= bits;
+		}
+		out
+	}
+
+	/// Computes the Boolean union of any number of bit-slices.
+	///
+	/// The first bit-slice is copied into a new bit-vector, and each following
+	/// bit-slice is merged into it with `|=`. As with that operator, the result
+	/// is as long as the first bit-slice, and any bits in later operands past
+	/// that length are ignored. An empty sequence of operands produces an empty
+	/// bit-vector.
+	///
+	/// Operands that share the type parameters of the produced bit-vector are
+	/// merged a processor word at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 0, 0, 0];
+	/// let b = bits![0, 0, 1, 0];
+	/// let c = bits![0, 0, 1, 1];
+	/// let any = BitVec::<usize, Lsb0>::or_all([a, b, c]);
+	/// assert_eq!(any, bits![1, 0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn or_all<'a, T2, O2, I>(operands: I) -> Self
+	where
+		T2: 'a + BitStore,
+		O2: BitOrder,
+		I: IntoIterator<Item = &'a BitSlice<T2, O2>>,
+	{
+		let mut iter = operands.into_iter();
+		let mut out = Self::new();
+		if let Some(first) = iter.next() {
+			out.extend_from_bitslice(first);
+		}
+		for bits in iter {
+			*out.as_mut_bitslice() |= bits;
+		}
+		out
+	}
 }
 
 /// Converters.
@@ -566,6 +711,36 @@ where
 		self.bitspan = bitspan;
 	}
 
+	/// Retains only the bits selected by a mask bit-slice.
+	///
+	/// This is the in-place form of [`BitSlice::extract`]: bits whose
+	/// corresponding mask bit is `1` are kept, in order, and all others are
+	/// removed. Bits of the bit-vector beyond the end of the mask are removed.
+	/// Unlike [`.retain()`], this processes a processor word of the bit-vector
+	/// and mask at a time, and does not allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 0, 1, 1];
+	/// bv.retain_mask(bits![1, 1, 0, 0, 1]);
+	/// assert_eq!(bv, bits![0, 1, 1]);
+	/// ```
+	///
+	/// [`BitSlice::extract`]: crate::slice::BitSlice::extract
+	/// [`.retain()`]: Self::retain
+	#[inline]
+	pub fn retain_mask<T2, O2>(&mut self, mask: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.as_mut_bitslice().compress(mask);
+		self.truncate(len);
+	}
+
 	/// Sets the starting-bit index of the span descriptor.
 	///
 	/// ## Safety
@@ -14,6 +14,10 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -186,6 +190,151 @@ where
 	}
 }
 
+/** Computes the Boolean intersection of two borrowed bit-slices into a new
+bit-vector.
+
+This is equivalent to `lhs.to_bitvec() & rhs`, and has the same behavior as the
+`&=` operator: the result is as long as the left-hand operand, and the
+right-hand operand is zero-extended if it is shorter. The arithmetic is
+accelerated when the two bit-slices share type parameters.
+**/
+impl<T1, T2, O1, O2> BitAnd<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitand(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.to_bitvec() & rhs
+	}
+}
+
+/** Computes the Boolean union of two borrowed bit-slices into a new
+bit-vector.
+
+This is equivalent to `lhs.to_bitvec() | rhs`, and has the same behavior as the
+`|=` operator: the result is as long as the left-hand operand, and the
+right-hand operand is zero-extended if it is shorter. The arithmetic is
+accelerated when the two bit-slices share type parameters.
+**/
+impl<T1, T2, O1, O2> BitOr<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.to_bitvec() | rhs
+	}
+}
+
+/** Computes the Boolean symmetric difference of two borrowed bit-slices into a new
+bit-vector.
+
+This is equivalent to `lhs.to_bitvec() ^ rhs`, and has the same behavior as the
+`^=` operator: the result is as long as the left-hand operand, and the
+right-hand operand is zero-extended if it is shorter. The arithmetic is
+accelerated when the two bit-slices share type parameters.
+**/
+impl<T1, T2, O1, O2> BitXor<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitxor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.to_bitvec() ^ rhs
+	}
+}
+
+/** Inverts a borrowed bit-slice into a new bit-vector.
+
+This is equivalent to `!lhs.to_bitvec()`, and does not modify the original
+bit-slice.
+**/
+impl<T, O> Not for &BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitVec<T::Unalias, O>;
+
+	#[inline]
+	fn not(self) -> Self::Output {
+		!self.to_bitvec()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs> Shl<Rhs> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs> ShlAssign<Rhs> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs> Shr<Rhs> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs> ShrAssign<Rhs> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
+
 impl<T, O> Deref for BitVec<T, O>
 where
 	T: BitStore,
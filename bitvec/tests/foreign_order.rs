@@ -64,3 +64,33 @@ fn verify_u64() {
 fn verify_usize() {
 	verify_for_type::<usize, Swizzle>(cfg!(feature = "verbose"));
 }
+
+#[test]
+fn bitfield() {
+	let mut data = [0u16; 3];
+	let bits = data.view_bits_mut::<Swizzle>();
+	for (start, width) in [(0, 16), (3, 9), (7, 32), (12, 20)] {
+		let value = 0x5A5A_F0E1u32 >> (32 - width);
+		bits[start .. start + width].store_le(value);
+		assert_eq!(bits[start .. start + width].load_le::<u32>(), value);
+		bits[start .. start + width].store_be(value);
+		assert_eq!(bits[start .. start + width].load_be::<u32>(), value);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io() {
+	use std::io::{
+		Read,
+		Write,
+	};
+
+	let mut bv = BitVec::<u8, Swizzle>::new();
+	bv.write_all(&[0x12, 0x34, 0xAB]).unwrap();
+	assert_eq!(bv.len(), 24);
+
+	let mut bytes = [0u8; 3];
+	bv.as_bitslice().read_exact(&mut bytes).unwrap();
+	assert_eq!(bytes, [0x12, 0x34, 0xAB]);
+}